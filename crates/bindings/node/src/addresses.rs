@@ -0,0 +1,72 @@
+//! Address encodings for verifying keys.
+use anyhow::Error;
+use napi::JsError;
+use napi_derive::napi;
+use polysig_driver::addresses::{Network, PublicKey};
+
+/// Bitcoin P2WPKH address for a SEC1 encoded secp256k1
+/// public key.
+///
+/// The network is one of `bitcoin`, `testnet` or `regtest`.
+#[napi(js_name = "bitcoinP2wpkhAddress")]
+pub fn bitcoin_p2wpkh_address(
+    public_key: Vec<u8>,
+    network: String,
+) -> Result<String, JsError> {
+    let network: Network = network.parse().map_err(Error::new)?;
+    let public_key =
+        PublicKey::secp256k1(&public_key).map_err(Error::new)?;
+    Ok(public_key.bitcoin_p2wpkh(network).map_err(Error::new)?)
+}
+
+/// Bitcoin P2TR address for an x-only or SEC1 encoded
+/// secp256k1 public key.
+///
+/// The network is one of `bitcoin`, `testnet` or `regtest`.
+#[napi(js_name = "bitcoinP2trAddress")]
+pub fn bitcoin_p2tr_address(
+    public_key: Vec<u8>,
+    network: String,
+) -> Result<String, JsError> {
+    let network: Network = network.parse().map_err(Error::new)?;
+    let public_key = if public_key.len() == 32 {
+        PublicKey::x_only(&public_key)
+    } else {
+        PublicKey::secp256k1(&public_key)
+    }
+    .map_err(Error::new)?;
+    Ok(public_key.bitcoin_p2tr(network).map_err(Error::new)?)
+}
+
+/// Ethereum address for a SEC1 encoded secp256k1 public key.
+#[napi(js_name = "ethereumAddress")]
+pub fn ethereum_address(
+    public_key: Vec<u8>,
+) -> Result<String, JsError> {
+    let public_key =
+        PublicKey::secp256k1(&public_key).map_err(Error::new)?;
+    Ok(public_key.ethereum().map_err(Error::new)?)
+}
+
+/// Solana address for an Ed25519 public key.
+///
+/// Errors if the public key is not a valid curve point.
+#[napi(js_name = "solanaAddress")]
+pub fn solana_address(
+    public_key: Vec<u8>,
+) -> Result<String, JsError> {
+    let public_key =
+        PublicKey::ed25519(&public_key).map_err(Error::new)?;
+    Ok(public_key.solana().map_err(Error::new)?)
+}
+
+/// Cosmos address for a SEC1 encoded secp256k1 public key.
+#[napi(js_name = "cosmosAddress")]
+pub fn cosmos_address(
+    public_key: Vec<u8>,
+    prefix: String,
+) -> Result<String, JsError> {
+    let public_key =
+        PublicKey::secp256k1(&public_key).map_err(Error::new)?;
+    Ok(public_key.cosmos(&prefix).map_err(Error::new)?)
+}
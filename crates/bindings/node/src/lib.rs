@@ -8,3 +8,7 @@ pub mod protocols;
 
 /// Single party signers.
 pub mod signers;
+
+/// Address encodings.
+#[cfg(feature = "addresses")]
+pub mod addresses;
@@ -15,8 +15,8 @@ use super::types::{KeyShare, SessionOptions};
 mod types;
 
 use types::{
-    Params, PartyOptions, RecoverableSignature, ThresholdKeyShare,
-    VerifyingKey,
+    AuxInfo, CggmpAuxInfo, Params, PartyOptions,
+    RecoverableSignature, ThresholdKeyShare, VerifyingKey,
 };
 
 /// CGGMP protocol.
@@ -92,7 +92,47 @@ impl CggmpProtocol {
         Ok(key_share)
     }
 
+    /// Generate auxiliary information.
+    ///
+    /// The aux info can be passed to sign to avoid
+    /// generating it for every signature; the parties
+    /// must be the same parties that will sign.
+    #[napi(js_name = "auxGen")]
+    pub async fn aux_gen(
+        options: SessionOptions,
+        party: PartyOptions,
+        session_id_seed: Vec<u8>,
+        signer: Vec<u8>,
+    ) -> Result<AuxInfo> {
+        let options: polysig_client::SessionOptions =
+            options.try_into().map_err(Error::new)?;
+
+        let party: polysig_driver::cggmp::PartyOptions =
+            party.try_into().map_err(Error::new)?;
+
+        let signer: SigningKey =
+            signer.as_slice().try_into().map_err(Error::new)?;
+        let verifier = signer.verifying_key().clone();
+
+        let participant = Participant::new(signer, verifier, party)
+            .map_err(Error::new)?;
+        let aux_info = polysig_client::cggmp::aux_gen::<Params>(
+            options,
+            participant,
+            SessionId::from_seed(&session_id_seed),
+        )
+        .await
+        .map_err(Error::new)?;
+
+        let aux_info: AuxInfo =
+            aux_info.try_into().map_err(Error::new)?;
+        Ok(aux_info)
+    }
+
     /// Sign a message.
+    ///
+    /// Aux info is generated before signing unless
+    /// it is given.
     #[napi]
     pub async fn sign(
         &self,
@@ -100,6 +140,7 @@ impl CggmpProtocol {
         session_id_seed: Vec<u8>,
         signer: Vec<u8>,
         message: String,
+        aux_info: Option<AuxInfo>,
     ) -> Result<RecoverableSignature> {
         let options = self.options.clone();
         let party: polysig_driver::cggmp::PartyOptions =
@@ -119,11 +160,19 @@ impl CggmpProtocol {
         let key_share =
             self.key_share.to_key_share(&selected_parties);
 
+        let aux_info: Option<CggmpAuxInfo> =
+            if let Some(aux_info) = aux_info {
+                Some(aux_info.try_into().map_err(Error::new)?)
+            } else {
+                None
+            };
+
         let signature = polysig_client::cggmp::sign(
             options,
             participant,
             SessionId::from_seed(&session_id_seed),
             &key_share,
+            aux_info.as_ref(),
             &message,
         )
         .await
@@ -135,6 +184,8 @@ impl CggmpProtocol {
     }
 
     /// Reshare key shares.
+    ///
+    /// Old holders that are not new holders receive no key share.
     #[napi]
     pub async fn reshare(
         &self,
@@ -144,9 +195,11 @@ impl CggmpProtocol {
         signer: Vec<u8>,
         account_verifying_key: VerifyingKey,
         key_share: Option<KeyShare>,
+        old_holders: Vec<VerifyingKey>,
         old_threshold: i64,
+        new_holders: Vec<VerifyingKey>,
         new_threshold: i64,
-    ) -> Result<KeyShare> {
+    ) -> Result<Option<KeyShare>> {
         let options = self.options.clone();
         let party: polysig_driver::cggmp::PartyOptions =
             party.try_into().map_err(Error::new)?;
@@ -166,21 +219,40 @@ impl CggmpProtocol {
         let participant = Participant::new(signer, verifier, party)
             .map_err(Error::new)?;
 
+        let mut old_verifiers: Vec<ecdsa::VerifyingKey> =
+            Vec::with_capacity(old_holders.len());
+        for verifier in old_holders {
+            old_verifiers
+                .push(verifier.try_into().map_err(Error::new)?);
+        }
+        let mut new_verifiers: Vec<ecdsa::VerifyingKey> =
+            Vec::with_capacity(new_holders.len());
+        for verifier in new_holders {
+            new_verifiers
+                .push(verifier.try_into().map_err(Error::new)?);
+        }
+
         let key_share = polysig_client::cggmp::reshare(
             options,
             participant,
             SessionId::from_seed(&session_id_seed),
             account_verifying_key,
             key_share,
+            &old_verifiers,
             old_threshold as usize,
+            &new_verifiers,
             new_threshold as usize,
         )
         .await
         .map_err(Error::new)?;
 
-        let key_share: KeyShare =
-            key_share.try_into().map_err(Error::new)?;
-        Ok(key_share)
+        if let Some(key_share) = key_share {
+            let key_share: KeyShare =
+                key_share.try_into().map_err(Error::new)?;
+            Ok(Some(key_share))
+        } else {
+            Ok(None)
+        }
     }
 
     /// Generate a BIP32 derived child key.
@@ -14,6 +14,34 @@ pub(super) type Params = synedrion::TestParams;
 pub(super) type ThresholdKeyShare =
     synedrion::ThresholdKeyShare<Params, ecdsa::VerifyingKey>;
 
+pub(super) type CggmpAuxInfo = driver::cggmp::AuxInfo<Params>;
+
+/// Auxiliary information encoded as a PEM.
+#[napi(object)]
+#[derive(Debug)]
+pub struct AuxInfo {
+    pub version: u16,
+    pub contents: String,
+}
+
+impl From<driver::cggmp::EncodedAuxInfo> for AuxInfo {
+    fn from(value: driver::cggmp::EncodedAuxInfo) -> Self {
+        Self {
+            version: value.version,
+            contents: value.contents,
+        }
+    }
+}
+
+impl From<AuxInfo> for driver::cggmp::EncodedAuxInfo {
+    fn from(value: AuxInfo) -> Self {
+        Self {
+            version: value.version,
+            contents: value.contents,
+        }
+    }
+}
+
 #[napi(object)]
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -107,3 +135,22 @@ impl TryFrom<KeyShare> for ThresholdKeyShare {
         Ok((&key_share).try_into()?)
     }
 }
+
+impl TryFrom<CggmpAuxInfo> for AuxInfo {
+    type Error = polysig_protocol::Error;
+
+    fn try_from(value: CggmpAuxInfo) -> Result<Self, Self::Error> {
+        let aux_info: driver::cggmp::EncodedAuxInfo =
+            (&value).try_into()?;
+        Ok(aux_info.into())
+    }
+}
+
+impl TryFrom<AuxInfo> for CggmpAuxInfo {
+    type Error = polysig_protocol::Error;
+
+    fn try_from(value: AuxInfo) -> Result<Self, Self::Error> {
+        let aux_info: driver::cggmp::EncodedAuxInfo = value.into();
+        Ok((&aux_info).try_into()?)
+    }
+}
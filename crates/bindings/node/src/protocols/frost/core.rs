@@ -57,6 +57,36 @@ macro_rules! frost_impl {
                 Ok(key_share)
             }
 
+            /// Distributed key generation with identifiers derived
+            /// from the participant verifying keys.
+            #[napi]
+            pub async fn dkg_derived(
+                options: SessionOptions,
+                party: PartyOptions,
+                signer: SigningKey,
+            ) -> Result<KeyShare> {
+                let options: polysig_client::SessionOptions =
+                    options.try_into().map_err(Error::new)?;
+
+                let party: ProtocolPartyOptions =
+                    party.try_into().map_err(Error::new)?;
+
+                let signer: ProtocolSigningKey = signer.try_into()?;
+                let verifier = signer.verifying_key().clone();
+
+                let participant =
+                    Participant::new(signer, verifier, party)
+                        .map_err(Error::new)?;
+
+                let key_share = dkg_derived(options, participant)
+                    .await
+                    .map_err(Error::new)?;
+
+                let key_share: KeyShare =
+                    key_share.try_into().map_err(Error::new)?;
+                Ok(key_share)
+            }
+
             /// Sign a message.
             #[napi]
             pub async fn sign(
@@ -92,6 +122,74 @@ macro_rules! frost_impl {
 
                 Ok(signature.try_into()?)
             }
+
+            /// Sign a message with identifiers derived from the
+            /// verifying keys of the signing parties.
+            #[napi]
+            pub async fn sign_derived(
+                &self,
+                party: PartyOptions,
+                signer: SigningKey,
+                message: String,
+            ) -> Result<Signature> {
+                let options = self.options.clone();
+                let party: ProtocolPartyOptions =
+                    party.try_into().map_err(Error::new)?;
+                let signer: ProtocolSigningKey = signer.try_into()?;
+                let verifier = signer.verifying_key().clone();
+                let participant =
+                    Participant::new(signer, verifier, party)
+                        .map_err(Error::new)?;
+
+                let signature = sign_derived(
+                    options,
+                    participant,
+                    self.key_share.clone(),
+                    message.as_bytes().to_vec(),
+                )
+                .await
+                .map_err(Error::new)?;
+
+                Ok(signature.try_into()?)
+            }
+
+            /// Refresh the key share.
+            ///
+            /// All participants holding a key share must take part.
+            #[napi]
+            pub async fn refresh(
+                &self,
+                party: PartyOptions,
+                signer: SigningKey,
+                identifiers: Vec<Identifier>,
+            ) -> Result<KeyShare> {
+                let options = self.options.clone();
+                let party: ProtocolPartyOptions =
+                    party.try_into().map_err(Error::new)?;
+                let signer: ProtocolSigningKey = signer.try_into()?;
+                let verifier = signer.verifying_key().clone();
+                let participant =
+                    Participant::new(signer, verifier, party)
+                        .map_err(Error::new)?;
+
+                let mut ids = Vec::with_capacity(identifiers.len());
+                for id in identifiers {
+                    ids.push(id.try_into()?);
+                }
+
+                let key_share = refresh(
+                    options,
+                    participant,
+                    ids,
+                    self.key_share.clone(),
+                )
+                .await
+                .map_err(Error::new)?;
+
+                let key_share: KeyShare =
+                    key_share.try_into().map_err(Error::new)?;
+                Ok(key_share)
+            }
         }
     };
 }
@@ -111,13 +209,7 @@ macro_rules! frost_types {
             fn try_from(
                 value: VerifyingKey,
             ) -> std::result::Result<Self, Self::Error> {
-                let bytes: [u8; 32] = value
-                    .public_key
-                    .as_slice()
-                    .try_into()
-                    .map_err(Error::new)?;
-                Ok(ProtocolVerifyingKey::from_bytes(&bytes)
-                    .map_err(Error::new)?)
+                into_verifying_key(&value.public_key)
             }
         }
 
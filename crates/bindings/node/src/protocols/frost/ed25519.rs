@@ -3,7 +3,9 @@ use crate::protocols::types::{KeyShare, SessionOptions};
 use anyhow::Error;
 use napi::bindgen_prelude::Result;
 use napi_derive::napi;
-use polysig_client::frost::ed25519::{dkg, sign};
+use polysig_client::frost::ed25519::{
+    dkg, dkg_derived, refresh, sign, sign_derived,
+};
 use polysig_driver::{
     self as driver,
     frost::ed25519::{
@@ -37,5 +39,11 @@ impl TryFrom<SigningKey> for frost::SigningKey {
     }
 }
 
+fn into_verifying_key(bytes: &[u8]) -> Result<ProtocolVerifyingKey> {
+    let bytes: [u8; 32] = bytes.try_into().map_err(Error::new)?;
+    Ok(ProtocolVerifyingKey::from_bytes(&bytes)
+        .map_err(Error::new)?)
+}
+
 frost_types!();
 frost_impl!(FrostEd25519Protocol);
@@ -0,0 +1,49 @@
+//! FROST Ristretto255 protocol.
+use crate::protocols::types::{KeyShare, SessionOptions};
+use anyhow::Error;
+use napi::bindgen_prelude::Result;
+use napi_derive::napi;
+use polysig_client::frost::ristretto255::{
+    dkg, dkg_derived, refresh, sign, sign_derived,
+};
+use polysig_driver::{
+    self as driver,
+    frost::ristretto255::{
+        self as frost, Participant,
+        PartyOptions as ProtocolPartyOptions,
+        SigningKey as ProtocolSigningKey,
+        VerifyingKey as ProtocolVerifyingKey,
+    },
+};
+use serde::{Deserialize, Serialize};
+
+/// Threshold key share for FROST Ristretto255.
+pub type ThresholdKeyShare = frost::KeyShare;
+
+use super::core::{frost_impl, frost_types};
+
+/// Protocol signing key.
+#[napi(object)]
+pub struct SigningKey {
+    /// Signing key bytes.
+    pub bytes: Vec<u8>,
+}
+
+impl TryFrom<SigningKey> for frost::SigningKey {
+    type Error = napi::Error;
+
+    fn try_from(
+        value: SigningKey,
+    ) -> std::result::Result<Self, Self::Error> {
+        Ok(value.bytes.as_slice().try_into().map_err(Error::new)?)
+    }
+}
+
+fn into_verifying_key(bytes: &[u8]) -> Result<ProtocolVerifyingKey> {
+    let bytes: [u8; 32] = bytes.try_into().map_err(Error::new)?;
+    Ok(ProtocolVerifyingKey::from_bytes(&bytes)
+        .map_err(Error::new)?)
+}
+
+frost_types!();
+frost_impl!(FrostRistretto255Protocol);
@@ -3,13 +3,15 @@ use crate::protocols::types::{KeyShare, SessionOptions};
 use anyhow::Error;
 use napi::bindgen_prelude::Result;
 use napi_derive::napi;
-use polysig_client::frost::secp256k1_tr::{dkg, sign};
+use polysig_client::frost::secp256k1_tr::{
+    dkg, dkg_derived, refresh, sign, sign_derived, sign_taproot,
+};
 use polysig_driver::{
     self as driver,
     frost::secp256k1_tr::{
-        self as frost, Participant,
+        self as frost, tweaked_output_key, Participant,
         PartyOptions as ProtocolPartyOptions,
-        SigningKey as ProtocolSigningKey,
+        SigningKey as ProtocolSigningKey, TaprootTweak,
         VerifyingKey as ProtocolVerifyingKey,
     },
 };
@@ -38,5 +40,78 @@ impl TryFrom<SigningKey> for frost::SigningKey {
     }
 }
 
+fn into_verifying_key(bytes: &[u8]) -> Result<ProtocolVerifyingKey> {
+    let bytes: [u8; 32] = bytes.try_into().map_err(Error::new)?;
+    Ok(ProtocolVerifyingKey::from_bytes(&bytes)
+        .map_err(Error::new)?)
+}
+
+// Outputs without a script tree have no merkle root.
+fn into_tweak(merkle_root: Option<Vec<u8>>) -> Result<TaprootTweak> {
+    let merkle_root: Option<[u8; 32]> = match merkle_root {
+        Some(root) => {
+            Some(root.as_slice().try_into().map_err(Error::new)?)
+        }
+        None => None,
+    };
+    Ok(merkle_root.into())
+}
+
 frost_types!();
 frost_impl!(FrostSecp256K1TrProtocol);
+
+#[napi]
+impl FrostSecp256K1TrProtocol {
+    /// Sign a message for the Taproot output key committing
+    /// to an optional script tree merkle root.
+    #[napi]
+    pub async fn sign_taproot(
+        &self,
+        party: PartyOptions,
+        signer: SigningKey,
+        identifiers: Vec<Identifier>,
+        message: Vec<u8>,
+        merkle_root: Option<Vec<u8>>,
+    ) -> Result<Signature> {
+        let options = self.options.clone();
+        let tweak = into_tweak(merkle_root)?;
+        let party: ProtocolPartyOptions =
+            party.try_into().map_err(Error::new)?;
+        let signer: ProtocolSigningKey = signer.try_into()?;
+        let verifier = signer.verifying_key().clone();
+        let participant = Participant::new(signer, verifier, party)
+            .map_err(Error::new)?;
+
+        let mut ids = Vec::with_capacity(identifiers.len());
+        for id in identifiers {
+            ids.push(id.try_into()?);
+        }
+
+        let signature = sign_taproot(
+            options,
+            participant,
+            ids,
+            self.key_share.clone(),
+            message,
+            tweak,
+        )
+        .await
+        .map_err(Error::new)?;
+
+        Ok(signature.try_into()?)
+    }
+
+    /// X-only Taproot output key committing to an optional
+    /// script tree merkle root.
+    #[napi]
+    pub fn taproot_output_key(
+        &self,
+        merkle_root: Option<Vec<u8>>,
+    ) -> Result<Vec<u8>> {
+        let tweak = into_tweak(merkle_root)?;
+        let output_key =
+            tweaked_output_key(&self.key_share.1, &tweak)
+                .map_err(Error::new)?;
+        Ok(output_key.to_vec())
+    }
+}
@@ -1,11 +1,12 @@
 //! ECDSA signatures compatible with Ethereum.
 use anyhow::Error;
+use napi::{Env, JsError, JsUnknown};
+use napi_derive::napi;
 use polysig_driver::{
+    eip712::TypedData,
     recoverable_signature::RecoverableSignature,
     signers::ecdsa::{self, Signature},
 };
-use napi::{Env, JsError, JsUnknown};
-use napi_derive::napi;
 use std::borrow::Cow;
 
 /// Signer for ECDSA.
@@ -123,6 +124,35 @@ impl EcdsaSigner {
         Ok(env.to_js_value(&signature)?)
     }
 
+    /// Sign EIP-712 typed data encoded as JSON in the format
+    /// accepted by `eth_signTypedData_v4`.
+    #[napi(js_name = "signTypedData")]
+    pub fn sign_typed_data(
+        &self,
+        typed_data: String,
+        env: Env,
+    ) -> Result<JsUnknown, JsError> {
+        let typed_data: TypedData =
+            serde_json::from_str(&typed_data).map_err(Error::new)?;
+        let result = self
+            .inner
+            .sign_typed_data(&typed_data)
+            .map_err(Error::new)?;
+        let signature: RecoverableSignature = result.into();
+        Ok(env.to_js_value(&signature)?)
+    }
+
+    /// Compute the EIP-712 signing hash of typed data encoded
+    /// as JSON.
+    #[napi(js_name = "hashTypedData")]
+    pub fn hash_typed_data(
+        typed_data: String,
+    ) -> Result<Vec<u8>, JsError> {
+        let typed_data: TypedData =
+            serde_json::from_str(&typed_data).map_err(Error::new)?;
+        Ok(typed_data.signing_hash().map_err(Error::new)?.to_vec())
+    }
+
     /// Recover the public key from a signature and recovery identifier.
     #[napi]
     pub fn recover(
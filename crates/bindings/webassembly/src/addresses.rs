@@ -0,0 +1,60 @@
+//! Address encodings for verifying keys.
+use polysig_driver::addresses::{Network, PublicKey};
+use wasm_bindgen::prelude::{wasm_bindgen, JsError};
+
+/// Bitcoin P2WPKH address for a SEC1 encoded secp256k1
+/// public key.
+///
+/// The network is one of `bitcoin`, `testnet` or `regtest`.
+#[wasm_bindgen(js_name = "bitcoinP2wpkhAddress")]
+pub fn bitcoin_p2wpkh_address(
+    public_key: &[u8],
+    network: &str,
+) -> Result<String, JsError> {
+    let network: Network = network.parse()?;
+    let public_key = PublicKey::secp256k1(public_key)?;
+    Ok(public_key.bitcoin_p2wpkh(network)?)
+}
+
+/// Bitcoin P2TR address for an x-only or SEC1 encoded
+/// secp256k1 public key.
+///
+/// The network is one of `bitcoin`, `testnet` or `regtest`.
+#[wasm_bindgen(js_name = "bitcoinP2trAddress")]
+pub fn bitcoin_p2tr_address(
+    public_key: &[u8],
+    network: &str,
+) -> Result<String, JsError> {
+    let network: Network = network.parse()?;
+    let public_key = if public_key.len() == 32 {
+        PublicKey::x_only(public_key)?
+    } else {
+        PublicKey::secp256k1(public_key)?
+    };
+    Ok(public_key.bitcoin_p2tr(network)?)
+}
+
+/// Ethereum address for a SEC1 encoded secp256k1 public key.
+#[wasm_bindgen(js_name = "ethereumAddress")]
+pub fn ethereum_address(
+    public_key: &[u8],
+) -> Result<String, JsError> {
+    Ok(PublicKey::secp256k1(public_key)?.ethereum()?)
+}
+
+/// Solana address for an Ed25519 public key.
+///
+/// Errors if the public key is not a valid curve point.
+#[wasm_bindgen(js_name = "solanaAddress")]
+pub fn solana_address(public_key: &[u8]) -> Result<String, JsError> {
+    Ok(PublicKey::ed25519(public_key)?.solana()?)
+}
+
+/// Cosmos address for a SEC1 encoded secp256k1 public key.
+#[wasm_bindgen(js_name = "cosmosAddress")]
+pub fn cosmos_address(
+    public_key: &[u8],
+    prefix: &str,
+) -> Result<String, JsError> {
+    Ok(PublicKey::secp256k1(public_key)?.cosmos(prefix)?)
+}
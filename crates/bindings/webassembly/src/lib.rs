@@ -18,6 +18,14 @@ pub mod protocols;
 ))]
 pub mod signers;
 
+/// Address encodings.
+#[cfg(all(
+    target_arch = "wasm32",
+    target_os = "unknown",
+    feature = "addresses"
+))]
+pub mod addresses;
+
 /// Initialize the panic hook and logging.
 #[doc(hidden)]
 #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
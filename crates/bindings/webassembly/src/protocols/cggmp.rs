@@ -6,7 +6,7 @@ use polysig_driver::synedrion::{
     SessionId,
 };
 use polysig_driver::{
-    cggmp::{self, Participant},
+    cggmp::{self, EncodedAuxInfo, Participant},
     KeyShare,
 };
 use polysig_protocol::hex;
@@ -23,6 +23,8 @@ type Params = synedrion::TestParams;
 type ThresholdKeyShare =
     synedrion::ThresholdKeyShare<Params, VerifyingKey>;
 
+type AuxInfo = cggmp::AuxInfo<Params>;
+
 /// Options for a party participating in a protocol.
 ///
 /// Required in the bindings to convert the `verifiers`
@@ -132,13 +134,55 @@ impl CggmpProtocol {
         Ok(future_to_promise(fut).into())
     }
 
+    /// Generate auxiliary information.
+    ///
+    /// The aux info can be passed to sign to avoid
+    /// generating it for every signature; the parties
+    /// must be the same parties that will sign.
+    #[wasm_bindgen(js_name = "auxGen")]
+    pub fn aux_gen(
+        options: JsValue,
+        party: JsValue,
+        session_id_seed: Vec<u8>,
+        signer: Vec<u8>,
+    ) -> Result<JsValue, JsError> {
+        let options: SessionOptions =
+            serde_wasm_bindgen::from_value(options)?;
+        let party: PartyOptions =
+            serde_wasm_bindgen::from_value(party)?;
+        let signer: SigningKey =
+            signer.as_slice().try_into().map_err(JsError::from)?;
+        let verifier = signer.verifying_key().clone();
+        let participant =
+            Participant::new(signer, verifier, party.try_into()?)
+                .map_err(JsError::from)?;
+        let fut = async move {
+            let aux_info = polysig_client::cggmp::aux_gen::<Params>(
+                options,
+                participant,
+                SessionId::from_seed(&session_id_seed),
+            )
+            .await?;
+
+            let aux_info: EncodedAuxInfo =
+                (&aux_info).try_into().map_err(JsError::from)?;
+
+            Ok(serde_wasm_bindgen::to_value(&aux_info)?)
+        };
+        Ok(future_to_promise(fut).into())
+    }
+
     /// Sign a message.
+    ///
+    /// Aux info is generated before signing unless
+    /// it is given.
     pub fn sign(
         &self,
         party: JsValue,
         session_id_seed: Vec<u8>,
         signer: Vec<u8>,
         message: String,
+        aux_info: JsValue,
     ) -> Result<JsValue, JsError> {
         let options = self.options.clone();
         let party: PartyOptions =
@@ -162,12 +206,22 @@ impl CggmpProtocol {
         let message: [u8; 32] =
             message.as_slice().try_into().map_err(JsError::from)?;
 
+        let aux_info: Option<EncodedAuxInfo> =
+            serde_wasm_bindgen::from_value(aux_info)?;
+        let aux_info: Option<AuxInfo> =
+            if let Some(aux_info) = aux_info {
+                Some((&aux_info).try_into().map_err(JsError::from)?)
+            } else {
+                None
+            };
+
         let fut = async move {
             let signature = polysig_client::cggmp::sign(
                 options,
                 participant,
                 SessionId::from_seed(&session_id_seed),
                 &key_share,
+                aux_info.as_ref(),
                 &message,
             )
             .await?;
@@ -177,6 +231,8 @@ impl CggmpProtocol {
     }
 
     /// Reshare key shares.
+    ///
+    /// Old holders that are not new holders receive no key share.
     pub fn reshare(
         &self,
         party: JsValue,
@@ -184,7 +240,9 @@ impl CggmpProtocol {
         signer: Vec<u8>,
         account_verifying_key: JsValue,
         key_share: JsValue,
+        old_holders: JsValue,
         old_threshold: usize,
+        new_holders: JsValue,
         new_threshold: usize,
     ) -> Result<JsValue, JsError> {
         let options = self.options.clone();
@@ -195,6 +253,10 @@ impl CggmpProtocol {
         let verifier = signer.verifying_key().clone();
         let account_verifying_key: VerifyingKey =
             serde_wasm_bindgen::from_value(account_verifying_key)?;
+        let old_holders: Vec<VerifyingKey> =
+            serde_wasm_bindgen::from_value(old_holders)?;
+        let new_holders: Vec<VerifyingKey> =
+            serde_wasm_bindgen::from_value(new_holders)?;
 
         let key_share: Option<KeyShare> =
             serde_wasm_bindgen::from_value(key_share)?;
@@ -216,7 +278,9 @@ impl CggmpProtocol {
                 SessionId::from_seed(&session_id_seed),
                 account_verifying_key,
                 key_share,
+                &old_holders,
                 old_threshold,
+                &new_holders,
                 new_threshold,
             )
             .await?;
@@ -66,6 +66,38 @@ macro_rules! frost_impl {
                 Ok(future_to_promise(fut).into())
             }
 
+            /// Distributed key generation with identifiers derived
+            /// from the participant verifying keys.
+            #[wasm_bindgen(js_name = "dkgDerived")]
+            pub async fn dkg_derived(
+                options: JsValue,
+                party: JsValue,
+                signer: Vec<u8>,
+            ) -> Result<JsValue, JsError> {
+                let options: SessionOptions =
+                    serde_wasm_bindgen::from_value(options)?;
+                let party: PartyOptions =
+                    serde_wasm_bindgen::from_value(party)?;
+                let signer: SigningKey = into_signing_key(signer)?;
+                let verifier = signer.verifying_key().clone();
+
+                let participant =
+                    Participant::new(signer, verifier, party)
+                        .map_err(JsError::from)?;
+
+                let fut = async move {
+                    let key_share =
+                        dkg_derived(options, participant).await?;
+
+                    let key_share: KeyShare = (&key_share)
+                        .try_into()
+                        .map_err(JsError::from)?;
+
+                    Ok(serde_wasm_bindgen::to_value(&key_share)?)
+                };
+                Ok(future_to_promise(fut).into())
+            }
+
             /// Sign a message.
             pub async fn sign(
                 &self,
@@ -102,6 +134,76 @@ macro_rules! frost_impl {
                 };
                 Ok(future_to_promise(fut).into())
             }
+
+            /// Sign a message with identifiers derived from the
+            /// verifying keys of the signing parties.
+            #[wasm_bindgen(js_name = "signDerived")]
+            pub async fn sign_derived(
+                &self,
+                party: JsValue,
+                signer: Vec<u8>,
+                message: Vec<u8>,
+            ) -> Result<JsValue, JsError> {
+                let options = self.options.clone();
+                let party: PartyOptions =
+                    serde_wasm_bindgen::from_value(party)?;
+                let signer: SigningKey = into_signing_key(signer)?;
+                let verifier = signer.verifying_key().clone();
+                let participant =
+                    Participant::new(signer, verifier, party)
+                        .map_err(JsError::from)?;
+
+                let key_share = self.key_share.clone();
+                let fut = async move {
+                    let signature = sign_derived(
+                        options,
+                        participant,
+                        key_share,
+                        message,
+                    )
+                    .await?;
+                    Ok(serde_wasm_bindgen::to_value(&signature)?)
+                };
+                Ok(future_to_promise(fut).into())
+            }
+
+            /// Refresh the key share.
+            ///
+            /// All participants holding a key share must take part.
+            pub async fn refresh(
+                &self,
+                party: JsValue,
+                signer: Vec<u8>,
+                identifiers: Vec<u16>,
+            ) -> Result<JsValue, JsError> {
+                let options = self.options.clone();
+                let party: PartyOptions =
+                    serde_wasm_bindgen::from_value(party)?;
+                let signer: SigningKey = into_signing_key(signer)?;
+                let verifier = signer.verifying_key().clone();
+                let participant =
+                    Participant::new(signer, verifier, party)
+                        .map_err(JsError::from)?;
+
+                let mut ids = Vec::with_capacity(identifiers.len());
+                for id in identifiers {
+                    ids.push(id.try_into()?);
+                }
+
+                let key_share = self.key_share.clone();
+                let fut = async move {
+                    let key_share =
+                        refresh(options, participant, ids, key_share)
+                            .await?;
+
+                    let key_share: KeyShare = (&key_share)
+                        .try_into()
+                        .map_err(JsError::from)?;
+
+                    Ok(serde_wasm_bindgen::to_value(&key_share)?)
+                };
+                Ok(future_to_promise(fut).into())
+            }
         }
     };
 }
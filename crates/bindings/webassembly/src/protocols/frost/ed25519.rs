@@ -1,6 +1,6 @@
 //! FROST Ed25519 protocol.
 use polysig_client::{
-    frost::ed25519::{dkg, sign},
+    frost::ed25519::{dkg, dkg_derived, refresh, sign, sign_derived},
     SessionOptions,
 };
 use polysig_driver::{
@@ -0,0 +1,23 @@
+//! FROST P-256 protocol.
+use polysig_client::{
+    frost::p256::{dkg, dkg_derived, refresh, sign, sign_derived},
+    SessionOptions,
+};
+use polysig_driver::{
+    frost::p256::{
+        self as frost, Identifier, Participant, PartyOptions,
+        SigningKey,
+    },
+    KeyShare,
+};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::future_to_promise;
+
+/// Threshold key share for FROST P-256.
+pub type ThresholdKeyShare = frost::KeyShare;
+
+fn into_signing_key(value: Vec<u8>) -> Result<SigningKey, JsError> {
+    Ok(SigningKey::from_slice(&value)?)
+}
+
+super::core::frost_impl!(FrostP256Protocol);
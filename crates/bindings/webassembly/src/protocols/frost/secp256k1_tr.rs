@@ -1,12 +1,14 @@
 //! FROST Secp256k1 Taproot protocol.
 use polysig_client::{
-    frost::secp256k1_tr::{dkg, sign},
+    frost::secp256k1_tr::{
+        dkg, dkg_derived, refresh, sign, sign_derived, sign_taproot,
+    },
     SessionOptions,
 };
 use polysig_driver::{
     frost::secp256k1_tr::{
-        self as frost, Identifier, Participant, PartyOptions,
-        SigningKey,
+        self as frost, tweaked_output_key, Identifier, Participant,
+        PartyOptions, SigningKey, TaprootTweak,
     },
     KeyShare,
 };
@@ -20,4 +22,72 @@ fn into_signing_key(value: Vec<u8>) -> Result<SigningKey, JsError> {
     Ok(SigningKey::from_bytes(&value)?)
 }
 
+// Outputs without a script tree have no merkle root.
+fn into_tweak(
+    merkle_root: Option<Vec<u8>>,
+) -> Result<TaprootTweak, JsError> {
+    let merkle_root: Option<[u8; 32]> = match merkle_root {
+        Some(root) => Some(root.as_slice().try_into()?),
+        None => None,
+    };
+    Ok(merkle_root.into())
+}
+
 super::core::frost_impl!(FrostSecp256K1TrProtocol);
+
+#[wasm_bindgen]
+impl FrostSecp256K1TrProtocol {
+    /// Sign a message for the Taproot output key committing
+    /// to an optional script tree merkle root.
+    #[wasm_bindgen(js_name = "signTaproot")]
+    pub async fn sign_taproot(
+        &self,
+        party: JsValue,
+        signer: Vec<u8>,
+        identifiers: Vec<u16>,
+        message: Vec<u8>,
+        merkle_root: Option<Vec<u8>>,
+    ) -> Result<JsValue, JsError> {
+        let options = self.options.clone();
+        let tweak = into_tweak(merkle_root)?;
+        let party: PartyOptions =
+            serde_wasm_bindgen::from_value(party)?;
+        let signer: SigningKey = into_signing_key(signer)?;
+        let verifier = signer.verifying_key().clone();
+        let participant = Participant::new(signer, verifier, party)
+            .map_err(JsError::from)?;
+
+        let mut ids = Vec::with_capacity(identifiers.len());
+        for id in identifiers {
+            ids.push(id.try_into()?);
+        }
+
+        let key_share = self.key_share.clone();
+        let fut = async move {
+            let signature = sign_taproot(
+                options,
+                participant,
+                ids,
+                key_share,
+                message,
+                tweak,
+            )
+            .await?;
+            Ok(serde_wasm_bindgen::to_value(&signature)?)
+        };
+        Ok(future_to_promise(fut).into())
+    }
+
+    /// X-only Taproot output key committing to an optional
+    /// script tree merkle root.
+    #[wasm_bindgen(js_name = "taprootOutputKey")]
+    pub fn taproot_output_key(
+        &self,
+        merkle_root: Option<Vec<u8>>,
+    ) -> Result<Vec<u8>, JsError> {
+        let tweak = into_tweak(merkle_root)?;
+        let output_key =
+            tweaked_output_key(&self.key_share.1, &tweak)?;
+        Ok(output_key.to_vec())
+    }
+}
@@ -1,5 +1,6 @@
 //! ECDSA signatures compatible with Ethereum.
 use polysig_driver::{
+    eip712::TypedData,
     recoverable_signature::RecoverableSignature,
     signers::ecdsa::{self, Signature},
 };
@@ -114,6 +115,29 @@ impl EcdsaSigner {
         Ok(serde_wasm_bindgen::to_value(&signature)?)
     }
 
+    /// Sign EIP-712 typed data encoded as JSON in the format
+    /// accepted by `eth_signTypedData_v4`.
+    #[wasm_bindgen(js_name = "signTypedData")]
+    pub fn sign_typed_data(
+        &self,
+        typed_data: &str,
+    ) -> Result<JsValue, JsError> {
+        let typed_data: TypedData = serde_json::from_str(typed_data)?;
+        let result = self.inner.sign_typed_data(&typed_data)?;
+        let signature: RecoverableSignature = result.into();
+        Ok(serde_wasm_bindgen::to_value(&signature)?)
+    }
+
+    /// Compute the EIP-712 signing hash of typed data encoded
+    /// as JSON.
+    #[wasm_bindgen(js_name = "hashTypedData")]
+    pub fn hash_typed_data(
+        typed_data: &str,
+    ) -> Result<Vec<u8>, JsError> {
+        let typed_data: TypedData = serde_json::from_str(typed_data)?;
+        Ok(typed_data.signing_hash()?.to_vec())
+    }
+
     /// Recover the public key from a signature and recovery identifier.
     pub fn recover(
         message: &[u8],
@@ -125,6 +125,12 @@ macro_rules! client_transport_impl {
                 &mut self,
                 public_key: &[u8],
             ) -> Result<()> {
+                if !self.options.is_expected_peer(public_key) {
+                    return Err(Error::UnexpectedPeer(hex::encode(
+                        public_key,
+                    )));
+                }
+
                 let mut peers = self.peers.write().await;
 
                 if peers.get(public_key.as_ref()).is_some() {
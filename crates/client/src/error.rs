@@ -35,6 +35,11 @@ pub enum Error {
     #[error(r#"peer "{0}" not found "#)]
     PeerNotFound(String),
 
+    /// Error generated when a peer handshake is attempted with
+    /// a public key that is not an expected participant.
+    #[error(r#"peer "{0}" is not an expected participant"#)]
+    UnexpectedPeer(String),
+
     /// Error generated when a node expects to be in the handshake
     /// protocol state.
     #[error("not handshake protocol state")]
@@ -73,6 +78,39 @@ pub enum Error {
     #[error("could not find an ACK for key init phase")]
     NoKeyInitAck,
 
+    #[cfg(feature = "cggmp")]
+    /// Error generated when old or new holders for key resharing
+    /// are not session participants or a session participant
+    /// is neither an old nor a new holder.
+    #[error(
+        "key resharing holders must match the session participants"
+    )]
+    ReshareHolders,
+
+    #[cfg(feature = "cggmp")]
+    /// Error generated when there are fewer old holders than
+    /// the old threshold.
+    #[error("key resharing requires {0} old holders but got {1}")]
+    ReshareOldHolders(usize, usize),
+
+    #[cfg(feature = "cggmp")]
+    /// Error generated when an old holder does not provide a key
+    /// share or a party that is not an old holder provides a
+    /// key share.
+    #[error("old holders must provide a key share and other parties must not")]
+    ReshareKeyShare,
+
+    #[cfg(feature = "cggmp")]
+    /// Error generated when a new holder did not receive a key share.
+    #[error("key resharing did not produce a key share")]
+    NoReshareKeyShare,
+
+    #[cfg(feature = "frost")]
+    /// Error generated when the coordinator for FROST
+    /// coordinated signing is not the session initiator.
+    #[error("coordinator must be the session initiator")]
+    CoordinatorInitiator,
+
     /// Javascript string error message.
     #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
     #[error("{0}")]
@@ -148,6 +186,30 @@ pub enum Error {
         #[from] polysig_driver::frost_secp256k1_tr::Error,
     ),
 
+    #[cfg(feature = "frost-ristretto255")]
+    /// FROST library error.
+    #[error(transparent)]
+    FrostRistretto255Core(
+        #[from] polysig_driver::frost_ristretto255::Error,
+    ),
+
+    #[cfg(feature = "frost-p256")]
+    /// FROST library error.
+    #[error(transparent)]
+    FrostP256Core(#[from] polysig_driver::frost_p256::Error),
+
+    #[cfg(feature = "frost-secp256k1")]
+    /// FROST library error.
+    #[error(transparent)]
+    FrostSecp256k1Core(
+        #[from] polysig_driver::frost_secp256k1::Error,
+    ),
+
+    #[cfg(feature = "frost-ed448")]
+    /// FROST library error.
+    #[error(transparent)]
+    FrostEd448Core(#[from] polysig_driver::frost_ed448::Error),
+
     #[cfg(feature = "cggmp")]
     /// CGGMP library error.
     #[error(transparent)]
@@ -6,7 +6,13 @@ use std::sync::Arc;
 use tokio::sync::mpsc;
 
 use polysig_protocol::{
-    channel::decrypt_server_channel, decode, hex, snow::Builder,
+    channel::{
+        authenticates_initiator, decrypt_server_channel,
+        encrypt_server_channel, read_handshake_message,
+        static_key_proof,
+    },
+    decode, encode, hex,
+    snow::Builder,
     Encoding, Event, HandshakeMessage, MeetingResponse,
     OpaqueMessage, ProtocolState, RequestMessage, ResponseMessage,
     SealedEnvelope, ServerMessage, SessionId, TransparentMessage,
@@ -85,8 +91,14 @@ where
                     HandshakeMessage::Responder(len, buf),
                 ),
             ) => Ok(Some(
-                Self::server_handshake(options, server, len, buf)
-                    .await?,
+                Self::server_handshake(
+                    options,
+                    server,
+                    outbound_tx,
+                    len,
+                    buf,
+                )
+                .await?,
             )),
             ResponseMessage::Transparent(
                 TransparentMessage::PeerHandshake {
@@ -183,21 +195,53 @@ where
     async fn server_handshake(
         options: Arc<ClientOptions>,
         server: Server,
+        outbound_tx: mpsc::UnboundedSender<InternalMessage>,
         len: usize,
         buf: Vec<u8>,
     ) -> Result<Event> {
+        let server_public_key =
+            options.server_public_key.as_ref().unwrap();
         let mut state = server.write().await;
-        let transport = match state.take() {
+        let (transport, handshake_hash) = match state.take() {
             Some(ProtocolState::Handshake(mut initiator)) => {
-                let mut read_buf = vec![0u8; 1024];
-                initiator.read_message(&buf[..len], &mut read_buf)?;
+                read_handshake_message(
+                    &mut initiator,
+                    &buf[..len],
+                    server_public_key,
+                )?;
 
-                initiator.into_transport_mode()?
+                let handshake_hash =
+                    initiator.get_handshake_hash().to_vec();
+                (initiator.into_transport_mode()?, handshake_hash)
             }
             _ => return Err(Error::NotHandshakeState),
         };
 
-        *state = Some(ProtocolState::Transport(transport));
+        let mut transport = ProtocolState::Transport(transport);
+
+        // Prove we hold the private key for the public key
+        // we offered when the pattern does not authenticate it
+        if !authenticates_initiator(&options.params()?) {
+            let proof = static_key_proof(
+                options.keypair.as_ref().unwrap().private_key(),
+                server_public_key,
+                &handshake_hash,
+            )?;
+            let payload =
+                encode(&ServerMessage::Authenticate(proof)).await?;
+            let envelope = encrypt_server_channel(
+                &mut transport,
+                &payload,
+                false,
+            )
+            .await?;
+            let request = RequestMessage::Opaque(
+                OpaqueMessage::ServerMessage(envelope),
+            );
+            outbound_tx.send(InternalMessage::Request(request))?;
+        }
+
+        *state = Some(transport);
 
         Ok(Event::ServerConnected {
             server_key: options.server_public_key.clone(),
@@ -212,6 +256,15 @@ where
         len: usize,
         buf: Vec<u8>,
     ) -> Result<Option<Event>> {
+        // The relay supplies the initiator public key so it
+        // must be one of the participants we expect otherwise
+        // the relay could choose the remote static key
+        if !options.is_expected_peer(public_key.as_ref()) {
+            return Err(Error::UnexpectedPeer(hex::encode(
+                public_key.as_ref(),
+            )));
+        }
+
         let mut peers = peers.write().await;
 
         if peers.get(public_key.as_ref()).is_some() {
@@ -231,8 +284,11 @@ where
                 .remote_public_key(public_key.as_ref())
                 .build_responder()?;
 
-            let mut read_buf = vec![0u8; 1024];
-            responder.read_message(&buf[..len], &mut read_buf)?;
+            read_handshake_message(
+                &mut responder,
+                &buf[..len],
+                public_key.as_ref(),
+            )?;
 
             let mut payload = vec![0u8; 1024];
             let len = responder.write_message(&[], &mut payload)?;
@@ -284,8 +340,11 @@ where
 
         let transport = match peer {
             ProtocolState::Handshake(mut initiator) => {
-                let mut read_buf = vec![0u8; 1024];
-                initiator.read_message(&buf[..len], &mut read_buf)?;
+                read_handshake_message(
+                    &mut initiator,
+                    &buf[..len],
+                    public_key.as_ref(),
+                )?;
                 initiator.into_transport_mode()?
             }
             _ => return Err(Error::NotHandshakeState),
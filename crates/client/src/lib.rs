@@ -60,7 +60,20 @@ pub struct ClientOptions {
     ///
     /// If no pattern is specified the default noise parameters
     /// pattern is used.
+    ///
+    /// Use an authenticated pattern such as
+    /// [AUTHENTICATED_PATTERN](polysig_protocol::AUTHENTICATED_PATTERN)
+    /// to verify the static keys of the server and peers during
+    /// the handshake; the pattern must complete in a single
+    /// round trip so `Noise_XX` is not supported.
     pub pattern: Option<String>,
+    /// Public keys of the peers this client expects to talk to.
+    ///
+    /// When set peer handshakes to or from any other public
+    /// key are rejected; the relay server chooses the public
+    /// key announced for a handshake so it must not be trusted
+    /// to pick the remote static key.
+    pub participants: Option<Vec<Vec<u8>>>,
 }
 
 impl ClientOptions {
@@ -86,6 +99,18 @@ impl ClientOptions {
         }
     }
 
+    /// Determine if a peer is allowed to handshake with this client.
+    ///
+    /// When no participants are configured every peer is allowed.
+    pub fn is_expected_peer(&self, public_key: &[u8]) -> bool {
+        match &self.participants {
+            Some(participants) => participants
+                .iter()
+                .any(|p| p.as_slice() == public_key),
+            None => true,
+        }
+    }
+
     /// Parse noise parameters from the pattern.
     pub fn params(&self) -> Result<NoiseParams> {
         let pattern = self
@@ -5,30 +5,69 @@ use crate::{
     Transport,
 };
 use futures::StreamExt;
-use polysig_protocol::{Event, SessionId, SessionState};
+use polysig_protocol::{
+    hex, Event, RoundNumber, SessionId, SessionState,
+};
 
 use polysig_driver::{Error, ProtocolDriver, Round};
 
 use super::public_key_to_str;
 
+/// Sender identity for the messages of a protocol driver.
+pub(crate) type Sender<D> =
+    <<D as ProtocolDriver>::Message as Round>::Sender;
+
 /// Connects a network transport with a protocol driver.
+///
+/// Incoming messages must be delivered by the peer for the
+/// party named as the sender, be addressed to this party and
+/// must not be for a round that has already completed.
 pub(crate) struct Bridge<D: ProtocolDriver> {
     pub(crate) transport: Transport,
     pub(crate) driver: Option<D>,
     pub(crate) session: SessionState,
     pub(crate) party_number: NonZeroU16,
+    /// Expected sender for each party in session order.
+    senders: Vec<Option<Sender<D>>>,
+    /// Round for the last messages sent by this party.
+    round: Option<RoundNumber>,
 }
 
 impl<D: ProtocolDriver> Bridge<D> {
+    /// Create a new bridge.
+    ///
+    /// Senders must be in the same order as the session
+    /// participants; messages are rejected from a party
+    /// without a sender.
+    pub fn new<S>(
+        transport: Transport,
+        session: SessionState,
+        party_number: NonZeroU16,
+        senders: impl IntoIterator<Item = S>,
+        driver: D,
+    ) -> Self
+    where
+        S: Into<Option<Sender<D>>>,
+    {
+        Self {
+            transport,
+            driver: Some(driver),
+            session,
+            party_number,
+            senders: senders.into_iter().map(Into::into).collect(),
+            round: None,
+        }
+    }
+
     /// Handle event from the client event loop stream.
     pub async fn handle_event(
         &mut self,
         event: Event,
     ) -> Result<Option<D::Output>> {
         if let Event::JsonMessage {
+            peer_key,
             message,
             session_id,
-            ..
         } = event
         {
             if let Some(session_id) = &session_id {
@@ -40,35 +79,39 @@ impl<D: ProtocolDriver> Bridge<D> {
             }
 
             let message: D::Message = message.deserialize()?;
+            self.verify_message(&peer_key, &message)?;
 
             let driver = self.driver.as_mut().unwrap();
             let round_info =
-                driver.round_info().map_err(Box::from)?;
+                driver.round_info().map_err(driver_error)?;
 
             // println!("{:#?}", round_info);
 
             if !round_info.can_finalize {
-                driver.handle_incoming(message).map_err(Box::from)?;
-                let round_info =
-                    driver.round_info().map_err(Box::from)?;
-                if round_info.can_finalize {
+                driver
+                    .handle_incoming(message)
+                    .map_err(driver_error)?;
+
+                // Keep finalizing while a round can complete
+                // without further messages, so a driver can
+                // finish after sending the last messages
+                loop {
+                    let driver = self.driver.as_mut().unwrap();
+                    let round_info =
+                        driver.round_info().map_err(driver_error)?;
+                    if !round_info.can_finalize {
+                        break;
+                    }
+
                     if let Some(result) = driver
                         .try_finalize_round()
-                        .map_err(Box::from)?
+                        .map_err(driver_error)?
                     {
                         return Ok(Some(result));
                     }
 
                     let messages =
-                        driver.proceed().map_err(Box::from)?;
-
-                    /*
-                    println!(
-                        "*** DISPATCH MESSAGES ({}) ***",
-                        messages.len()
-                    );
-                    */
-
+                        driver.proceed().map_err(driver_error)?;
                     self.dispatch_round_messages(messages).await?;
                 }
             }
@@ -80,16 +123,63 @@ impl<D: ProtocolDriver> Bridge<D> {
     /// Start running the protocol.
     pub async fn execute(&mut self) -> Result<()> {
         let driver = self.driver.as_mut().unwrap();
-        let messages = driver.proceed().map_err(Box::from)?;
+        let messages = driver.proceed().map_err(driver_error)?;
         self.dispatch_round_messages(messages).await?;
         Ok(())
     }
 
+    /// Verify an incoming message was sent by the peer
+    /// that delivered it and is for this party.
+    fn verify_message(
+        &self,
+        peer_key: &[u8],
+        message: &D::Message,
+    ) -> Result<()> {
+        let party_number =
+            self.session.party_number(peer_key).ok_or_else(|| {
+                Error::UnknownPeer(hex::encode(peer_key))
+            })?;
+
+        let index = party_number.get() as usize - 1;
+        let sender = self.senders.get(index).and_then(|s| s.as_ref());
+        if sender != Some(message.sender()) {
+            return Err(
+                Error::SenderMismatch(party_number.get()).into()
+            );
+        }
+
+        if message.receiver() != &self.party_number {
+            return Err(Error::ReceiverMismatch(
+                message.receiver().get(),
+                self.party_number.get(),
+            )
+            .into());
+        }
+
+        if let Some(round) = self.round {
+            if message.round_number() < round {
+                return Err(Error::StaleRound(
+                    message.round_number().get(),
+                    round.get(),
+                )
+                .into());
+            }
+        }
+
+        Ok(())
+    }
+
     /// Send messages to peers.
     async fn dispatch_round_messages(
         &mut self,
         messages: Vec<D::Message>,
     ) -> Result<()> {
+        if let Some(round) =
+            messages.iter().map(|m| m.round_number()).max()
+        {
+            self.round = Some(round);
+        }
+
         for message in messages {
             let party_number = message.receiver();
 
@@ -116,6 +206,25 @@ impl<D: ProtocolDriver> Bridge<D> {
     }
 }
 
+/// Convert a protocol driver error.
+///
+/// Errors from the CGGMP drivers are passed through so callers
+/// can identify a party that caused the protocol to abort.
+fn driver_error<E>(error: E) -> crate::Error
+where
+    E: std::error::Error + Send + Sync + 'static,
+{
+    let error: Box<dyn std::error::Error + Send + Sync + 'static> =
+        Box::new(error);
+    #[cfg(feature = "cggmp")]
+    let error = match error.downcast::<polysig_driver::cggmp::Error>()
+    {
+        Ok(error) => return crate::Error::Cggmp(*error),
+        Err(error) => error,
+    };
+    crate::Error::Generic(error)
+}
+
 /// Wait for a driver to complete.
 pub async fn wait_for_driver<D>(
     stream: &mut EventStream,
@@ -44,14 +44,16 @@ where
                 ))
             })?;
 
-        let driver = CggmpDriver::new(session_id, signer, verifiers)?;
+        let driver =
+            CggmpDriver::new(session_id, signer, verifiers.clone())?;
 
-        let bridge = Bridge {
+        let bridge = Bridge::new(
             transport,
-            driver: Some(driver),
             session,
             party_number,
-        };
+            verifiers,
+            driver,
+        );
         Ok(Self { bridge })
     }
 }
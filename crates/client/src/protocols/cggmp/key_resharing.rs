@@ -45,15 +45,20 @@ where
                 ))
             })?;
 
-        let driver =
-            CggmpDriver::new(session_id, signer, verifiers, inputs)?;
+        let driver = CggmpDriver::new(
+            session_id,
+            signer,
+            verifiers.clone(),
+            inputs,
+        )?;
 
-        let bridge = Bridge {
+        let bridge = Bridge::new(
             transport,
-            driver: Some(driver),
             session,
             party_number,
-        };
+            verifiers,
+            driver,
+        );
         Ok(Self { bridge })
     }
 }
@@ -63,7 +68,7 @@ impl<P> Driver for KeyResharingDriver<P>
 where
     P: SchemeParams + 'static,
 {
-    type Output = ThresholdKeyShare<P, VerifyingKey>;
+    type Output = Option<ThresholdKeyShare<P, VerifyingKey>>;
 
     async fn handle_event(
         &mut self,
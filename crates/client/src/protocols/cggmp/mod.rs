@@ -12,8 +12,8 @@ use polysig_driver::{
     synedrion::{
         self,
         ecdsa::{SigningKey, VerifyingKey},
-        KeyResharingInputs, NewHolder, OldHolder, PrehashedMessage,
-        SchemeParams, SessionId, ThresholdKeyShare,
+        AuxInfo, KeyResharingInputs, NewHolder, OldHolder,
+        PrehashedMessage, SchemeParams, SessionId, ThresholdKeyShare,
     },
 };
 use polysig_protocol::{
@@ -66,7 +66,9 @@ pub async fn dkg<P: SchemeParams + 'static>(
     let t = options.parameters.threshold as usize;
 
     // Create the client
-    let (client, event_loop) = new_client(options).await?;
+    let (client, event_loop) =
+        new_client(options, participant.party().participants())
+            .await?;
 
     let mut transport: Transport = client.into();
 
@@ -117,8 +119,11 @@ pub async fn dkg<P: SchemeParams + 'static>(
                 ack.key_share_verifying_key.clone()
             };
 
-        make_dkg_reshare::<P>(
+        let verifiers = participant.party().verifiers();
+        let (transport, stream, t_key_share) = make_dkg_reshare::<P>(
+            &verifiers[..t],
             t,
+            verifiers,
             t,
             account_verifying_key,
             t_key_share,
@@ -127,9 +132,14 @@ pub async fn dkg<P: SchemeParams + 'static>(
             session,
             session_id,
             participant.signing_key().to_owned(),
-            participant.party().verifiers(),
+            verifiers,
+        )
+        .await?;
+        (
+            transport,
+            stream,
+            t_key_share.ok_or(Error::NoReshareKeyShare)?,
         )
-        .await?
     } else {
         (transport, stream, t_key_share.unwrap())
     };
@@ -267,17 +277,30 @@ async fn make_dkg_init<P: SchemeParams + 'static>(
 }
 
 /// Reshare key shares.
+///
+/// The session participants must be the union of the old
+/// holders and the new holders. Old holders must provide
+/// their key share and parties that are not old holders
+/// join without a key share.
+///
+/// New holders receive a key share for the same account
+/// verifying key; old holders that are not new holders
+/// hand off their share and receive `None`.
 pub async fn reshare<P: SchemeParams>(
     options: SessionOptions,
     participant: Participant,
     session_id: SessionId,
     account_verifying_key: VerifyingKey,
     key_share: Option<ThresholdKeyShare<P, VerifyingKey>>,
+    old_holders: &[VerifyingKey],
     old_threshold: usize,
+    new_holders: &[VerifyingKey],
     new_threshold: usize,
-) -> crate::Result<ThresholdKeyShare<P, VerifyingKey>> {
+) -> crate::Result<Option<ThresholdKeyShare<P, VerifyingKey>>> {
     // Create the client
-    let (client, event_loop) = new_client(options).await?;
+    let (client, event_loop) =
+        new_client(options, participant.party().participants())
+            .await?;
 
     let mut transport: Transport = client.into();
 
@@ -306,7 +329,9 @@ pub async fn reshare<P: SchemeParams>(
 
     let (mut transport, mut stream, new_key_share) =
         make_dkg_reshare::<P>(
+            old_holders,
             old_threshold,
+            new_holders,
             new_threshold,
             account_verifying_key,
             key_share,
@@ -334,7 +359,9 @@ pub async fn reshare<P: SchemeParams>(
 
 /// Drive the key resharing phase of threshold DKG.
 async fn make_dkg_reshare<P: SchemeParams + 'static>(
+    old_holders: &[VerifyingKey],
     old_threshold: usize,
+    new_holders: &[VerifyingKey],
     new_threshold: usize,
     account_verifying_key: VerifyingKey,
     t_key_share: Option<ThresholdKeyShare<P, VerifyingKey>>,
@@ -347,46 +374,49 @@ async fn make_dkg_reshare<P: SchemeParams + 'static>(
 ) -> Result<(
     Transport,
     EventStream,
-    ThresholdKeyShare<P, VerifyingKey>,
+    Option<ThresholdKeyShare<P, VerifyingKey>>,
 )> {
-    let old_holders = BTreeSet::from_iter(
-        verifiers.iter().cloned().take(old_threshold),
-    );
+    let old_holders =
+        old_holders.iter().cloned().collect::<BTreeSet<_>>();
+    let new_holders =
+        new_holders.iter().cloned().collect::<BTreeSet<_>>();
+    let participants = verifiers.iter().collect::<BTreeSet<_>>();
+    let holders =
+        old_holders.union(&new_holders).collect::<BTreeSet<_>>();
+    if participants != holders {
+        return Err(Error::ReshareHolders);
+    }
 
-    let inputs = if let Some(t_key_share) = t_key_share {
-        let new_holder = NewHolder {
-            verifying_key: account_verifying_key,
+    if old_holders.len() < old_threshold {
+        return Err(Error::ReshareOldHolders(
             old_threshold,
-            old_holders,
+            old_holders.len(),
+        ));
+    }
+
+    let verifier = signer.verifying_key();
+    let old_holder =
+        match (old_holders.contains(verifier), t_key_share) {
+            (true, Some(key_share)) => Some(OldHolder { key_share }),
+            (false, None) => None,
+            _ => return Err(Error::ReshareKeyShare),
         };
 
-        KeyResharingInputs {
-            old_holder: Some(OldHolder {
-                key_share: t_key_share.clone(),
-            }),
-            new_holder: Some(new_holder.clone()),
-            new_holders: verifiers
-                .to_vec()
-                .into_iter()
-                .collect::<BTreeSet<_>>(),
-            new_threshold,
-        }
-    } else {
-        let new_holder = NewHolder {
-            verifying_key: account_verifying_key.clone(),
+    let new_holder = if new_holders.contains(verifier) {
+        Some(NewHolder {
+            verifying_key: account_verifying_key,
             old_threshold,
             old_holders,
-        };
+        })
+    } else {
+        None
+    };
 
-        KeyResharingInputs {
-            old_holder: None,
-            new_holder: Some(new_holder.clone()),
-            new_holders: verifiers
-                .to_vec()
-                .into_iter()
-                .collect::<BTreeSet<_>>(),
-            new_threshold,
-        }
+    let inputs = KeyResharingInputs {
+        old_holder,
+        new_holder,
+        new_holders,
+        new_threshold,
     };
 
     let driver = KeyResharingDriver::<P>::new(
@@ -404,16 +434,20 @@ async fn make_dkg_reshare<P: SchemeParams + 'static>(
     Ok((transport, stream, key_share))
 }
 
-/// Sign a message using the CGGMP protocol.
-pub async fn sign<P: SchemeParams + 'static>(
+/// Generate auxiliary information for the CGGMP protocol.
+///
+/// The aux info may be stored and passed to [sign] so that
+/// it is not generated for every signature; the participants
+/// must be the same parties that will later sign.
+pub async fn aux_gen<P: SchemeParams + 'static>(
     options: SessionOptions,
     participant: Participant,
     session_id: SessionId,
-    key_share: &synedrion::KeyShare<P, VerifyingKey>,
-    prehashed_message: &PrehashedMessage,
-) -> crate::Result<RecoverableSignature> {
+) -> crate::Result<AuxInfo<P, VerifyingKey>> {
     // Create the client
-    let (client, event_loop) = new_client(options).await?;
+    let (client, event_loop) =
+        new_client(options, participant.party().participants())
+            .await?;
 
     let mut transport: Transport = client.into();
 
@@ -443,14 +477,82 @@ pub async fn sign<P: SchemeParams + 'static>(
     // Wait for aux gen protocol to complete
     let driver = AuxGenDriver::<P>::new(
         transport,
-        session.clone(),
+        session,
         session_id,
         participant.signing_key().clone(),
         participant.party().verifiers().to_vec(),
     )?;
-    let (transport, aux_info) =
+    let (mut transport, aux_info) =
         wait_for_driver(&mut stream, driver).await?;
 
+    // Close the session and socket
+    if participant.party().is_initiator() {
+        transport.close_session(protocol_session_id).await?;
+        wait_for_session_finish(&mut stream, protocol_session_id)
+            .await?;
+    }
+    transport.close().await?;
+    wait_for_close(&mut stream).await?;
+
+    Ok(aux_info)
+}
+
+/// Sign a message using the CGGMP protocol.
+///
+/// When no aux info is given it is generated before signing
+/// which is computationally expensive; see [aux_gen].
+pub async fn sign<P: SchemeParams + 'static>(
+    options: SessionOptions,
+    participant: Participant,
+    session_id: SessionId,
+    key_share: &synedrion::KeyShare<P, VerifyingKey>,
+    aux_info: Option<&AuxInfo<P, VerifyingKey>>,
+    prehashed_message: &PrehashedMessage,
+) -> crate::Result<RecoverableSignature> {
+    // Create the client
+    let (client, event_loop) =
+        new_client(options, participant.party().participants())
+            .await?;
+
+    let mut transport: Transport = client.into();
+
+    // Handshake with the server
+    transport.connect().await?;
+
+    // Start the event stream
+    let mut stream = event_loop.run();
+
+    // Wait for the session to become active
+    let client_session = if participant.party().is_initiator() {
+        SessionHandler::Initiator(SessionInitiator::new(
+            transport,
+            participant.party().participants().to_vec(),
+        ))
+    } else {
+        SessionHandler::Participant(SessionParticipant::new(
+            transport,
+        ))
+    };
+
+    let (transport, session) =
+        wait_for_session(&mut stream, client_session).await?;
+
+    let protocol_session_id = session.session_id;
+
+    let (transport, aux_info) = if let Some(aux_info) = aux_info {
+        (transport, aux_info.clone())
+    } else {
+        // Wait for aux gen protocol to complete
+        let driver = AuxGenDriver::<P>::new(
+            transport,
+            session.clone(),
+            session_id,
+            participant.signing_key().clone(),
+            participant.party().verifiers().to_vec(),
+        )?;
+        wait_for_driver(&mut stream, driver).await?
+    };
+
     // Wait for message to be signed
     let driver = SignatureDriver::<P>::new(
         transport,
@@ -49,18 +49,19 @@ where
         let driver = CggmpDriver::new(
             session_id,
             signer,
-            verifiers,
+            verifiers.clone(),
             key_share,
             aux_info,
             prehashed_message,
         )?;
 
-        let bridge = Bridge {
+        let bridge = Bridge::new(
             transport,
-            driver: Some(driver),
             session,
             party_number,
-        };
+            verifiers,
+            driver,
+        );
         Ok(Self { bridge })
     }
 }
@@ -0,0 +1,141 @@
+macro_rules! frost_coordinator_impl {
+    () => {
+        /// Coordinate signing a message using the FROST protocol.
+        ///
+        /// The coordinator collects the commitments, sends the
+        /// signing package and aggregates the signature shares
+        /// so it only needs the public key package.
+        ///
+        /// Roles must be in the same order as the session
+        /// participants and the coordinator must be the session
+        /// initiator.
+        pub async fn coordinate(
+            options: SessionOptions,
+            participant: Participant,
+            roles: Vec<Role<Identifier>>,
+            public_key_package: PublicKeyPackage,
+            message: Vec<u8>,
+        ) -> crate::Result<Signature> {
+            if !participant.party().is_initiator() {
+                return Err(crate::Error::CoordinatorInitiator);
+            }
+
+            let signer = participant.signing_key().clone();
+            let verifiers = participant.party().verifiers().to_vec();
+            run_coordinated(
+                options,
+                participant,
+                |transport, session| {
+                    coordinator::new_coordinator_driver(
+                        transport,
+                        session,
+                        roles,
+                        signer,
+                        verifiers,
+                        public_key_package,
+                        message,
+                    )
+                },
+            )
+            .await
+        }
+
+        /// Sign a message as a participant in coordinated
+        /// signing using the FROST protocol.
+        ///
+        /// Commitments and the signature share are only sent
+        /// to the coordinator which assembles the signature.
+        pub async fn sign_coordinated(
+            options: SessionOptions,
+            participant: Participant,
+            roles: Vec<Role<Identifier>>,
+            key_share: KeyShare,
+            message: Vec<u8>,
+        ) -> crate::Result<()> {
+            if participant.party().is_initiator() {
+                return Err(crate::Error::CoordinatorInitiator);
+            }
+
+            let signer = participant.signing_key().clone();
+            let verifiers = participant.party().verifiers().to_vec();
+            run_coordinated(
+                options,
+                participant,
+                |transport, session| {
+                    coordinator::new_participant_driver(
+                        transport, session, roles, signer, verifiers,
+                        key_share, message,
+                    )
+                },
+            )
+            .await
+        }
+
+        async fn run_coordinated<D>(
+            options: SessionOptions,
+            participant: Participant,
+            new_driver: impl FnOnce(
+                Transport,
+                polysig_protocol::SessionState,
+            ) -> crate::Result<D>,
+        ) -> crate::Result<D::Output>
+        where
+            D: crate::protocols::Driver + Into<Transport>,
+        {
+            // Create the client
+            let (client, event_loop) = new_client(
+                options,
+                participant.party().participants(),
+            )
+            .await?;
+
+            let mut transport: Transport = client.into();
+
+            // Handshake with the server
+            transport.connect().await?;
+
+            // Start the event stream
+            let mut stream = event_loop.run();
+
+            // Wait for the session to become active
+            let client_session = if participant.party().is_initiator()
+            {
+                SessionHandler::Initiator(SessionInitiator::new(
+                    transport,
+                    participant.party().participants().to_vec(),
+                ))
+            } else {
+                SessionHandler::Participant(SessionParticipant::new(
+                    transport,
+                ))
+            };
+
+            let (transport, session) =
+                wait_for_session(&mut stream, client_session).await?;
+
+            let protocol_session_id = session.session_id;
+
+            let driver = new_driver(transport, session)?;
+
+            let (mut transport, output) =
+                wait_for_driver(&mut stream, driver).await?;
+
+            // Close the session and socket, the coordinator is
+            // the last to finish so it closes the session
+            if participant.party().is_initiator() {
+                transport.close_session(protocol_session_id).await?;
+                wait_for_session_finish(
+                    &mut stream,
+                    protocol_session_id,
+                )
+                .await?;
+            }
+            transport.close().await?;
+            wait_for_close(&mut stream).await?;
+
+            Ok(output)
+        }
+    };
+}
+
+pub(crate) use frost_coordinator_impl;
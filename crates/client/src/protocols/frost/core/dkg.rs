@@ -6,7 +6,7 @@ use crate::{
 use async_trait::async_trait;
 use polysig_protocol::{Event, PartyNumber, SessionState};
 
-use polysig_driver::ProtocolDriver;
+use polysig_driver::{ProtocolDriver, Round};
 
 /// Generic FROST key generation driver.
 pub struct DkgDriver<D, O>
@@ -26,14 +26,16 @@ where
         transport: Transport,
         session: SessionState,
         party_number: PartyNumber,
+        senders: Vec<<D::Message as Round>::Sender>,
         driver: D,
     ) -> Self {
-        let bridge = Bridge {
+        let bridge = Bridge::new(
             transport,
-            driver: Some(driver),
             session,
             party_number,
-        };
+            senders,
+            driver,
+        );
         Self {
             bridge,
             marker: std::marker::PhantomData,
@@ -85,7 +87,11 @@ macro_rules! frost_dkg_impl {
             let params = options.parameters;
 
             // Create the client
-            let (client, event_loop) = new_client(options).await?;
+            let (client, event_loop) = new_client(
+                options,
+                participant.party().participants(),
+            )
+            .await?;
 
             let mut transport: Transport = client.into();
 
@@ -116,6 +122,8 @@ macro_rules! frost_dkg_impl {
                 session,
                 params,
                 identifiers,
+                participant.signing_key().clone(),
+                participant.party().verifiers().to_vec(),
             )?;
 
             let (transport, key_share) =
@@ -126,6 +134,18 @@ macro_rules! frost_dkg_impl {
 
             Ok(key_share)
         }
+
+        /// Run distributed key generation for the FROST protocol
+        /// with identifiers derived from the participant
+        /// verifying keys.
+        pub async fn dkg_derived(
+            options: SessionOptions,
+            participant: Participant,
+        ) -> crate::Result<KeyShare> {
+            let identifiers =
+                derive_identifiers(participant.party().verifiers())?;
+            dkg(options, participant, identifiers).await
+        }
     };
 }
 
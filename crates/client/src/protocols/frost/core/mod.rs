@@ -1,2 +1,7 @@
+pub(crate) mod coordinator;
 pub(crate) mod dkg;
+pub(crate) mod preprocess;
+pub(crate) mod refresh;
+pub(crate) mod repair;
+pub(crate) mod reshare;
 pub(crate) mod sign;
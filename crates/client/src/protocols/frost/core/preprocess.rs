@@ -0,0 +1,146 @@
+//! Generic nonce preprocessing for FROST.
+use crate::{
+    protocols::{Bridge, Driver},
+    Result, Transport,
+};
+use async_trait::async_trait;
+use polysig_protocol::{Event, PartyNumber, SessionState};
+
+use polysig_driver::{ProtocolDriver, Round};
+
+/// Generic FROST nonce preprocessing driver.
+pub struct PreprocessDriver<D, O>
+where
+    D: ProtocolDriver,
+{
+    bridge: Bridge<D>,
+    marker: std::marker::PhantomData<O>,
+}
+
+impl<D, O> PreprocessDriver<D, O>
+where
+    D: ProtocolDriver,
+{
+    /// Create a new FROST nonce preprocessor.
+    pub fn new(
+        transport: Transport,
+        session: SessionState,
+        party_number: PartyNumber,
+        senders: Vec<<D::Message as Round>::Sender>,
+        driver: D,
+    ) -> Self {
+        let bridge = Bridge::new(
+            transport,
+            session,
+            party_number,
+            senders,
+            driver,
+        );
+        Self {
+            bridge,
+            marker: std::marker::PhantomData,
+        }
+    }
+}
+
+#[async_trait]
+impl<D, O> Driver for PreprocessDriver<D, O>
+where
+    D: ProtocolDriver<Output = O> + Send + Sync,
+    O: Send + Sync,
+{
+    type Output = O;
+
+    async fn handle_event(
+        &mut self,
+        event: Event,
+    ) -> Result<Option<Self::Output>> {
+        Ok(self.bridge.handle_event(event).await?)
+    }
+
+    async fn execute(&mut self) -> Result<()> {
+        Ok(self.bridge.execute().await?)
+    }
+
+    fn into_transport(self) -> Transport {
+        self.bridge.transport
+    }
+}
+
+impl<D, O> From<PreprocessDriver<D, O>> for Transport
+where
+    D: ProtocolDriver,
+{
+    fn from(value: PreprocessDriver<D, O>) -> Self {
+        value.bridge.transport
+    }
+}
+
+macro_rules! frost_preprocess_impl {
+    () => {
+        /// Preprocess a batch of nonces for the FROST protocol.
+        ///
+        /// The commitments are exchanged with every participant
+        /// so that a later call to `sign_preprocessed()` for any
+        /// subset of the participants completes in a single
+        /// round.
+        pub async fn preprocess(
+            options: SessionOptions,
+            participant: Participant,
+            identifiers: Vec<Identifier>,
+            key_share: KeyShare,
+            batch_size: u32,
+        ) -> crate::Result<NonceStore> {
+            // Create the client
+            let (client, event_loop) = new_client(
+                options,
+                participant.party().participants(),
+            )
+            .await?;
+
+            let mut transport: Transport = client.into();
+
+            // Handshake with the server
+            transport.connect().await?;
+
+            // Start the event stream
+            let mut stream = event_loop.run();
+
+            // Wait for the session to become active
+            let client_session = if participant.party().is_initiator()
+            {
+                SessionHandler::Initiator(SessionInitiator::new(
+                    transport,
+                    participant.party().participants().to_vec(),
+                ))
+            } else {
+                SessionHandler::Participant(SessionParticipant::new(
+                    transport,
+                ))
+            };
+
+            let (transport, session) =
+                wait_for_session(&mut stream, client_session).await?;
+
+            let driver = preprocess::new_driver(
+                transport,
+                session,
+                identifiers,
+                participant.signing_key().clone(),
+                participant.party().verifiers().to_vec(),
+                key_share,
+                batch_size,
+            )?;
+
+            let (transport, store) =
+                wait_for_driver(&mut stream, driver).await?;
+
+            transport.close().await?;
+            wait_for_close(&mut stream).await?;
+
+            Ok(store)
+        }
+    };
+}
+
+pub(crate) use frost_preprocess_impl;
@@ -0,0 +1,176 @@
+//! Generic key share refresh for FROST.
+use async_trait::async_trait;
+use polysig_driver::{ProtocolDriver, Round};
+
+use crate::{
+    protocols::{Bridge, Driver},
+    Result, Transport,
+};
+use polysig_protocol::{Event, PartyNumber, SessionState};
+
+/// FROST key share refresh driver.
+pub struct RefreshDriver<D, O>
+where
+    D: ProtocolDriver,
+{
+    bridge: Bridge<D>,
+    marker: std::marker::PhantomData<O>,
+}
+
+impl<D, O> RefreshDriver<D, O>
+where
+    D: ProtocolDriver,
+{
+    /// Create a new FROST key share refresh driver.
+    pub fn new(
+        transport: Transport,
+        session: SessionState,
+        party_number: PartyNumber,
+        senders: Vec<<D::Message as Round>::Sender>,
+        driver: D,
+    ) -> Self {
+        let bridge = Bridge::new(
+            transport,
+            session,
+            party_number,
+            senders,
+            driver,
+        );
+        Self {
+            bridge,
+            marker: std::marker::PhantomData,
+        }
+    }
+}
+
+#[async_trait]
+impl<D, O> Driver for RefreshDriver<D, O>
+where
+    D: ProtocolDriver<Output = O> + Send + Sync,
+    O: Send + Sync,
+{
+    type Output = O;
+
+    async fn handle_event(
+        &mut self,
+        event: Event,
+    ) -> Result<Option<Self::Output>> {
+        Ok(self.bridge.handle_event(event).await?)
+    }
+
+    async fn execute(&mut self) -> Result<()> {
+        Ok(self.bridge.execute().await?)
+    }
+
+    fn into_transport(self) -> Transport {
+        self.bridge.transport
+    }
+}
+
+impl<D, O> From<RefreshDriver<D, O>> for Transport
+where
+    D: ProtocolDriver,
+{
+    fn from(value: RefreshDriver<D, O>) -> Self {
+        value.bridge.transport
+    }
+}
+
+macro_rules! frost_refresh_impl {
+    () => {
+        /// Refresh a key share for the FROST protocol.
+        ///
+        /// All participants holding a key share must take part
+        /// and the first identifier is used as the dealer.
+        ///
+        /// The group verifying key is not changed.
+        ///
+        /// # ⚠️ Warning
+        ///
+        /// The dealer must be trusted. It computes the
+        /// refreshing shares for every participant so it knows
+        /// the difference between the old and the refreshed
+        /// key share of every participant; anyone who obtains
+        /// old key shares and colludes with the dealer can
+        /// derive the refreshed key shares so the refresh gives
+        /// no protection against the dealer. A malicious dealer
+        /// can also send inconsistent refreshing shares which
+        /// leave the participants with key shares that cannot
+        /// sign.
+        ///
+        /// Only use this when the dealer is trusted by all the
+        /// participants; otherwise run a new distributed key
+        /// generation instead.
+        pub async fn refresh(
+            options: SessionOptions,
+            participant: Participant,
+            // Identifiers must match the KeyPackage identifiers!
+            identifiers: Vec<Identifier>,
+            key_share: KeyShare,
+        ) -> crate::Result<KeyShare> {
+            let params = options.parameters;
+
+            // Create the client
+            let (client, event_loop) = new_client(
+                options,
+                participant.party().participants(),
+            )
+            .await?;
+
+            let mut transport: Transport = client.into();
+
+            // Handshake with the server
+            transport.connect().await?;
+
+            // Start the event stream
+            let mut stream = event_loop.run();
+
+            // Wait for the session to become active
+            let client_session = if participant.party().is_initiator()
+            {
+                SessionHandler::Initiator(SessionInitiator::new(
+                    transport,
+                    participant.party().participants().to_vec(),
+                ))
+            } else {
+                SessionHandler::Participant(SessionParticipant::new(
+                    transport,
+                ))
+            };
+
+            let (transport, session) =
+                wait_for_session(&mut stream, client_session).await?;
+
+            let protocol_session_id = session.session_id;
+
+            let driver = refresh::new_driver(
+                transport,
+                session,
+                params,
+                identifiers,
+                participant.signing_key().clone(),
+                participant.party().verifiers().to_vec(),
+                key_share,
+            )?;
+
+            let (mut transport, key_share) =
+                wait_for_driver(&mut stream, driver).await?;
+
+            // Close the session and socket
+            if participant.party().is_initiator() {
+                transport.close_session(protocol_session_id).await?;
+                wait_for_session_finish(
+                    &mut stream,
+                    protocol_session_id,
+                )
+                .await?;
+            }
+            transport.close().await?;
+            wait_for_close(&mut stream).await?;
+
+            Ok(key_share)
+        }
+    };
+}
+
+pub(crate) use frost_refresh_impl;
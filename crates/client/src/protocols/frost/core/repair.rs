@@ -0,0 +1,163 @@
+//! Generic key share repair for FROST.
+use async_trait::async_trait;
+use polysig_driver::{ProtocolDriver, Round};
+
+use crate::{
+    protocols::{Bridge, Driver},
+    Result, Transport,
+};
+use polysig_protocol::{Event, PartyNumber, SessionState};
+
+/// FROST key share repair driver.
+pub struct RepairDriver<D, O>
+where
+    D: ProtocolDriver,
+{
+    bridge: Bridge<D>,
+    marker: std::marker::PhantomData<O>,
+}
+
+impl<D, O> RepairDriver<D, O>
+where
+    D: ProtocolDriver,
+{
+    /// Create a new FROST key share repair driver.
+    pub fn new(
+        transport: Transport,
+        session: SessionState,
+        party_number: PartyNumber,
+        senders: Vec<<D::Message as Round>::Sender>,
+        driver: D,
+    ) -> Self {
+        let bridge = Bridge::new(
+            transport,
+            session,
+            party_number,
+            senders,
+            driver,
+        );
+        Self {
+            bridge,
+            marker: std::marker::PhantomData,
+        }
+    }
+}
+
+#[async_trait]
+impl<D, O> Driver for RepairDriver<D, O>
+where
+    D: ProtocolDriver<Output = O> + Send + Sync,
+    O: Send + Sync,
+{
+    type Output = O;
+
+    async fn handle_event(
+        &mut self,
+        event: Event,
+    ) -> Result<Option<Self::Output>> {
+        Ok(self.bridge.handle_event(event).await?)
+    }
+
+    async fn execute(&mut self) -> Result<()> {
+        Ok(self.bridge.execute().await?)
+    }
+
+    fn into_transport(self) -> Transport {
+        self.bridge.transport
+    }
+}
+
+impl<D, O> From<RepairDriver<D, O>> for Transport
+where
+    D: ProtocolDriver,
+{
+    fn from(value: RepairDriver<D, O>) -> Self {
+        value.bridge.transport
+    }
+}
+
+macro_rules! frost_repair_impl {
+    () => {
+        /// Repair a lost key share for the FROST protocol.
+        ///
+        /// Helpers must provide their key share and the participant
+        /// identified by `lost` must not provide a key share; at
+        /// least threshold helpers must take part.
+        ///
+        /// Returns the repaired key share for the lost participant
+        /// and the existing key share for helpers.
+        pub async fn repair(
+            options: SessionOptions,
+            participant: Participant,
+            // Identifiers must match the KeyPackage identifiers!
+            identifiers: Vec<Identifier>,
+            lost: Identifier,
+            key_share: Option<KeyShare>,
+        ) -> crate::Result<KeyShare> {
+            let params = options.parameters;
+
+            // Create the client
+            let (client, event_loop) = new_client(
+                options,
+                participant.party().participants(),
+            )
+            .await?;
+
+            let mut transport: Transport = client.into();
+
+            // Handshake with the server
+            transport.connect().await?;
+
+            // Start the event stream
+            let mut stream = event_loop.run();
+
+            // Wait for the session to become active
+            let client_session = if participant.party().is_initiator()
+            {
+                SessionHandler::Initiator(SessionInitiator::new(
+                    transport,
+                    participant.party().participants().to_vec(),
+                ))
+            } else {
+                SessionHandler::Participant(SessionParticipant::new(
+                    transport,
+                ))
+            };
+
+            let (transport, session) =
+                wait_for_session(&mut stream, client_session).await?;
+
+            let protocol_session_id = session.session_id;
+
+            let driver = repair::new_driver(
+                transport,
+                session,
+                params,
+                identifiers,
+                participant.signing_key().clone(),
+                participant.party().verifiers().to_vec(),
+                lost,
+                key_share,
+            )?;
+
+            let (mut transport, key_share) =
+                wait_for_driver(&mut stream, driver).await?;
+
+            // Close the session and socket
+            if participant.party().is_initiator() {
+                transport.close_session(protocol_session_id).await?;
+                wait_for_session_finish(
+                    &mut stream,
+                    protocol_session_id,
+                )
+                .await?;
+            }
+            transport.close().await?;
+            wait_for_close(&mut stream).await?;
+
+            Ok(key_share)
+        }
+    };
+}
+
+pub(crate) use frost_repair_impl;
@@ -0,0 +1,169 @@
+//! Generic key resharing for FROST.
+use async_trait::async_trait;
+use polysig_driver::{ProtocolDriver, Round};
+
+use crate::{
+    protocols::{Bridge, Driver},
+    Result, Transport,
+};
+use polysig_protocol::{Event, PartyNumber, SessionState};
+
+/// FROST key resharing driver.
+pub struct ReshareDriver<D, O>
+where
+    D: ProtocolDriver,
+{
+    bridge: Bridge<D>,
+    marker: std::marker::PhantomData<O>,
+}
+
+impl<D, O> ReshareDriver<D, O>
+where
+    D: ProtocolDriver,
+{
+    /// Create a new FROST key resharing driver.
+    pub fn new(
+        transport: Transport,
+        session: SessionState,
+        party_number: PartyNumber,
+        senders: Vec<Option<<D::Message as Round>::Sender>>,
+        driver: D,
+    ) -> Self {
+        let bridge = Bridge::new(
+            transport,
+            session,
+            party_number,
+            senders,
+            driver,
+        );
+        Self {
+            bridge,
+            marker: std::marker::PhantomData,
+        }
+    }
+}
+
+#[async_trait]
+impl<D, O> Driver for ReshareDriver<D, O>
+where
+    D: ProtocolDriver<Output = O> + Send + Sync,
+    O: Send + Sync,
+{
+    type Output = O;
+
+    async fn handle_event(
+        &mut self,
+        event: Event,
+    ) -> Result<Option<Self::Output>> {
+        Ok(self.bridge.handle_event(event).await?)
+    }
+
+    async fn execute(&mut self) -> Result<()> {
+        Ok(self.bridge.execute().await?)
+    }
+
+    fn into_transport(self) -> Transport {
+        self.bridge.transport
+    }
+}
+
+impl<D, O> From<ReshareDriver<D, O>> for Transport
+where
+    D: ProtocolDriver,
+{
+    fn from(value: ReshareDriver<D, O>) -> Self {
+        value.bridge.transport
+    }
+}
+
+macro_rules! frost_reshare_impl {
+    () => {
+        /// Reshare a key for the FROST protocol to a new set of
+        /// participants and a new threshold.
+        ///
+        /// Each party in the session is represented by an optional
+        /// old identifier and an optional new identifier; parties
+        /// with an old identifier must provide their key share
+        /// and parties with a new identifier receive a new key
+        /// share.
+        ///
+        /// The old threshold must match the key shares of the
+        /// old participants.
+        ///
+        /// The group verifying key is not changed.
+        pub async fn reshare(
+            options: SessionOptions,
+            participant: Participant,
+            // Identifiers must match the KeyPackage identifiers!
+            parties: Vec<(Option<Identifier>, Option<Identifier>)>,
+            old_threshold: u16,
+            new_threshold: u16,
+            verifying_key: VerifyingKey,
+            key_share: Option<KeyShare>,
+        ) -> crate::Result<Option<KeyShare>> {
+            // Create the client
+            let (client, event_loop) = new_client(
+                options,
+                participant.party().participants(),
+            )
+            .await?;
+
+            let mut transport: Transport = client.into();
+
+            // Handshake with the server
+            transport.connect().await?;
+
+            // Start the event stream
+            let mut stream = event_loop.run();
+
+            // Wait for the session to become active
+            let client_session = if participant.party().is_initiator()
+            {
+                SessionHandler::Initiator(SessionInitiator::new(
+                    transport,
+                    participant.party().participants().to_vec(),
+                ))
+            } else {
+                SessionHandler::Participant(SessionParticipant::new(
+                    transport,
+                ))
+            };
+
+            let (transport, session) =
+                wait_for_session(&mut stream, client_session).await?;
+
+            let protocol_session_id = session.session_id;
+
+            let driver = reshare::new_driver(
+                transport,
+                session,
+                parties,
+                participant.signing_key().clone(),
+                participant.party().verifiers().to_vec(),
+                old_threshold,
+                new_threshold,
+                verifying_key,
+                key_share,
+            )?;
+
+            let (mut transport, key_share) =
+                wait_for_driver(&mut stream, driver).await?;
+
+            // Close the session and socket
+            if participant.party().is_initiator() {
+                transport.close_session(protocol_session_id).await?;
+                wait_for_session_finish(
+                    &mut stream,
+                    protocol_session_id,
+                )
+                .await?;
+            }
+            transport.close().await?;
+            wait_for_close(&mut stream).await?;
+
+            Ok(key_share)
+        }
+    };
+}
+
+pub(crate) use frost_reshare_impl;
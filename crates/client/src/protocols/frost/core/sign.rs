@@ -1,6 +1,6 @@
 //! Generic signature generation for FROST.
 use async_trait::async_trait;
-use polysig_driver::ProtocolDriver;
+use polysig_driver::{ProtocolDriver, Round};
 
 use crate::{
     protocols::{Bridge, Driver},
@@ -26,14 +26,16 @@ where
         transport: Transport,
         session: SessionState,
         party_number: PartyNumber,
+        senders: Vec<<D::Message as Round>::Sender>,
         driver: D,
     ) -> Self {
-        let bridge = Bridge {
+        let bridge = Bridge::new(
             transport,
-            driver: Some(driver),
             session,
             party_number,
-        };
+            senders,
+            driver,
+        );
         Self {
             bridge,
             marker: std::marker::PhantomData,
@@ -84,11 +86,58 @@ macro_rules! frost_sign_impl {
             identifiers: Vec<Identifier>,
             key_share: KeyShare,
             message: Vec<u8>,
+        ) -> crate::Result<Signature> {
+            run_sign(
+                options,
+                participant,
+                identifiers,
+                key_share,
+                message,
+                None,
+            )
+            .await
+        }
+
+        /// Sign a message using the FROST protocol with nonces
+        /// created by `preprocess()`.
+        ///
+        /// All signers must take the nonces at the same index
+        /// from their nonce store.
+        pub async fn sign_preprocessed(
+            options: SessionOptions,
+            participant: Participant,
+            identifiers: Vec<Identifier>,
+            key_share: KeyShare,
+            nonces: PreprocessedNonces,
+            message: Vec<u8>,
+        ) -> crate::Result<Signature> {
+            run_sign(
+                options,
+                participant,
+                identifiers,
+                key_share,
+                message,
+                Some(nonces),
+            )
+            .await
+        }
+
+        async fn run_sign(
+            options: SessionOptions,
+            participant: Participant,
+            identifiers: Vec<Identifier>,
+            key_share: KeyShare,
+            message: Vec<u8>,
+            nonces: Option<PreprocessedNonces>,
         ) -> crate::Result<Signature> {
             let min_signers = options.parameters.threshold as u16;
 
             // Create the client
-            let (client, event_loop) = new_client(options).await?;
+            let (client, event_loop) = new_client(
+                options,
+                participant.party().participants(),
+            )
+            .await?;
 
             let mut transport: Transport = client.into();
 
@@ -121,9 +170,12 @@ macro_rules! frost_sign_impl {
                 transport,
                 session,
                 identifiers,
+                participant.signing_key().clone(),
+                participant.party().verifiers().to_vec(),
                 min_signers,
                 key_share,
                 message,
+                nonces,
             )?;
 
             let (mut transport, signature) =
@@ -143,6 +195,33 @@ macro_rules! frost_sign_impl {
 
             Ok(signature)
         }
+
+        /// Sign a message using the FROST protocol with
+        /// identifiers derived from the participant verifying
+        /// keys.
+        ///
+        /// The key share must have been created with
+        /// `dkg_derived()` and the verifiers for the party are
+        /// the signers taking part in this session.
+        pub async fn sign_derived(
+            options: SessionOptions,
+            participant: Participant,
+            key_share: KeyShare,
+            message: Vec<u8>,
+        ) -> crate::Result<Signature> {
+            let identifiers = derive_signer_identifiers(
+                participant.party().verifiers(),
+                &key_share,
+            )?;
+            sign(
+                options,
+                participant,
+                identifiers,
+                key_share,
+                message,
+            )
+            .await
+        }
     };
 }
 
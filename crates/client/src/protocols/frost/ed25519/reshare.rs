@@ -0,0 +1,64 @@
+//! Key resharing for FROST Ed25519.
+use crate::{Error, NetworkTransport, Result, Transport};
+use polysig_protocol::{hex, SessionState};
+
+use polysig_driver::{
+    frost::ed25519::{
+        KeyShare, ReshareDriver as FrostDriver, SigningKey,
+        VerifyingKey as PartyVerifyingKey,
+    },
+    frost_ed25519::{Identifier, VerifyingKey},
+};
+
+/// Key resharing driver for FROST Ed25519.
+pub type ReshareDriver =
+    crate::protocols::frost::core::reshare::ReshareDriver<
+        FrostDriver,
+        Option<KeyShare>,
+    >;
+
+/// Create a new FROST Ed25519 key resharing driver.
+#[allow(clippy::too_many_arguments)]
+pub fn new_driver(
+    transport: Transport,
+    session: SessionState,
+    parties: Vec<(Option<Identifier>, Option<Identifier>)>,
+    signer: SigningKey,
+    verifiers: Vec<PartyVerifyingKey>,
+    old_threshold: u16,
+    new_threshold: u16,
+    verifying_key: VerifyingKey,
+    key_share: Option<KeyShare>,
+) -> Result<ReshareDriver> {
+    let party_number = session
+        .party_number(transport.public_key())
+        .ok_or_else(|| {
+        Error::NotSessionParticipant(hex::encode(
+            transport.public_key(),
+        ))
+    })?;
+
+    let senders = parties
+        .iter()
+        .map(|(old, new)| old.or(*new))
+        .collect::<Vec<_>>();
+    let driver = FrostDriver::new(
+        session.session_id,
+        party_number,
+        parties,
+        signer,
+        verifiers,
+        old_threshold,
+        new_threshold,
+        verifying_key,
+        key_share,
+    )?;
+
+    Ok(ReshareDriver::new(
+        transport,
+        session,
+        party_number,
+        senders,
+        driver,
+    ))
+}
@@ -1,6 +1,9 @@
 //! Signature generation for FROST Ed25519.
 use polysig_driver::{
-    frost::ed25519::{KeyShare, SignatureDriver as FrostDriver},
+    frost::ed25519::{
+        KeyShare, PreprocessedNonces, SignatureDriver as FrostDriver,
+        SigningKey, VerifyingKey,
+    },
     frost_ed25519::{Identifier, Signature},
 };
 
@@ -15,13 +18,19 @@ pub type SignatureDriver =
     >;
 
 /// Create a new FROST Ed25519 signature driver.
+///
+/// When preprocessed nonces are given signing completes
+/// after a single round.
 pub fn new_driver(
     transport: Transport,
     session: SessionState,
     identifiers: Vec<Identifier>,
+    signer: SigningKey,
+    verifiers: Vec<VerifyingKey>,
     min_signers: u16,
     key_share: KeyShare,
     message: Vec<u8>,
+    nonces: Option<PreprocessedNonces>,
 ) -> Result<SignatureDriver> {
     let party_number = session
         .party_number(transport.public_key())
@@ -31,18 +40,36 @@ pub fn new_driver(
         ))
     })?;
 
-    let driver = FrostDriver::new(
-        party_number,
-        identifiers,
-        min_signers,
-        key_share,
-        message,
-    )?;
+    let driver = if let Some(nonces) = nonces {
+        FrostDriver::new_preprocessed(
+            session.session_id,
+            party_number,
+            identifiers.clone(),
+            signer,
+            verifiers,
+            min_signers,
+            key_share,
+            message,
+            nonces,
+        )?
+    } else {
+        FrostDriver::new(
+            session.session_id,
+            party_number,
+            identifiers.clone(),
+            signer,
+            verifiers,
+            min_signers,
+            key_share,
+            message,
+        )?
+    };
 
     Ok(SignatureDriver::new(
         transport,
         session,
         party_number,
+        identifiers,
         driver,
     ))
 }
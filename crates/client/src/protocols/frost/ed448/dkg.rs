@@ -0,0 +1,51 @@
+//! Distributed key generation for FROST Ed448.
+use crate::{Error, NetworkTransport, Result, Transport};
+use polysig_protocol::{hex, Parameters, SessionState};
+
+use polysig_driver::{
+    frost::ed448::{
+        DkgDriver as FrostDriver, KeyShare, SigningKey, VerifyingKey,
+    },
+    frost_ed448::Identifier,
+};
+
+/// Distributed key generation driver for FROST Ed448
+pub type DkgDriver = crate::protocols::frost::core::dkg::DkgDriver<
+    FrostDriver,
+    KeyShare,
+>;
+
+/// Create a new FROST Ed448 DKG driver.
+pub fn new_driver(
+    transport: Transport,
+    session: SessionState,
+    params: Parameters,
+    identifiers: Vec<Identifier>,
+    signer: SigningKey,
+    verifiers: Vec<VerifyingKey>,
+) -> Result<DkgDriver> {
+    let party_number = session
+        .party_number(transport.public_key())
+        .ok_or_else(|| {
+        Error::NotSessionParticipant(hex::encode(
+            transport.public_key(),
+        ))
+    })?;
+
+    let driver = FrostDriver::new(
+        session.session_id,
+        party_number,
+        params,
+        identifiers.clone(),
+        signer,
+        verifiers,
+    )?;
+
+    Ok(DkgDriver::new(
+        transport,
+        session,
+        party_number,
+        identifiers,
+        driver,
+    ))
+}
@@ -6,5 +6,17 @@ pub mod ed25519;
 #[cfg(feature = "frost-secp256k1-tr")]
 pub mod secp256k1_tr;
 
+#[cfg(feature = "frost-ristretto255")]
+pub mod ristretto255;
+
+#[cfg(feature = "frost-p256")]
+pub mod p256;
+
+#[cfg(feature = "frost-secp256k1")]
+pub mod secp256k1;
+
+#[cfg(feature = "frost-ed448")]
+pub mod ed448;
+
 #[cfg(feature = "frost")]
 pub(crate) mod core;
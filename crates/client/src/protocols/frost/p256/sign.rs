@@ -0,0 +1,75 @@
+//! Signature generation for FROST P-256.
+use polysig_driver::{
+    frost::p256::{
+        KeyShare, PreprocessedNonces, SignatureDriver as FrostDriver,
+        SigningKey, VerifyingKey,
+    },
+    frost_p256::{Identifier, Signature},
+};
+
+use crate::{Error, NetworkTransport, Result, Transport};
+use polysig_protocol::{hex, SessionState};
+
+/// Signature generation driver for FROST P-256.
+pub type SignatureDriver =
+    crate::protocols::frost::core::sign::SignatureDriver<
+        FrostDriver,
+        Signature,
+    >;
+
+/// Create a new FROST P-256 signature driver.
+///
+/// When preprocessed nonces are given signing completes
+/// after a single round.
+pub fn new_driver(
+    transport: Transport,
+    session: SessionState,
+    identifiers: Vec<Identifier>,
+    signer: SigningKey,
+    verifiers: Vec<VerifyingKey>,
+    min_signers: u16,
+    key_share: KeyShare,
+    message: Vec<u8>,
+    nonces: Option<PreprocessedNonces>,
+) -> Result<SignatureDriver> {
+    let party_number = session
+        .party_number(transport.public_key())
+        .ok_or_else(|| {
+        Error::NotSessionParticipant(hex::encode(
+            transport.public_key(),
+        ))
+    })?;
+
+    let driver = if let Some(nonces) = nonces {
+        FrostDriver::new_preprocessed(
+            session.session_id,
+            party_number,
+            identifiers.clone(),
+            signer,
+            verifiers,
+            min_signers,
+            key_share,
+            message,
+            nonces,
+        )?
+    } else {
+        FrostDriver::new(
+            session.session_id,
+            party_number,
+            identifiers.clone(),
+            signer,
+            verifiers,
+            min_signers,
+            key_share,
+            message,
+        )?
+    };
+
+    Ok(SignatureDriver::new(
+        transport,
+        session,
+        party_number,
+        identifiers,
+        driver,
+    ))
+}
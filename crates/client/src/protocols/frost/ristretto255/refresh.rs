@@ -0,0 +1,55 @@
+//! Key share refresh for FROST Ristretto255.
+use crate::{Error, NetworkTransport, Result, Transport};
+use polysig_protocol::{hex, Parameters, SessionState};
+
+use polysig_driver::{
+    frost::ristretto255::{
+        KeyShare, RefreshDriver as FrostDriver, SigningKey,
+        VerifyingKey,
+    },
+    frost_ristretto255::Identifier,
+};
+
+/// Key share refresh driver for FROST Ristretto255.
+pub type RefreshDriver =
+    crate::protocols::frost::core::refresh::RefreshDriver<
+        FrostDriver,
+        KeyShare,
+    >;
+
+/// Create a new FROST Ristretto255 key share refresh driver.
+pub fn new_driver(
+    transport: Transport,
+    session: SessionState,
+    params: Parameters,
+    identifiers: Vec<Identifier>,
+    signer: SigningKey,
+    verifiers: Vec<VerifyingKey>,
+    key_share: KeyShare,
+) -> Result<RefreshDriver> {
+    let party_number = session
+        .party_number(transport.public_key())
+        .ok_or_else(|| {
+        Error::NotSessionParticipant(hex::encode(
+            transport.public_key(),
+        ))
+    })?;
+
+    let driver = FrostDriver::new(
+        session.session_id,
+        party_number,
+        params,
+        identifiers.clone(),
+        signer,
+        verifiers,
+        key_share,
+    )?;
+
+    Ok(RefreshDriver::new(
+        transport,
+        session,
+        party_number,
+        identifiers,
+        driver,
+    ))
+}
@@ -0,0 +1,106 @@
+//! Coordinated signature generation for FROST Secp256k1.
+use polysig_driver::{
+    frost::{
+        secp256k1::{
+            CoordinatorDriver as FrostCoordinatorDriver, KeyShare,
+            ParticipantDriver as FrostParticipantDriver, SigningKey,
+            VerifyingKey,
+        },
+        Role,
+    },
+    frost_secp256k1::{
+        keys::PublicKeyPackage, Identifier, Signature,
+    },
+};
+
+use crate::{Error, NetworkTransport, Result, Transport};
+use polysig_protocol::{hex, SessionState};
+
+/// Signing coordinator driver for FROST Secp256k1.
+pub type CoordinatorDriver =
+    crate::protocols::frost::core::sign::SignatureDriver<
+        FrostCoordinatorDriver,
+        Signature,
+    >;
+
+/// Coordinated signing participant driver for FROST Secp256k1.
+pub type ParticipantDriver =
+    crate::protocols::frost::core::sign::SignatureDriver<
+        FrostParticipantDriver,
+        (),
+    >;
+
+/// Create a new FROST Secp256k1 signing coordinator driver.
+pub fn new_coordinator_driver(
+    transport: Transport,
+    session: SessionState,
+    roles: Vec<Role<Identifier>>,
+    signer: SigningKey,
+    verifiers: Vec<VerifyingKey>,
+    public_key_package: PublicKeyPackage,
+    message: Vec<u8>,
+) -> Result<CoordinatorDriver> {
+    let party_number = session
+        .party_number(transport.public_key())
+        .ok_or_else(|| {
+        Error::NotSessionParticipant(hex::encode(
+            transport.public_key(),
+        ))
+    })?;
+
+    let driver = FrostCoordinatorDriver::new(
+        session.session_id,
+        party_number,
+        roles.clone(),
+        signer,
+        verifiers,
+        public_key_package,
+        message,
+    )?;
+
+    Ok(CoordinatorDriver::new(
+        transport,
+        session,
+        party_number,
+        roles,
+        driver,
+    ))
+}
+
+/// Create a new FROST Secp256k1 coordinated signing
+/// participant driver.
+pub fn new_participant_driver(
+    transport: Transport,
+    session: SessionState,
+    roles: Vec<Role<Identifier>>,
+    signer: SigningKey,
+    verifiers: Vec<VerifyingKey>,
+    key_share: KeyShare,
+    message: Vec<u8>,
+) -> Result<ParticipantDriver> {
+    let party_number = session
+        .party_number(transport.public_key())
+        .ok_or_else(|| {
+        Error::NotSessionParticipant(hex::encode(
+            transport.public_key(),
+        ))
+    })?;
+
+    let driver = FrostParticipantDriver::new(
+        session.session_id,
+        party_number,
+        roles.clone(),
+        signer,
+        verifiers,
+        key_share,
+        message,
+    )?;
+
+    Ok(ParticipantDriver::new(
+        transport,
+        session,
+        party_number,
+        roles,
+        driver,
+    ))
+}
@@ -0,0 +1,40 @@
+//! Driver for the FROST Secp256k1 protocol.
+use polysig_driver::{
+    frost::secp256k1::{
+        derive_identifiers, derive_signer_identifiers, KeyShare,
+        NonceStore, Participant, PreprocessedNonces, Signature,
+    },
+    frost::Role,
+    frost_secp256k1::{
+        keys::PublicKeyPackage, Identifier, VerifyingKey,
+    },
+};
+
+use crate::{
+    new_client,
+    protocols::frost::core::{
+        coordinator::frost_coordinator_impl, dkg::frost_dkg_impl,
+        preprocess::frost_preprocess_impl,
+        refresh::frost_refresh_impl, repair::frost_repair_impl,
+        reshare::frost_reshare_impl, sign::frost_sign_impl,
+    },
+    wait_for_close, wait_for_driver, wait_for_session,
+    wait_for_session_finish, NetworkTransport, SessionHandler,
+    SessionInitiator, SessionOptions, SessionParticipant, Transport,
+};
+
+mod coordinator;
+mod dkg;
+mod preprocess;
+mod refresh;
+mod repair;
+mod reshare;
+mod sign;
+
+frost_coordinator_impl!();
+frost_dkg_impl!();
+frost_preprocess_impl!();
+frost_refresh_impl!();
+frost_repair_impl!();
+frost_reshare_impl!();
+frost_sign_impl!();
@@ -0,0 +1,55 @@
+//! Nonce preprocessing for FROST Secp256k1.
+use polysig_driver::{
+    frost::secp256k1::{
+        KeyShare, NonceStore, PreprocessDriver as FrostDriver,
+        SigningKey, VerifyingKey,
+    },
+    frost_secp256k1::Identifier,
+};
+
+use crate::{Error, NetworkTransport, Result, Transport};
+use polysig_protocol::{hex, SessionState};
+
+/// Nonce preprocessing driver for FROST Secp256k1.
+pub type PreprocessDriver =
+    crate::protocols::frost::core::preprocess::PreprocessDriver<
+        FrostDriver,
+        NonceStore,
+    >;
+
+/// Create a new FROST Secp256k1 nonce preprocessing driver.
+pub fn new_driver(
+    transport: Transport,
+    session: SessionState,
+    identifiers: Vec<Identifier>,
+    signer: SigningKey,
+    verifiers: Vec<VerifyingKey>,
+    key_share: KeyShare,
+    batch_size: u32,
+) -> Result<PreprocessDriver> {
+    let party_number = session
+        .party_number(transport.public_key())
+        .ok_or_else(|| {
+        Error::NotSessionParticipant(hex::encode(
+            transport.public_key(),
+        ))
+    })?;
+
+    let driver = FrostDriver::new(
+        session.session_id,
+        party_number,
+        identifiers.clone(),
+        signer,
+        verifiers,
+        key_share,
+        batch_size,
+    )?;
+
+    Ok(PreprocessDriver::new(
+        transport,
+        session,
+        party_number,
+        identifiers,
+        driver,
+    ))
+}
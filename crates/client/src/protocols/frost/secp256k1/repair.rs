@@ -0,0 +1,57 @@
+//! Key share repair for FROST Secp256k1.
+use crate::{Error, NetworkTransport, Result, Transport};
+use polysig_protocol::{hex, Parameters, SessionState};
+
+use polysig_driver::{
+    frost::secp256k1::{
+        KeyShare, RepairDriver as FrostDriver, SigningKey,
+        VerifyingKey,
+    },
+    frost_secp256k1::Identifier,
+};
+
+/// Key share repair driver for FROST Secp256k1.
+pub type RepairDriver =
+    crate::protocols::frost::core::repair::RepairDriver<
+        FrostDriver,
+        KeyShare,
+    >;
+
+/// Create a new FROST Secp256k1 key share repair driver.
+pub fn new_driver(
+    transport: Transport,
+    session: SessionState,
+    params: Parameters,
+    identifiers: Vec<Identifier>,
+    signer: SigningKey,
+    verifiers: Vec<VerifyingKey>,
+    lost: Identifier,
+    key_share: Option<KeyShare>,
+) -> Result<RepairDriver> {
+    let party_number = session
+        .party_number(transport.public_key())
+        .ok_or_else(|| {
+        Error::NotSessionParticipant(hex::encode(
+            transport.public_key(),
+        ))
+    })?;
+
+    let driver = FrostDriver::new(
+        session.session_id,
+        party_number,
+        params,
+        identifiers.clone(),
+        signer,
+        verifiers,
+        lost,
+        key_share,
+    )?;
+
+    Ok(RepairDriver::new(
+        transport,
+        session,
+        party_number,
+        identifiers,
+        driver,
+    ))
+}
@@ -3,7 +3,9 @@ use crate::{Error, NetworkTransport, Result, Transport};
 use polysig_protocol::{hex, Parameters, SessionState};
 
 use polysig_driver::{
-    frost::secp256k1_tr::{DkgDriver as FrostDriver, KeyShare},
+    frost::secp256k1_tr::{
+        DkgDriver as FrostDriver, KeyShare, SigningKey, VerifyingKey,
+    },
     frost_secp256k1_tr::Identifier,
 };
 
@@ -19,6 +21,8 @@ pub fn new_driver(
     session: SessionState,
     params: Parameters,
     identifiers: Vec<Identifier>,
+    signer: SigningKey,
+    verifiers: Vec<VerifyingKey>,
 ) -> Result<DkgDriver> {
     let party_number = session
         .party_number(transport.public_key())
@@ -28,7 +32,20 @@ pub fn new_driver(
         ))
     })?;
 
-    let driver = FrostDriver::new(party_number, params, identifiers)?;
+    let driver = FrostDriver::new(
+        session.session_id,
+        party_number,
+        params,
+        identifiers.clone(),
+        signer,
+        verifiers,
+    )?;
 
-    Ok(DkgDriver::new(transport, session, party_number, driver))
+    Ok(DkgDriver::new(
+        transport,
+        session,
+        party_number,
+        identifiers,
+        driver,
+    ))
 }
@@ -1,22 +1,64 @@
 //! Driver for the FROST Secp256k1 Taproot protocol.
 
 use polysig_driver::{
-    frost::secp256k1_tr::{KeyShare, Participant, Signature},
-    frost_secp256k1_tr::Identifier,
+    frost::secp256k1_tr::{
+        derive_identifiers, derive_signer_identifiers,
+        tweak_key_share, KeyShare, NonceStore, Participant,
+        PreprocessedNonces, Signature, TaprootTweak,
+    },
+    frost::Role,
+    frost_secp256k1_tr::{
+        keys::PublicKeyPackage, Identifier, VerifyingKey,
+    },
 };
 
 use crate::{
     new_client,
     protocols::frost::core::{
-        dkg::frost_dkg_impl, sign::frost_sign_impl,
+        coordinator::frost_coordinator_impl, dkg::frost_dkg_impl,
+        preprocess::frost_preprocess_impl,
+        refresh::frost_refresh_impl, repair::frost_repair_impl,
+        reshare::frost_reshare_impl, sign::frost_sign_impl,
     },
     wait_for_close, wait_for_driver, wait_for_session,
     wait_for_session_finish, NetworkTransport, SessionHandler,
     SessionInitiator, SessionOptions, SessionParticipant, Transport,
 };
 
+mod coordinator;
 mod dkg;
+mod preprocess;
+mod refresh;
+mod repair;
+mod reshare;
 mod sign;
 
+frost_coordinator_impl!();
 frost_dkg_impl!();
+frost_preprocess_impl!();
+frost_refresh_impl!();
+frost_repair_impl!();
+frost_reshare_impl!();
 frost_sign_impl!();
+
+/// Sign a message for a Taproot output key.
+///
+/// The key share is tweaked before signing so the signature
+/// is valid for a key path spend of the output key.
+pub async fn sign_taproot(
+    options: SessionOptions,
+    participant: Participant,
+    identifiers: Vec<Identifier>,
+    key_share: KeyShare,
+    message: Vec<u8>,
+    tweak: TaprootTweak,
+) -> crate::Result<Signature> {
+    sign(
+        options,
+        participant,
+        identifiers,
+        tweak_key_share(key_share, &tweak),
+        message,
+    )
+    .await
+}
@@ -1,6 +1,9 @@
 //! Signature generation for FROST Secp256k1 Taproot.
 use polysig_driver::{
-    frost::secp256k1_tr::{KeyShare, SignatureDriver as FrostDriver},
+    frost::secp256k1_tr::{
+        KeyShare, PreprocessedNonces, SignatureDriver as FrostDriver,
+        SigningKey, VerifyingKey,
+    },
     frost_secp256k1_tr::{Identifier, Signature},
 };
 
@@ -15,13 +18,19 @@ pub type SignatureDriver =
     >;
 
 /// Create a new FROST Secp256k1 Taproot signature driver.
+///
+/// When preprocessed nonces are given signing completes
+/// after a single round.
 pub fn new_driver(
     transport: Transport,
     session: SessionState,
     identifiers: Vec<Identifier>,
+    signer: SigningKey,
+    verifiers: Vec<VerifyingKey>,
     min_signers: u16,
     key_share: KeyShare,
     message: Vec<u8>,
+    nonces: Option<PreprocessedNonces>,
 ) -> Result<SignatureDriver> {
     let party_number = session
         .party_number(transport.public_key())
@@ -31,18 +40,36 @@ pub fn new_driver(
         ))
     })?;
 
-    let driver = FrostDriver::new(
-        party_number,
-        identifiers,
-        min_signers,
-        key_share,
-        message,
-    )?;
+    let driver = if let Some(nonces) = nonces {
+        FrostDriver::new_preprocessed(
+            session.session_id,
+            party_number,
+            identifiers.clone(),
+            signer,
+            verifiers,
+            min_signers,
+            key_share,
+            message,
+            nonces,
+        )?
+    } else {
+        FrostDriver::new(
+            session.session_id,
+            party_number,
+            identifiers.clone(),
+            signer,
+            verifiers,
+            min_signers,
+            key_share,
+            message,
+        )?
+    };
 
     Ok(SignatureDriver::new(
         transport,
         session,
         party_number,
+        identifiers,
         driver,
     ))
 }
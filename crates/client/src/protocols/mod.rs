@@ -68,14 +68,18 @@ pub trait Driver {
 }
 
 /// Create a new client using the provided session options.
+///
+/// Peer handshakes are restricted to the session participants.
 pub(crate) async fn new_client(
     options: SessionOptions,
+    participants: &[Vec<u8>],
 ) -> Result<(Client, EventLoop)> {
     let server_url = options.server.server_url;
     let options = ClientOptions {
         keypair: Some(options.keypair),
         server_public_key: Some(options.server.server_public_key),
         pattern: options.server.pattern,
+        participants: Some(participants.to_vec()),
     };
     let url = options.url(&server_url);
     Ok(Client::new(&url, options).await?)
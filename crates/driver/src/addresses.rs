@@ -0,0 +1,338 @@
+//! Address encodings for verifying keys.
+//!
+//! Convert a verifying key into a [PublicKey] and encode it
+//! as a Bitcoin, Ethereum, Solana or Cosmos address.
+//!
+//! Bitcoin SegWit addresses use bech32 ([BIP-173]) for
+//! P2WPKH and bech32m ([BIP-350]) for P2TR; Ethereum
+//! addresses use the mixed case checksum from [EIP-55].
+//!
+//! [BIP-173]: https://github.com/bitcoin/bips/blob/master/bip-0173.mediawiki
+//! [BIP-350]: https://github.com/bitcoin/bips/blob/master/bip-0350.mediawiki
+//! [EIP-55]: https://eips.ethereum.org/EIPS/eip-55
+use crate::{bitcoin::tweak_public_key, Error, Result};
+use k256::elliptic_curve::sec1::ToEncodedPoint;
+use ripemd::Ripemd160;
+use sha2::{Digest, Sha256};
+use sha3::Keccak256;
+use std::str::FromStr;
+
+const BECH32_CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+const BECH32_CONST: u32 = 1;
+const BECH32M_CONST: u32 = 0x2bc830a3;
+
+/// Bitcoin network for SegWit addresses.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum Network {
+    /// Bitcoin mainnet.
+    #[default]
+    Bitcoin,
+    /// Bitcoin testnet and signet.
+    Testnet,
+    /// Bitcoin regression test network.
+    Regtest,
+}
+
+impl Network {
+    /// Human readable part for SegWit addresses.
+    pub fn hrp(&self) -> &'static str {
+        match self {
+            Self::Bitcoin => "bc",
+            Self::Testnet => "tb",
+            Self::Regtest => "bcrt",
+        }
+    }
+}
+
+impl FromStr for Network {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "bitcoin" | "mainnet" => Ok(Self::Bitcoin),
+            "testnet" | "signet" => Ok(Self::Testnet),
+            "regtest" => Ok(Self::Regtest),
+            _ => Err(Error::AddressNetwork(s.to_owned())),
+        }
+    }
+}
+
+/// Public key to encode as an address.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum PublicKey {
+    /// Compressed SEC1 secp256k1 public key.
+    Secp256k1([u8; 33]),
+    /// X-only secp256k1 public key (BIP-340).
+    XOnly([u8; 32]),
+    /// Ed25519 public key.
+    Ed25519([u8; 32]),
+}
+
+impl PublicKey {
+    /// Parse a compressed or uncompressed SEC1
+    /// secp256k1 public key.
+    pub fn secp256k1(bytes: &[u8]) -> Result<Self> {
+        let public_key = k256::PublicKey::from_sec1_bytes(bytes)
+            .map_err(|_| Error::AddressPublicKey)?;
+        let point = public_key.to_encoded_point(true);
+        Ok(Self::Secp256k1(
+            point
+                .as_bytes()
+                .try_into()
+                .map_err(|_| Error::AddressPublicKey)?,
+        ))
+    }
+
+    /// Parse an x-only secp256k1 public key.
+    pub fn x_only(bytes: &[u8]) -> Result<Self> {
+        k256::schnorr::VerifyingKey::from_bytes(bytes)
+            .map_err(|_| Error::AddressPublicKey)?;
+        Ok(Self::XOnly(
+            bytes.try_into().map_err(|_| Error::AddressPublicKey)?,
+        ))
+    }
+
+    /// Parse a compressed Edwards Y Ed25519 public key.
+    pub fn ed25519(bytes: &[u8]) -> Result<Self> {
+        let bytes: [u8; 32] =
+            bytes.try_into().map_err(|_| Error::AddressPublicKey)?;
+        ed25519_dalek::VerifyingKey::from_bytes(&bytes)
+            .map_err(|_| Error::AddressPublicKey)?;
+        Ok(Self::Ed25519(bytes))
+    }
+
+    /// Bitcoin pay to witness public key hash address.
+    pub fn bitcoin_p2wpkh(&self, network: Network) -> Result<String> {
+        let Self::Secp256k1(public_key) = self else {
+            return Err(Error::AddressKind("Bitcoin P2WPKH"));
+        };
+        segwit_address(network.hrp(), 0, &hash160(public_key))
+    }
+
+    /// Bitcoin pay to Taproot address for a key path
+    /// spend without a script tree (BIP-86).
+    pub fn bitcoin_p2tr(&self, network: Network) -> Result<String> {
+        let internal_key: [u8; 32] = match self {
+            Self::Secp256k1(public_key) => {
+                public_key[1..].try_into().unwrap()
+            }
+            Self::XOnly(public_key) => *public_key,
+            Self::Ed25519(_) => {
+                return Err(Error::AddressKind("Bitcoin P2TR"))
+            }
+        };
+        let output_key = tweak_public_key(&internal_key, None)?;
+        segwit_address(network.hrp(), 1, &output_key)
+    }
+
+    /// Ethereum address with an EIP-55 checksum.
+    pub fn ethereum(&self) -> Result<String> {
+        let Self::Secp256k1(public_key) = self else {
+            return Err(Error::AddressKind("Ethereum"));
+        };
+        let public_key = k256::PublicKey::from_sec1_bytes(public_key)
+            .map_err(|_| Error::AddressPublicKey)?;
+        let point = public_key.to_encoded_point(false);
+        // Remove the leading 0x04
+        let digest = Keccak256::digest(&point.as_bytes()[1..]);
+        let address = polysig_protocol::hex::encode(&digest[12..]);
+
+        let checksum = Keccak256::digest(address.as_bytes());
+        let address = address
+            .chars()
+            .enumerate()
+            .map(|(index, c)| {
+                let nibble = checksum[index / 2]
+                    >> if index % 2 == 0 { 4 } else { 0 };
+                if nibble & 0x0f >= 8 {
+                    c.to_ascii_uppercase()
+                } else {
+                    c
+                }
+            })
+            .collect::<String>();
+        Ok(format!("0x{}", address))
+    }
+
+    /// Solana address.
+    pub fn solana(&self) -> Result<String> {
+        let Self::Ed25519(public_key) = self else {
+            return Err(Error::AddressKind("Solana"));
+        };
+        Ok(bs58::encode(public_key).into_string())
+    }
+
+    /// Cosmos SDK account address for a human readable
+    /// part such as `cosmos` or `osmo`.
+    pub fn cosmos(&self, hrp: &str) -> Result<String> {
+        let Self::Secp256k1(public_key) = self else {
+            return Err(Error::AddressKind("Cosmos"));
+        };
+        let data = convert_bits(&hash160(public_key));
+        bech32_encode(hrp, &data, BECH32_CONST)
+    }
+}
+
+impl From<&k256::schnorr::VerifyingKey> for PublicKey {
+    fn from(value: &k256::schnorr::VerifyingKey) -> Self {
+        Self::XOnly(value.to_bytes().into())
+    }
+}
+
+#[cfg(any(feature = "ecdsa", feature = "cggmp"))]
+impl From<&k256::ecdsa::VerifyingKey> for PublicKey {
+    fn from(value: &k256::ecdsa::VerifyingKey) -> Self {
+        let point = value.to_encoded_point(true);
+        Self::Secp256k1(point.as_bytes().try_into().unwrap())
+    }
+}
+
+#[cfg(feature = "eddsa")]
+impl From<&ed25519_dalek::VerifyingKey> for PublicKey {
+    fn from(value: &ed25519_dalek::VerifyingKey) -> Self {
+        Self::Ed25519(value.to_bytes())
+    }
+}
+
+#[cfg(feature = "frost-secp256k1")]
+impl TryFrom<&frost_secp256k1::keys::PublicKeyPackage> for PublicKey {
+    type Error = Error;
+
+    fn try_from(
+        value: &frost_secp256k1::keys::PublicKeyPackage,
+    ) -> Result<Self> {
+        let bytes = value
+            .verifying_key()
+            .serialize()
+            .map_err(crate::frost::Error::from)?;
+        Self::secp256k1(&bytes)
+    }
+}
+
+#[cfg(feature = "frost-secp256k1-tr")]
+impl TryFrom<&frost_secp256k1_tr::keys::PublicKeyPackage>
+    for PublicKey
+{
+    type Error = Error;
+
+    fn try_from(
+        value: &frost_secp256k1_tr::keys::PublicKeyPackage,
+    ) -> Result<Self> {
+        let bytes = value
+            .verifying_key()
+            .serialize()
+            .map_err(crate::frost::Error::from)?;
+        Self::secp256k1(&bytes)
+    }
+}
+
+#[cfg(feature = "frost-ed25519")]
+impl TryFrom<&frost_ed25519::keys::PublicKeyPackage> for PublicKey {
+    type Error = Error;
+
+    fn try_from(
+        value: &frost_ed25519::keys::PublicKeyPackage,
+    ) -> Result<Self> {
+        let bytes = value
+            .verifying_key()
+            .serialize()
+            .map_err(crate::frost::Error::from)?;
+        Self::ed25519(&bytes)
+    }
+}
+
+/// Encode a SegWit address.
+///
+/// Version zero programs use bech32, later
+/// versions use bech32m.
+fn segwit_address(
+    hrp: &str,
+    version: u8,
+    program: &[u8],
+) -> Result<String> {
+    let mut data = vec![version];
+    data.extend(convert_bits(program));
+    let constant = if version == 0 {
+        BECH32_CONST
+    } else {
+        BECH32M_CONST
+    };
+    bech32_encode(hrp, &data, constant)
+}
+
+/// Encode 5-bit values with a bech32 or bech32m checksum.
+fn bech32_encode(
+    hrp: &str,
+    data: &[u8],
+    constant: u32,
+) -> Result<String> {
+    if hrp.is_empty()
+        || hrp.len() > 83
+        || !hrp.bytes().all(|c| (33..=126).contains(&c))
+        || hrp.bytes().any(|c| c.is_ascii_uppercase())
+        || hrp.len() + data.len() + 7 > 90
+    {
+        return Err(Error::AddressPrefix(hrp.to_owned()));
+    }
+
+    let mut values = hrp_expand(hrp);
+    values.extend_from_slice(data);
+    values.extend_from_slice(&[0; 6]);
+    let checksum = bech32_polymod(&values) ^ constant;
+
+    let mut address = format!("{}1", hrp);
+    for value in data {
+        address.push(BECH32_CHARSET[*value as usize] as char);
+    }
+    for index in 0..6 {
+        let value = (checksum >> (5 * (5 - index))) & 0x1f;
+        address.push(BECH32_CHARSET[value as usize] as char);
+    }
+    Ok(address)
+}
+
+fn hrp_expand(hrp: &str) -> Vec<u8> {
+    let mut values: Vec<u8> = hrp.bytes().map(|c| c >> 5).collect();
+    values.push(0);
+    values.extend(hrp.bytes().map(|c| c & 0x1f));
+    values
+}
+
+fn bech32_polymod(values: &[u8]) -> u32 {
+    const GENERATOR: [u32; 5] =
+        [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+    let mut checksum = 1u32;
+    for value in values {
+        let top = checksum >> 25;
+        checksum = ((checksum & 0x1ffffff) << 5) ^ *value as u32;
+        for (index, generator) in GENERATOR.iter().enumerate() {
+            if (top >> index) & 1 == 1 {
+                checksum ^= generator;
+            }
+        }
+    }
+    checksum
+}
+
+/// Convert bytes to 5-bit values padding the last value.
+fn convert_bits(bytes: &[u8]) -> Vec<u8> {
+    let mut values = Vec::with_capacity((bytes.len() * 8 + 4) / 5);
+    let mut accumulator = 0u32;
+    let mut bits = 0;
+    for byte in bytes {
+        accumulator = (accumulator << 8) | *byte as u32;
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            values.push(((accumulator >> bits) & 0x1f) as u8);
+        }
+    }
+    if bits > 0 {
+        values.push(((accumulator << (5 - bits)) & 0x1f) as u8);
+    }
+    values
+}
+
+fn hash160(bytes: &[u8]) -> [u8; 20] {
+    Ripemd160::digest(Sha256::digest(bytes)).into()
+}
@@ -0,0 +1,111 @@
+//! Sign Bitcoin Taproot transactions.
+//!
+//! Partially signed transactions ([BIP-174]) with the Taproot
+//! fields from [BIP-371] are signed for key path spends using
+//! the [BIP-341] signature hash.
+//!
+//! Sign with `SchnorrSigner` using [Psbt::sign_taproot] or
+//! compute the signature hash with [Psbt::taproot_sighash] and
+//! sign it using the FROST Secp256k1 Taproot `SignatureDriver`
+//! then add the signature with [Psbt::add_tap_key_sig].
+//!
+//! [BIP-174]: https://github.com/bitcoin/bips/blob/master/bip-0174.mediawiki
+//! [BIP-341]: https://github.com/bitcoin/bips/blob/master/bip-0341.mediawiki
+//! [BIP-371]: https://github.com/bitcoin/bips/blob/master/bip-0371.mediawiki
+use crate::{Error, Result};
+
+mod psbt;
+mod taproot;
+mod transaction;
+
+pub use psbt::{Map, Psbt, PsbtInput};
+pub use taproot::{
+    taproot_sighash, taproot_sighash_message, tweak_public_key,
+    tweak_signing_key, SIGHASH_ALL, SIGHASH_ANYONECANPAY,
+    SIGHASH_DEFAULT, SIGHASH_NONE, SIGHASH_SINGLE,
+};
+pub use transaction::{OutPoint, Transaction, TxIn, TxOut};
+
+/// Read consensus encoded values.
+struct Reader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, position: 0 }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.position == self.bytes.len()
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.position).copied()
+    }
+
+    fn read(&mut self, length: usize) -> Result<&'a [u8]> {
+        let end = self
+            .position
+            .checked_add(length)
+            .filter(|end| *end <= self.bytes.len())
+            .ok_or(Error::BitcoinDecode)?;
+        let bytes = &self.bytes[self.position..end];
+        self.position = end;
+        Ok(bytes)
+    }
+
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        Ok(self.read(N)?.try_into().unwrap())
+    }
+
+    fn read_u8(&mut self) -> Result<u8> {
+        Ok(self.read(1)?[0])
+    }
+
+    fn read_u32(&mut self) -> Result<u32> {
+        Ok(u32::from_le_bytes(self.read_array()?))
+    }
+
+    fn read_u64(&mut self) -> Result<u64> {
+        Ok(u64::from_le_bytes(self.read_array()?))
+    }
+
+    fn read_compact_size(&mut self) -> Result<u64> {
+        Ok(match self.read_u8()? {
+            0xfd => u16::from_le_bytes(self.read_array()?) as u64,
+            0xfe => self.read_u32()? as u64,
+            0xff => self.read_u64()?,
+            value => value as u64,
+        })
+    }
+
+    fn read_bytes(&mut self) -> Result<&'a [u8]> {
+        let length = self.read_compact_size()?;
+        self.read(
+            usize::try_from(length)
+                .map_err(|_| Error::BitcoinDecode)?,
+        )
+    }
+}
+
+fn write_compact_size(out: &mut Vec<u8>, value: u64) {
+    if value < 0xfd {
+        out.push(value as u8);
+    } else if value <= u16::MAX as u64 {
+        out.push(0xfd);
+        out.extend_from_slice(&(value as u16).to_le_bytes());
+    } else if value <= u32::MAX as u64 {
+        out.push(0xfe);
+        out.extend_from_slice(&(value as u32).to_le_bytes());
+    } else {
+        out.push(0xff);
+        out.extend_from_slice(&value.to_le_bytes());
+    }
+}
+
+fn write_bytes(out: &mut Vec<u8>, bytes: &[u8]) {
+    write_compact_size(out, bytes.len() as u64);
+    out.extend_from_slice(bytes);
+}
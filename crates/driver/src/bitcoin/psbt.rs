@@ -0,0 +1,336 @@
+//! Partially signed Bitcoin transactions (BIP-174).
+use super::{
+    taproot::sighash, write_bytes, Reader, Transaction, TxOut,
+    SIGHASH_DEFAULT,
+};
+use crate::{signers::schnorr::SchnorrSigner, Error, Result};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use k256::schnorr::{Signature, VerifyingKey};
+use std::collections::BTreeMap;
+
+/// Magic bytes for a PSBT.
+const MAGIC: &[u8; 5] = b"psbt\xff";
+
+const PSBT_GLOBAL_UNSIGNED_TX: u8 = 0x00;
+const PSBT_GLOBAL_VERSION: u8 = 0xfb;
+const PSBT_IN_WITNESS_UTXO: u8 = 0x01;
+const PSBT_IN_SIGHASH_TYPE: u8 = 0x03;
+const PSBT_IN_TAP_KEY_SIG: u8 = 0x13;
+const PSBT_IN_TAP_INTERNAL_KEY: u8 = 0x17;
+const PSBT_IN_TAP_MERKLE_ROOT: u8 = 0x18;
+
+/// Key value pairs ordered by key.
+pub type Map = BTreeMap<Vec<u8>, Vec<u8>>;
+
+/// Input of a PSBT.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct PsbtInput {
+    /// Key value pairs for the input.
+    pub fields: Map,
+}
+
+impl PsbtInput {
+    /// Output spent by the input.
+    pub fn witness_utxo(&self) -> Result<Option<TxOut>> {
+        self.field(PSBT_IN_WITNESS_UTXO)
+            .map(|value| {
+                let mut reader = Reader::new(value);
+                let output = TxOut::decode(&mut reader)?;
+                if !reader.is_empty() {
+                    return Err(Error::PsbtField(
+                        PSBT_IN_WITNESS_UTXO,
+                    ));
+                }
+                Ok(output)
+            })
+            .transpose()
+    }
+
+    /// Signature hash type for the input.
+    pub fn sighash_type(&self) -> Result<Option<u32>> {
+        self.field(PSBT_IN_SIGHASH_TYPE)
+            .map(|value| {
+                Ok(u32::from_le_bytes(value.try_into().map_err(
+                    |_| Error::PsbtField(PSBT_IN_SIGHASH_TYPE),
+                )?))
+            })
+            .transpose()
+    }
+
+    /// X-only internal key for the input.
+    pub fn tap_internal_key(&self) -> Result<Option<[u8; 32]>> {
+        self.field_array(PSBT_IN_TAP_INTERNAL_KEY)
+    }
+
+    /// Merkle root of the script tree for the input.
+    pub fn tap_merkle_root(&self) -> Result<Option<[u8; 32]>> {
+        self.field_array(PSBT_IN_TAP_MERKLE_ROOT)
+    }
+
+    /// Signature for a key path spend.
+    pub fn tap_key_sig(&self) -> Option<&[u8]> {
+        self.field(PSBT_IN_TAP_KEY_SIG)
+    }
+
+    fn field(&self, kind: u8) -> Option<&[u8]> {
+        self.fields.get([kind].as_slice()).map(Vec::as_slice)
+    }
+
+    fn field_array(&self, kind: u8) -> Result<Option<[u8; 32]>> {
+        self.field(kind)
+            .map(|value| {
+                value.try_into().map_err(|_| Error::PsbtField(kind))
+            })
+            .transpose()
+    }
+}
+
+/// Partially signed Bitcoin transaction.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Psbt {
+    /// Transaction to sign.
+    pub unsigned_tx: Transaction,
+    /// Global key value pairs except the unsigned transaction.
+    pub global: Map,
+    /// Inputs in the same order as the transaction inputs.
+    pub inputs: Vec<PsbtInput>,
+    /// Outputs in the same order as the transaction outputs.
+    pub outputs: Vec<Map>,
+}
+
+impl Psbt {
+    /// Decode a PSBT.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let mut reader = Reader::new(bytes);
+        if reader.read(MAGIC.len()).ok() != Some(MAGIC.as_slice()) {
+            return Err(Error::PsbtMagic);
+        }
+
+        let mut global = read_map(&mut reader)?;
+        if let Some(version) =
+            global.get([PSBT_GLOBAL_VERSION].as_slice())
+        {
+            if version.as_slice() != [0, 0, 0, 0] {
+                return Err(Error::PsbtField(PSBT_GLOBAL_VERSION));
+            }
+        }
+        let unsigned_tx = global
+            .remove([PSBT_GLOBAL_UNSIGNED_TX].as_slice())
+            .ok_or(Error::PsbtUnsignedTx)?;
+        let mut tx_reader = Reader::new(&unsigned_tx);
+        let unsigned_tx = Transaction::decode(&mut tx_reader, false)?;
+        if !tx_reader.is_empty()
+            || unsigned_tx.inputs.iter().any(|input| {
+                !input.script_sig.is_empty()
+                    || !input.witness.is_empty()
+            })
+        {
+            return Err(Error::PsbtUnsignedTx);
+        }
+
+        let mut inputs = Vec::with_capacity(unsigned_tx.inputs.len());
+        for _ in 0..unsigned_tx.inputs.len() {
+            inputs.push(PsbtInput {
+                fields: read_map(&mut reader)?,
+            });
+        }
+
+        let mut outputs =
+            Vec::with_capacity(unsigned_tx.outputs.len());
+        for _ in 0..unsigned_tx.outputs.len() {
+            outputs.push(read_map(&mut reader)?);
+        }
+
+        if !reader.is_empty() {
+            return Err(Error::BitcoinDecode);
+        }
+
+        Ok(Self {
+            unsigned_tx,
+            global,
+            inputs,
+            outputs,
+        })
+    }
+
+    /// Encode the PSBT.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = MAGIC.to_vec();
+
+        let mut unsigned_tx = Vec::new();
+        self.unsigned_tx.encode(&mut unsigned_tx, false);
+        write_bytes(&mut out, &[PSBT_GLOBAL_UNSIGNED_TX]);
+        write_bytes(&mut out, &unsigned_tx);
+        write_map(&mut out, &self.global);
+
+        for input in &self.inputs {
+            write_map(&mut out, &input.fields);
+        }
+        for output in &self.outputs {
+            write_map(&mut out, output);
+        }
+        out
+    }
+
+    /// Decode a base64 encoded PSBT.
+    pub fn from_base64(encoded: &str) -> Result<Self> {
+        let bytes = STANDARD
+            .decode(encoded.trim())
+            .map_err(|_| Error::BitcoinDecode)?;
+        Self::from_bytes(&bytes)
+    }
+
+    /// Encode the PSBT as base64.
+    pub fn to_base64(&self) -> String {
+        STANDARD.encode(self.to_bytes())
+    }
+
+    /// Compute the signature hash for a key path spend
+    /// of an input.
+    ///
+    /// Uses the signature hash type for the input or
+    /// `SIGHASH_DEFAULT` when no type is set.
+    pub fn taproot_sighash(&self, index: usize) -> Result<[u8; 32]> {
+        let sighash_type = self.input_sighash_type(index)?;
+        let prevouts = self
+            .inputs
+            .iter()
+            .map(|input| input.witness_utxo())
+            .collect::<Result<Vec<_>>>()?;
+        let prevouts =
+            prevouts.iter().map(Option::as_ref).collect::<Vec<_>>();
+        sighash(&self.unsigned_tx, &prevouts, index, sighash_type)
+    }
+
+    /// Add the signature for a key path spend of an input.
+    ///
+    /// When the output spent by the input is known the
+    /// signature must be valid for the output key.
+    pub fn add_tap_key_sig(
+        &mut self,
+        index: usize,
+        signature: &[u8; 64],
+    ) -> Result<()> {
+        let sighash_type = self.input_sighash_type(index)?;
+        let prevout = self.inputs[index].witness_utxo()?;
+        if let Some(prevout) = prevout {
+            let output_key = match prevout.script_pubkey.as_slice() {
+                [0x51, 0x20, key @ ..] if key.len() == 32 => key,
+                _ => {
+                    return Err(Error::PsbtField(
+                        PSBT_IN_WITNESS_UTXO,
+                    ))
+                }
+            };
+            let sighash = self.taproot_sighash(index)?;
+            let verifying_key = VerifyingKey::from_bytes(output_key)?;
+            verifying_key.verify_raw(
+                &sighash,
+                &Signature::try_from(signature.as_slice())?,
+            )?;
+        }
+
+        let mut value = signature.to_vec();
+        if sighash_type != SIGHASH_DEFAULT {
+            value.push(sighash_type);
+        }
+        self.inputs[index]
+            .fields
+            .insert(vec![PSBT_IN_TAP_KEY_SIG], value);
+        Ok(())
+    }
+
+    /// Sign the inputs with an internal key that matches
+    /// the verifying key of the signer.
+    ///
+    /// The signing key is tweaked with the merkle root of
+    /// each input and the indices of the signed inputs
+    /// are returned.
+    pub fn sign_taproot(
+        &mut self,
+        signer: &SchnorrSigner<'_>,
+    ) -> Result<Vec<usize>> {
+        let internal_key: [u8; 32] =
+            signer.verifying_key().to_bytes().into();
+        let mut signed = Vec::new();
+        for index in 0..self.inputs.len() {
+            let input = &self.inputs[index];
+            if input.tap_internal_key()? != Some(internal_key) {
+                continue;
+            }
+            let merkle_root = input.tap_merkle_root()?;
+            let tweaked =
+                signer.taproot_tweak(merkle_root.as_ref())?;
+            let sighash = self.taproot_sighash(index)?;
+            let signature = tweaked.sign_prehash(&sighash)?;
+            self.add_tap_key_sig(index, &signature.to_bytes())?;
+            signed.push(index);
+        }
+        Ok(signed)
+    }
+
+    /// Taproot tweak for signing an input using FROST.
+    ///
+    /// Pass the tweak and the signature hash to the FROST
+    /// Secp256k1 Taproot `SignatureDriver`.
+    #[cfg(feature = "frost-secp256k1-tr")]
+    pub fn taproot_tweak(
+        &self,
+        index: usize,
+    ) -> Result<crate::frost::secp256k1_tr::TaprootTweak> {
+        let input = self
+            .inputs
+            .get(index)
+            .ok_or(Error::BitcoinInputIndex(index))?;
+        Ok(input.tap_merkle_root()?.into())
+    }
+
+    /// Add a FROST signature for a key path spend of an input.
+    #[cfg(feature = "frost-secp256k1-tr")]
+    pub fn add_frost_signature(
+        &mut self,
+        index: usize,
+        signature: &frost_secp256k1_tr::Signature,
+    ) -> Result<()> {
+        let bytes = signature
+            .serialize()
+            .map_err(crate::frost::Error::from)?;
+        let signature: [u8; 64] =
+            bytes.try_into().map_err(|_| Error::TaprootSignature)?;
+        self.add_tap_key_sig(index, &signature)
+    }
+
+    fn input_sighash_type(&self, index: usize) -> Result<u8> {
+        let input = self
+            .inputs
+            .get(index)
+            .ok_or(Error::BitcoinInputIndex(index))?;
+        match input.sighash_type()? {
+            Some(value) => u8::try_from(value)
+                .map_err(|_| Error::SighashType(value)),
+            None => Ok(SIGHASH_DEFAULT),
+        }
+    }
+}
+
+fn read_map(reader: &mut Reader<'_>) -> Result<Map> {
+    let mut map = Map::new();
+    loop {
+        let key = reader.read_bytes()?;
+        if key.is_empty() {
+            return Ok(map);
+        }
+        let value = reader.read_bytes()?;
+        if map.insert(key.to_vec(), value.to_vec()).is_some() {
+            return Err(Error::PsbtDuplicateKey);
+        }
+    }
+}
+
+fn write_map(out: &mut Vec<u8>, map: &Map) {
+    for (key, value) in map {
+        write_bytes(out, key);
+        write_bytes(out, value);
+    }
+    out.push(0x00);
+}
@@ -0,0 +1,225 @@
+//! BIP-341 signature hash and output key tweak.
+use super::{write_bytes, Transaction, TxOut};
+use crate::{Error, Result};
+use k256::{
+    elliptic_curve::{sec1::ToEncodedPoint, PrimeField},
+    schnorr::SigningKey,
+    FieldBytes, ProjectivePoint, PublicKey, Scalar,
+};
+use sha2::{Digest, Sha256};
+
+/// Sign all inputs and outputs, the signature omits the
+/// signature hash type.
+pub const SIGHASH_DEFAULT: u8 = 0x00;
+/// Sign all inputs and outputs.
+pub const SIGHASH_ALL: u8 = 0x01;
+/// Sign all inputs and no outputs.
+pub const SIGHASH_NONE: u8 = 0x02;
+/// Sign all inputs and the output with the same index.
+pub const SIGHASH_SINGLE: u8 = 0x03;
+/// Only sign the input being signed, combined with
+/// one of the other signature hash types.
+pub const SIGHASH_ANYONECANPAY: u8 = 0x80;
+
+/// Compute the signature hash for a key path spend.
+///
+/// The previous outputs must be in the same order as
+/// the transaction inputs.
+pub fn taproot_sighash(
+    tx: &Transaction,
+    prevouts: &[TxOut],
+    input_index: usize,
+    sighash_type: u8,
+) -> Result<[u8; 32]> {
+    let prevouts = prevouts.iter().map(Some).collect::<Vec<_>>();
+    sighash(tx, &prevouts, input_index, sighash_type)
+}
+
+/// Compute the message committed to by the signature hash
+/// for a key path spend (`SigMsg` in BIP-341).
+///
+/// The previous outputs must be in the same order as
+/// the transaction inputs.
+pub fn taproot_sighash_message(
+    tx: &Transaction,
+    prevouts: &[TxOut],
+    input_index: usize,
+    sighash_type: u8,
+) -> Result<Vec<u8>> {
+    let prevouts = prevouts.iter().map(Some).collect::<Vec<_>>();
+    sighash_message(tx, &prevouts, input_index, sighash_type)
+}
+
+/// Compute the signature hash for a key path spend when
+/// only some previous outputs are known.
+///
+/// All previous outputs are required unless the signature
+/// hash type includes `SIGHASH_ANYONECANPAY`.
+pub(super) fn sighash(
+    tx: &Transaction,
+    prevouts: &[Option<&TxOut>],
+    input_index: usize,
+    sighash_type: u8,
+) -> Result<[u8; 32]> {
+    let msg =
+        sighash_message(tx, prevouts, input_index, sighash_type)?;
+    Ok(tagged_hash("TapSighash", &[&msg]))
+}
+
+fn sighash_message(
+    tx: &Transaction,
+    prevouts: &[Option<&TxOut>],
+    input_index: usize,
+    sighash_type: u8,
+) -> Result<Vec<u8>> {
+    if !matches!(sighash_type, 0x00..=0x03 | 0x81..=0x83) {
+        return Err(Error::SighashType(sighash_type as u32));
+    }
+    let input = tx
+        .inputs
+        .get(input_index)
+        .ok_or(Error::BitcoinInputIndex(input_index))?;
+    if prevouts.len() != tx.inputs.len() {
+        return Err(Error::SighashPrevouts(
+            tx.inputs.len(),
+            prevouts.len(),
+        ));
+    }
+
+    let anyone_can_pay = sighash_type & SIGHASH_ANYONECANPAY != 0;
+    let output_type = sighash_type & 0x03;
+
+    let mut msg = Vec::new();
+    // Signature hash epoch
+    msg.push(0x00);
+    msg.push(sighash_type);
+    msg.extend_from_slice(&tx.version.to_le_bytes());
+    msg.extend_from_slice(&tx.lock_time.to_le_bytes());
+
+    if !anyone_can_pay {
+        let prevouts = prevouts
+            .iter()
+            .enumerate()
+            .map(|(index, prevout)| {
+                prevout.ok_or(Error::BitcoinPrevout(index))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut outpoints = Sha256::new();
+        let mut amounts = Sha256::new();
+        let mut scripts = Sha256::new();
+        let mut sequences = Sha256::new();
+        for (input, prevout) in tx.inputs.iter().zip(prevouts) {
+            outpoints.update(input.previous_output.txid);
+            outpoints
+                .update(input.previous_output.vout.to_le_bytes());
+            amounts.update(prevout.value.to_le_bytes());
+            let mut script = Vec::new();
+            write_bytes(&mut script, &prevout.script_pubkey);
+            scripts.update(script);
+            sequences.update(input.sequence.to_le_bytes());
+        }
+        msg.extend_from_slice(&outpoints.finalize());
+        msg.extend_from_slice(&amounts.finalize());
+        msg.extend_from_slice(&scripts.finalize());
+        msg.extend_from_slice(&sequences.finalize());
+    }
+
+    if output_type != SIGHASH_NONE && output_type != SIGHASH_SINGLE {
+        let mut outputs = Vec::new();
+        for output in &tx.outputs {
+            output.encode(&mut outputs);
+        }
+        msg.extend_from_slice(&Sha256::digest(&outputs));
+    }
+
+    // Key path spend without an annex
+    msg.push(0x00);
+
+    if anyone_can_pay {
+        let prevout = prevouts[input_index]
+            .ok_or(Error::BitcoinPrevout(input_index))?;
+        msg.extend_from_slice(&input.previous_output.txid);
+        msg.extend_from_slice(
+            &input.previous_output.vout.to_le_bytes(),
+        );
+        prevout.encode(&mut msg);
+        msg.extend_from_slice(&input.sequence.to_le_bytes());
+    } else {
+        msg.extend_from_slice(&(input_index as u32).to_le_bytes());
+    }
+
+    if output_type == SIGHASH_SINGLE {
+        let output = tx
+            .outputs
+            .get(input_index)
+            .ok_or(Error::SighashSingle(input_index))?;
+        let mut encoded = Vec::new();
+        output.encode(&mut encoded);
+        msg.extend_from_slice(&Sha256::digest(&encoded));
+    }
+
+    Ok(msg)
+}
+
+/// Compute the x-only output key for an internal key.
+///
+/// Without a merkle root the output key commits to
+/// an unspendable script path (BIP-86).
+pub fn tweak_public_key(
+    internal_key: &[u8; 32],
+    merkle_root: Option<&[u8; 32]>,
+) -> Result<[u8; 32]> {
+    let mut encoded = [0x02; 33];
+    encoded[1..].copy_from_slice(internal_key);
+    let point = PublicKey::from_sec1_bytes(&encoded)
+        .map_err(|_| Error::TaprootOutputKey)?
+        .to_projective();
+
+    let tweak = tweak_scalar(internal_key, merkle_root)?;
+    let output_key = point + ProjectivePoint::GENERATOR * tweak;
+    let output_key = output_key.to_affine().to_encoded_point(false);
+    let x = output_key.x().ok_or(Error::TaprootOutputKey)?;
+    Ok((*x).into())
+}
+
+/// Tweak a signing key so signatures verify against the
+/// output key for the signing key.
+pub fn tweak_signing_key(
+    signing_key: &SigningKey,
+    merkle_root: Option<&[u8; 32]>,
+) -> Result<SigningKey> {
+    let internal_key: [u8; 32] =
+        signing_key.verifying_key().to_bytes().into();
+    // Secret key for the internal key with an even Y coordinate
+    let secret: Option<Scalar> =
+        Scalar::from_repr(signing_key.to_bytes()).into();
+    let secret = secret.ok_or(Error::TaprootOutputKey)?;
+    let tweaked = secret + tweak_scalar(&internal_key, merkle_root)?;
+    Ok(SigningKey::from_bytes(&tweaked.to_bytes())?)
+}
+
+fn tweak_scalar(
+    internal_key: &[u8; 32],
+    merkle_root: Option<&[u8; 32]>,
+) -> Result<Scalar> {
+    let merkle_root: &[u8] = match merkle_root {
+        Some(root) => root,
+        None => &[],
+    };
+    let tweak = tagged_hash("TapTweak", &[internal_key, merkle_root]);
+    Option::from(Scalar::from_repr(FieldBytes::from(tweak)))
+        .ok_or(Error::TaprootOutputKey)
+}
+
+/// Tagged hash from BIP-340.
+fn tagged_hash(tag: &str, parts: &[&[u8]]) -> [u8; 32] {
+    let tag = Sha256::digest(tag.as_bytes());
+    let mut hasher = Sha256::new();
+    hasher.update(tag);
+    hasher.update(tag);
+    for part in parts {
+        hasher.update(part);
+    }
+    hasher.finalize().into()
+}
@@ -0,0 +1,179 @@
+//! Bitcoin transaction encoding.
+use super::{write_bytes, write_compact_size, Reader};
+use crate::{Error, Result};
+use sha2::{Digest, Sha256};
+
+/// Reference to an output of a previous transaction.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct OutPoint {
+    /// Transaction identifier in internal byte order.
+    pub txid: [u8; 32],
+    /// Index of the output.
+    pub vout: u32,
+}
+
+/// Transaction input.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct TxIn {
+    /// Output spent by this input.
+    pub previous_output: OutPoint,
+    /// Signature script.
+    pub script_sig: Vec<u8>,
+    /// Sequence number.
+    pub sequence: u32,
+    /// Witness stack.
+    pub witness: Vec<Vec<u8>>,
+}
+
+/// Transaction output.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct TxOut {
+    /// Amount in satoshis.
+    pub value: u64,
+    /// Locking script.
+    pub script_pubkey: Vec<u8>,
+}
+
+impl TxOut {
+    pub(super) fn decode(reader: &mut Reader<'_>) -> Result<Self> {
+        Ok(Self {
+            value: reader.read_u64()?,
+            script_pubkey: reader.read_bytes()?.to_vec(),
+        })
+    }
+
+    pub(super) fn encode(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.value.to_le_bytes());
+        write_bytes(out, &self.script_pubkey);
+    }
+}
+
+/// Bitcoin transaction.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Transaction {
+    /// Transaction version.
+    pub version: i32,
+    /// Transaction inputs.
+    pub inputs: Vec<TxIn>,
+    /// Transaction outputs.
+    pub outputs: Vec<TxOut>,
+    /// Lock time.
+    pub lock_time: u32,
+}
+
+impl Transaction {
+    /// Decode a transaction with or without witness data.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let mut reader = Reader::new(bytes);
+        let tx = Self::decode(&mut reader, true)?;
+        if !reader.is_empty() {
+            return Err(Error::BitcoinDecode);
+        }
+        Ok(tx)
+    }
+
+    /// Encode the transaction including witness data
+    /// when any input has a witness.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        self.encode(&mut out, self.has_witness());
+        out
+    }
+
+    /// Transaction identifier in internal byte order.
+    ///
+    /// Reverse the bytes to display the identifier.
+    pub fn txid(&self) -> [u8; 32] {
+        let mut out = Vec::new();
+        self.encode(&mut out, false);
+        Sha256::digest(Sha256::digest(&out)).into()
+    }
+
+    fn has_witness(&self) -> bool {
+        self.inputs.iter().any(|input| !input.witness.is_empty())
+    }
+
+    pub(super) fn decode(
+        reader: &mut Reader<'_>,
+        allow_witness: bool,
+    ) -> Result<Self> {
+        let version = reader.read_u32()? as i32;
+
+        // Segregated witness marker and flag
+        let has_witness = allow_witness && reader.peek() == Some(0);
+        if has_witness {
+            reader.read_u8()?;
+            if reader.read_u8()? != 1 {
+                return Err(Error::BitcoinDecode);
+            }
+        }
+
+        let mut inputs = Vec::new();
+        for _ in 0..reader.read_compact_size()? {
+            let txid = reader.read_array()?;
+            let vout = reader.read_u32()?;
+            let script_sig = reader.read_bytes()?.to_vec();
+            let sequence = reader.read_u32()?;
+            inputs.push(TxIn {
+                previous_output: OutPoint { txid, vout },
+                script_sig,
+                sequence,
+                witness: vec![],
+            });
+        }
+
+        let mut outputs = Vec::new();
+        for _ in 0..reader.read_compact_size()? {
+            outputs.push(TxOut::decode(reader)?);
+        }
+
+        if has_witness {
+            for input in inputs.iter_mut() {
+                for _ in 0..reader.read_compact_size()? {
+                    input.witness.push(reader.read_bytes()?.to_vec());
+                }
+            }
+        }
+
+        let lock_time = reader.read_u32()?;
+        Ok(Self {
+            version,
+            inputs,
+            outputs,
+            lock_time,
+        })
+    }
+
+    pub(super) fn encode(&self, out: &mut Vec<u8>, witness: bool) {
+        out.extend_from_slice(&self.version.to_le_bytes());
+        if witness {
+            out.extend_from_slice(&[0x00, 0x01]);
+        }
+
+        write_compact_size(out, self.inputs.len() as u64);
+        for input in &self.inputs {
+            out.extend_from_slice(&input.previous_output.txid);
+            out.extend_from_slice(
+                &input.previous_output.vout.to_le_bytes(),
+            );
+            write_bytes(out, &input.script_sig);
+            out.extend_from_slice(&input.sequence.to_le_bytes());
+        }
+
+        write_compact_size(out, self.outputs.len() as u64);
+        for output in &self.outputs {
+            output.encode(out);
+        }
+
+        if witness {
+            for input in &self.inputs {
+                write_compact_size(out, input.witness.len() as u64);
+                for item in &input.witness {
+                    write_bytes(out, item);
+                }
+            }
+        }
+
+        out.extend_from_slice(&self.lock_time.to_le_bytes());
+    }
+}
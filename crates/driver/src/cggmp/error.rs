@@ -1,6 +1,71 @@
 use k256::ecdsa::VerifyingKey;
+use std::{any::Any, fmt};
+use synedrion::{
+    sessions::{
+        self, LocalError, ProvableError, RemoteError, RemoteErrorEnum,
+    },
+    ProtocolResult,
+};
 use thiserror::Error;
 
+/// Evidence from the protocol library.
+///
+/// The concrete type depends on the protocol that was running,
+/// a provable fault during key generation is a
+/// `ProvableError<KeyGenResult<P, VerifyingKey>>` and a
+/// correctness proof is the `CorrectnessProof` of the
+/// protocol result; use [Evidence::downcast_ref] to
+/// access the typed value.
+pub struct Evidence(Box<dyn AnyEvidence>);
+
+impl Evidence {
+    fn new<T: AnyEvidence>(value: T) -> Self {
+        Self(Box::new(value))
+    }
+
+    /// Get a reference to the typed evidence.
+    ///
+    /// Returns `None` when the evidence is not of type `T`.
+    pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        self.0.as_any().downcast_ref::<T>()
+    }
+}
+
+impl fmt::Debug for Evidence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+trait AnyEvidence: Any + fmt::Debug + Send + Sync {
+    fn as_any(&self) -> &dyn Any;
+}
+
+impl<T: Any + fmt::Debug + Send + Sync> AnyEvidence for T {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Fault committed by a remote party.
+#[derive(Debug)]
+pub enum Fault {
+    /// Fault that cannot be proven to a third party, for example
+    /// a message with an invalid signature.
+    Remote(RemoteErrorEnum),
+    /// Provable fault with evidence from the protocol library.
+    Provable(Evidence),
+}
+
+impl fmt::Display for Fault {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Fault::Remote(e) => write!(f, "{}", e),
+            Fault::Provable(_) => write!(f, "provable fault"),
+        }
+    }
+}
+
 /// Errors generated by the protocol.
 #[derive(Debug, Error)]
 pub enum Error {
@@ -14,6 +79,30 @@ pub enum Error {
     #[error("{0}")]
     RemoteError(String),
 
+    /// Protocol was aborted due to a fault by a remote party.
+    ///
+    /// The party can be excluded from a subsequent attempt
+    /// to run the protocol.
+    #[error("protocol aborted in round {round} by party {party:?}: {fault}")]
+    Abort {
+        /// Verifying key of the offending party.
+        party: VerifyingKey,
+        /// Round number when the fault was detected.
+        round: u8,
+        /// Fault committed by the party.
+        fault: Fault,
+    },
+
+    /// Protocol was aborted but the fault could not be
+    /// attributed to a party.
+    ///
+    /// Includes the proof of correctness for this party
+    /// when the protocol library provides one.
+    #[error(
+        "protocol aborted in round {0} without an attributable fault"
+    )]
+    UnattributedAbort(u8, Option<Evidence>),
+
     /// Signature verification failed.
     #[error("failed to verify generated signature")]
     VerifySignature,
@@ -22,6 +111,35 @@ pub enum Error {
     #[error("could not find an ACK for key init phase")]
     NoKeyInitAck,
 
+    /// Error generated when a key share created by a trusted
+    /// dealer does not match the verifying key for the
+    /// imported signing key.
+    #[error("key share does not match the imported verifying key")]
+    DealerVerifyingKey,
+
+    /// Error generated when there are not enough key shares
+    /// to reconstruct a signing key.
+    #[error(
+        "reconstruction requires at least {0} key shares but got {1}"
+    )]
+    ReconstructThreshold(usize, usize),
+
+    /// Error generated when a reconstructed signing key does not
+    /// match the verifying key for the key shares.
+    #[error("reconstructed key does not match the verifying key")]
+    ReconstructVerifyingKey,
+
+    /// Error generated when a key share cannot be used for
+    /// reconstruction because it is a duplicate or the secret
+    /// share could not be read.
+    #[error("invalid key share for reconstruction")]
+    ReconstructKeyShare,
+
+    /// Error generated when a serialized key share does not
+    /// match the format expected for reconstruction.
+    #[error("unsupported key share format: {0}")]
+    ReconstructFormat(String),
+
     /// Attempt to finish a protocol when another round is expected.
     #[error("protocol is not finished, another round is available")]
     NotFinished,
@@ -53,6 +171,56 @@ impl From<synedrion::sessions::RemoteError<VerifyingKey>> for Error {
     }
 }
 
+/// Convert errors from a protocol session into driver errors
+/// that identify the round and the offending party.
+pub(super) trait IntoAbort {
+    /// Convert into an error for the given round.
+    fn into_abort(self, round: u8) -> Error;
+}
+
+impl IntoAbort for LocalError {
+    fn into_abort(self, _round: u8) -> Error {
+        self.into()
+    }
+}
+
+impl IntoAbort for RemoteError<VerifyingKey> {
+    fn into_abort(self, round: u8) -> Error {
+        Error::Abort {
+            party: self.party,
+            round,
+            fault: Fault::Remote(self.error),
+        }
+    }
+}
+
+impl<Res> IntoAbort for sessions::Error<Res, VerifyingKey>
+where
+    Res: ProtocolResult + 'static,
+    ProvableError<Res>: Send + Sync,
+    Res::CorrectnessProof: Send + Sync,
+{
+    fn into_abort(self, round: u8) -> Error {
+        match self {
+            sessions::Error::Local(e) => e.into_abort(round),
+            sessions::Error::Remote(e) => e.into_abort(round),
+            sessions::Error::Provable { party, error } => {
+                Error::Abort {
+                    party,
+                    round,
+                    fault: Fault::Provable(Evidence::new(error)),
+                }
+            }
+            sessions::Error::Proof { proof } => {
+                Error::UnattributedAbort(
+                    round,
+                    Some(Evidence::new(proof)),
+                )
+            }
+        }
+    }
+}
+
 #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
 impl From<Error> for wasm_bindgen::JsValue {
     fn from(value: Error) -> Self {
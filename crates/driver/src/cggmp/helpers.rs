@@ -2,10 +2,12 @@
 use rand::rngs::OsRng;
 use std::num::NonZeroU16;
 
-use super::Result;
+use super::{error::IntoAbort, Error, Result};
 use synedrion::{
     ecdsa::{Signature, SigningKey, VerifyingKey},
-    sessions::{PreprocessedMessage, RoundAccumulator, Session},
+    sessions::{
+        PreprocessedMessage, ProvableError, RoundAccumulator, Session,
+    },
     ProtocolResult,
 };
 
@@ -40,6 +42,8 @@ pub fn proceed<Res>(
 ) -> Result<Vec<RoundMessage<MessageOut, VerifyingKey>>>
 where
     Res: ProtocolResult + Send + 'static,
+    ProvableError<Res>: Send + Sync,
+    Res::CorrectnessProof: Send + Sync,
 {
     let mut outgoing = Vec::new();
 
@@ -90,15 +94,19 @@ where
         });
     }
 
+    let round = session.current_round().0;
     for preprocessed in cached_messages.drain(..) {
         // In production usage, this will happen in a spawned task.
         // println!("{key_str}: applying a cached message");
         let mut rng = OsRng;
-        let result =
-            session.process_message(&mut rng, preprocessed).unwrap();
+        let result = session
+            .process_message(&mut rng, preprocessed)
+            .map_err(|e| e.into_abort(round))?;
 
         // This will happen in a host task.
-        accum.add_processed_message(result)??;
+        accum
+            .add_processed_message(result)?
+            .map_err(|e| e.into_abort(round))?;
     }
 
     Ok(outgoing)
@@ -111,6 +119,8 @@ pub fn handle_incoming<Res>(
 ) -> Result<()>
 where
     Res: ProtocolResult + Send + 'static,
+    ProvableError<Res>: Send + Sync,
+    Res::CorrectnessProof: Send + Sync,
 {
     if !session.can_finalize(accum)? {
         /*
@@ -125,15 +135,19 @@ where
 
         // This can be checked if a timeout expired, to see
         // which nodes have not responded yet.
+        let round: u8 = message.round.get().try_into()?;
         let unresponsive_parties = session.missing_messages(accum)?;
-        assert!(!unresponsive_parties.is_empty());
+        if unresponsive_parties.is_empty() {
+            return Err(Error::UnattributedAbort(round, None));
+        }
 
         // let message_round_number = message.round_number();
         let (body, from) = message.into_body();
 
         // Perform quick checks before proceeding with the verification.
-        let preprocessed =
-            session.preprocess_message(accum, &from, body).unwrap();
+        let preprocessed = session
+            .preprocess_message(accum, &from, body)
+            .map_err(|e| e.into_abort(round))?;
 
         if let Some(preprocessed) = preprocessed {
             /*
@@ -146,10 +160,12 @@ where
             let mut rng = OsRng;
             let result = session
                 .process_message(&mut rng, preprocessed)
-                .unwrap();
+                .map_err(|e| e.into_abort(round))?;
 
             // This will happen in a host task.
-            accum.add_processed_message(result)??;
+            accum
+                .add_processed_message(result)?
+                .map_err(|e| e.into_abort(round))?;
         }
     }
 
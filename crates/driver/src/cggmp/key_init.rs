@@ -2,7 +2,7 @@
 use rand::rngs::OsRng;
 use std::collections::BTreeSet;
 
-use super::{Error, Result};
+use super::{error::IntoAbort, Error, Result};
 use synedrion::{
     ecdsa::{Signature, SigningKey, VerifyingKey},
     make_key_init_session,
@@ -116,7 +116,11 @@ where
         println!("{key_str}: finalizing the round");
         */
 
-        match session.finalize_round(&mut OsRng, accum).unwrap() {
+        let round = session.current_round().0;
+        match session
+            .finalize_round(&mut OsRng, accum)
+            .map_err(|e| e.into_abort(round))?
+        {
             FinalizeOutcome::Success(result) => Ok(Some(result)),
             FinalizeOutcome::AnotherRound {
                 session: new_session,
@@ -2,7 +2,7 @@
 use rand::rngs::OsRng;
 use std::collections::BTreeSet;
 
-use super::{Error, Result};
+use super::{error::IntoAbort, Error, Result};
 use synedrion::{
     ecdsa::{Signature, SigningKey, VerifyingKey},
     make_key_resharing_session,
@@ -19,6 +19,9 @@ use crate::{ProtocolDriver, RoundInfo, RoundMessage};
 use super::MessageOut;
 
 /// CGGMP key resharing driver.
+///
+/// Outputs a key share for new holders; old holders that
+/// are not new holders finish without a key share.
 pub struct KeyResharingDriver<P>
 where
     P: SchemeParams + 'static,
@@ -81,7 +84,7 @@ where
 {
     type Error = Error;
     type Message = RoundMessage<MessageOut, VerifyingKey>;
-    type Output = ThresholdKeyShare<P, VerifyingKey>;
+    type Output = Option<ThresholdKeyShare<P, VerifyingKey>>;
 
     fn round_info(&self) -> Result<RoundInfo> {
         let session = self.session.as_ref().unwrap();
@@ -119,8 +122,12 @@ where
         println!("{key_str}: finalizing the round");
         */
 
-        match session.finalize_round(&mut OsRng, accum).unwrap() {
-            FinalizeOutcome::Success(result) => Ok(result),
+        let round = session.current_round().0;
+        match session
+            .finalize_round(&mut OsRng, accum)
+            .map_err(|e| e.into_abort(round))?
+        {
+            FinalizeOutcome::Success(result) => Ok(Some(result)),
             FinalizeOutcome::AnotherRound {
                 session: new_session,
                 cached_messages: new_cached_messages,
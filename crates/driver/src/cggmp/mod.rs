@@ -1,4 +1,7 @@
 //! Driver for the CGGMP protocol.
+use rand::rngs::OsRng;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
 use synedrion::{
     bip32::DerivationPath,
     ecdsa::{self, SigningKey, VerifyingKey},
@@ -8,6 +11,7 @@ use synedrion::{
 use polysig_protocol::pem;
 
 const TAG: &str = "CGGMP KEY SHARE";
+const AUX_INFO_TAG: &str = "CGGMP AUX INFO";
 const PEM_V1: u16 = 1;
 
 mod aux_gen;
@@ -17,14 +21,18 @@ mod key_gen;
 mod key_init;
 mod key_refresh;
 mod key_resharing;
+#[cfg(feature = "reconstruct")]
+mod reconstruct;
 mod sign;
 
 pub use aux_gen::AuxGenDriver;
-pub use error::Error;
+pub use error::{Error, Evidence, Fault};
 pub use key_gen::KeyGenDriver;
 pub use key_init::KeyInitDriver;
 pub use key_refresh::KeyRefreshDriver;
 pub use key_resharing::KeyResharingDriver;
+#[cfg(feature = "reconstruct")]
+pub use reconstruct::reconstruct_key;
 pub use sign::SignatureDriver;
 
 type MessageOut = MessageBundle<ecdsa::Signature>;
@@ -73,6 +81,66 @@ where
     }
 }
 
+/// Auxiliary information.
+pub type AuxInfo<P> = synedrion::AuxInfo<P, VerifyingKey>;
+
+/// Auxiliary information encoded as a PEM.
+///
+/// Aux info only depends upon the set of parties so it can be
+/// generated once and stored next to the key share to be
+/// re-used by signing sessions between the same parties.
+///
+/// It contains the secret Paillier key for this party so
+/// should be stored as securely as the key share.
+#[derive(Serialize, Deserialize)]
+pub struct EncodedAuxInfo {
+    /// Protocol version.
+    pub version: u16,
+    /// PEM-encoded aux info contents.
+    pub contents: String,
+}
+
+impl<P> TryFrom<&AuxInfo<P>> for EncodedAuxInfo
+where
+    P: SchemeParams,
+{
+    type Error = polysig_protocol::Error;
+
+    fn try_from(
+        value: &AuxInfo<P>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let aux_info = serde_json::to_vec(value)?;
+        let aux_info = pem::Pem::new(AUX_INFO_TAG, aux_info);
+        let aux_info = pem::encode(&aux_info);
+        Ok(Self {
+            version: PEM_V1,
+            contents: aux_info,
+        })
+    }
+}
+
+impl<P> TryFrom<&EncodedAuxInfo> for AuxInfo<P>
+where
+    P: SchemeParams,
+{
+    type Error = polysig_protocol::Error;
+
+    fn try_from(
+        value: &EncodedAuxInfo,
+    ) -> std::result::Result<Self, Self::Error> {
+        let aux_info = pem::parse(&value.contents)?;
+        if aux_info.tag() != AUX_INFO_TAG {
+            return Err(polysig_protocol::Error::PemTag(
+                AUX_INFO_TAG.to_string(),
+                aux_info.tag().to_string(),
+            ));
+        }
+        let aux_info: AuxInfo<P> =
+            serde_json::from_slice(aux_info.contents())?;
+        Ok(aux_info)
+    }
+}
+
 /// Result type for the CGGMP protocol.
 pub type Result<T> = std::result::Result<T, Error>;
 
@@ -82,6 +150,60 @@ pub type Participant = crate::Participant<SigningKey, VerifyingKey>;
 /// Options for each party.
 pub type PartyOptions = crate::PartyOptions<VerifyingKey>;
 
+/// Import a single-party signing key by splitting it
+/// into threshold key shares using a trusted dealer.
+///
+/// The key shares are returned in the same order as the
+/// verifiers and each key share is verified against the
+/// verifying key for the signing key so the address does
+/// not change.
+///
+/// The dealer learns every key share so the signing key
+/// and the key shares must be discarded from the dealer
+/// once they have been distributed.
+pub fn import_key<P>(
+    signing_key: &SigningKey,
+    verifiers: &[VerifyingKey],
+    threshold: usize,
+) -> Result<Vec<crate::KeyShare>>
+where
+    P: SchemeParams + 'static,
+{
+    let ids = verifiers.iter().cloned().collect::<BTreeSet<_>>();
+    let mut shares = KeyShare::<P>::new_centralized(
+        &mut OsRng,
+        &ids,
+        threshold,
+        Some(signing_key),
+    );
+
+    let verifying_key = signing_key.verifying_key();
+    let mut key_shares = Vec::with_capacity(verifiers.len());
+    for verifier in verifiers {
+        let key_share = shares
+            .remove(verifier)
+            .ok_or(Error::DealerVerifyingKey)?;
+        verify_key_share(&key_share, verifying_key)?;
+        key_shares.push((&key_share).try_into()?);
+    }
+    Ok(key_shares)
+}
+
+/// Verify a key share matches the verifying key
+/// of an imported signing key.
+pub fn verify_key_share<P>(
+    key_share: &KeyShare<P>,
+    verifying_key: &VerifyingKey,
+) -> Result<()>
+where
+    P: SchemeParams + 'static,
+{
+    if &key_share.verifying_key() != verifying_key {
+        return Err(Error::DealerVerifyingKey);
+    }
+    Ok(())
+}
+
 /// Derive a child key using the BIP32 algorithm.
 pub fn derive_bip32<P>(
     key_share: &ThresholdKeyShare<P, VerifyingKey>,
@@ -0,0 +1,145 @@
+//! Signing key reconstruction for CGGMP.
+use k256::{elliptic_curve::PrimeField, FieldBytes, Scalar};
+use polysig_protocol::hex;
+use serde::{
+    de::{DeserializeOwned, Error as _, IgnoredAny},
+    Deserialize, Deserializer, Serialize,
+};
+use std::collections::BTreeSet;
+use synedrion::{
+    ecdsa::{SigningKey, VerifyingKey},
+    SchemeParams,
+};
+
+use super::{Error, KeyShare, Result};
+
+/// Reconstruct the signing key from at least threshold
+/// key shares.
+///
+/// Each threshold key share is converted into an additive
+/// share for the set of owners and the secret shares are
+/// summed so the key shares must belong to distinct
+/// parties. The signing key can be used with
+/// [crate::signers::ecdsa::EcdsaSigner] and the verifying
+/// key matches the verifying key for the key shares.
+///
+/// Secret shares are private to the protocol library so
+/// they are read from the serialized key shares which must
+/// use the format of synedrion 0.2.0.
+///
+/// The reconstructed key defeats the purpose of threshold
+/// signing and should only be used for disaster recovery.
+pub fn reconstruct_key<P>(
+    key_shares: &[KeyShare<P>],
+) -> Result<SigningKey>
+where
+    P: SchemeParams + 'static,
+{
+    let threshold =
+        key_shares.first().map(|k| k.threshold()).unwrap_or(1);
+    if key_shares.len() < threshold {
+        return Err(Error::ReconstructThreshold(
+            threshold,
+            key_shares.len(),
+        ));
+    }
+
+    let verifying_key = key_shares[0].verifying_key();
+    let mut owners = BTreeSet::new();
+    for key_share in key_shares {
+        if key_share.verifying_key() != verifying_key {
+            return Err(Error::ReconstructVerifyingKey);
+        }
+        let key_share: ThresholdKeyShareMirror = mirror(key_share)?;
+        if !owners.insert(key_share.owner) {
+            return Err(Error::ReconstructKeyShare);
+        }
+    }
+
+    let mut secret = Scalar::ZERO;
+    for key_share in key_shares {
+        let key_share: KeyShareMirror =
+            mirror(&key_share.to_key_share(&owners))?;
+        secret += key_share.secret_share;
+    }
+
+    let signing_key = SigningKey::from_bytes(&secret.to_bytes())
+        .map_err(|_| Error::ReconstructVerifyingKey)?;
+    if signing_key.verifying_key() != &verifying_key {
+        return Err(Error::ReconstructVerifyingKey);
+    }
+    Ok(signing_key)
+}
+
+/// Serialized form of a threshold key share.
+///
+/// Mirrors `ThresholdKeyShare` from synedrion 0.2.0 whose
+/// fields are private; unknown or missing fields are
+/// rejected so a change to the format is an error.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ThresholdKeyShareMirror {
+    owner: VerifyingKey,
+    #[allow(dead_code)]
+    threshold: u32,
+    #[allow(dead_code)]
+    secret_share: IgnoredAny,
+    #[allow(dead_code)]
+    share_ids: IgnoredAny,
+    #[allow(dead_code)]
+    public_shares: IgnoredAny,
+    #[allow(dead_code)]
+    phantom: IgnoredAny,
+}
+
+/// Serialized form of a key share.
+///
+/// Mirrors `KeyShare` from synedrion 0.2.0 whose fields
+/// are private; unknown or missing fields are rejected so
+/// a change to the format is an error.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct KeyShareMirror {
+    #[allow(dead_code)]
+    owner: VerifyingKey,
+    #[serde(deserialize_with = "deserialize_scalar")]
+    secret_share: Scalar,
+    #[allow(dead_code)]
+    public_shares: IgnoredAny,
+    #[allow(dead_code)]
+    phantom: IgnoredAny,
+}
+
+/// Read the mirror of a serialized key share.
+fn mirror<T, M>(value: &T) -> Result<M>
+where
+    T: Serialize,
+    M: DeserializeOwned,
+{
+    let value = serde_json::to_value(value)
+        .map_err(|e| Error::ReconstructFormat(e.to_string()))?;
+    serde_json::from_value(value)
+        .map_err(|e| Error::ReconstructFormat(e.to_string()))
+}
+
+/// Deserialize a scalar encoded as a 0x-prefixed hex string
+/// of the big-endian bytes.
+fn deserialize_scalar<'de, D>(
+    deserializer: D,
+) -> std::result::Result<Scalar, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = String::deserialize(deserializer)?;
+    let bytes = value
+        .strip_prefix("0x")
+        .and_then(|value| hex::decode(value).ok())
+        .filter(|bytes| bytes.len() == 32)
+        .ok_or_else(|| {
+            D::Error::custom("expected 0x-prefixed 32 byte hex")
+        })?;
+    Option::from(Scalar::from_repr(FieldBytes::clone_from_slice(
+        &bytes,
+    )))
+    .ok_or_else(|| D::Error::custom("scalar is out of range"))
+}
@@ -2,7 +2,7 @@
 use rand::rngs::OsRng;
 use std::collections::BTreeSet;
 
-use super::{Error, Result};
+use super::{error::IntoAbort, Error, Result};
 use synedrion::{
     ecdsa::{Signature, SigningKey, VerifyingKey},
     make_interactive_signing_session,
@@ -121,7 +121,11 @@ where
         let session = self.session.take().unwrap();
         let accum = self.accum.take().unwrap();
 
-        match session.finalize_round(&mut OsRng, accum).unwrap() {
+        let round = session.current_round().0;
+        match session
+            .finalize_round(&mut OsRng, accum)
+            .map_err(|e| e.into_abort(round))?
+        {
             FinalizeOutcome::Success(result) => {
                 Ok(Some(result.into()))
             }
@@ -0,0 +1,382 @@
+//! Hash typed structured data according to [EIP-712].
+//!
+//! Typed data uses the JSON format accepted by
+//! `eth_signTypedData_v4` so arrays of atomic types and
+//! structs are both supported.
+//!
+//! [EIP-712]: https://eips.ethereum.org/EIPS/eip-712
+use crate::{Error, Result};
+use polysig_protocol::hex;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use sha3::{Digest, Keccak256};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Name of the domain struct type.
+const DOMAIN_TYPE: &str = "EIP712Domain";
+
+/// Members of the domain struct type in the order used when
+/// the types do not declare the domain.
+const DOMAIN_MEMBERS: [(&str, &str); 5] = [
+    ("name", "string"),
+    ("version", "string"),
+    ("chainId", "uint256"),
+    ("verifyingContract", "address"),
+    ("salt", "bytes32"),
+];
+
+/// Member of a struct type.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct MemberType {
+    /// Name of the member.
+    pub name: String,
+    /// Type of the member.
+    #[serde(rename = "type")]
+    pub kind: String,
+}
+
+/// Struct types by name.
+pub type Types = BTreeMap<String, Vec<MemberType>>;
+
+/// Typed structured data.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TypedData {
+    /// Struct types.
+    pub types: Types,
+    /// Type of the message.
+    pub primary_type: String,
+    /// Values for the domain separator.
+    pub domain: Map<String, Value>,
+    /// Message values.
+    pub message: Map<String, Value>,
+}
+
+impl TypedData {
+    /// Compute the domain separator.
+    ///
+    /// When the types do not declare the domain type it
+    /// is created from the members present in the domain.
+    pub fn domain_separator(&self) -> Result<[u8; 32]> {
+        if self.types.contains_key(DOMAIN_TYPE) {
+            hash_struct(&self.types, DOMAIN_TYPE, &self.domain)
+        } else {
+            let mut types = self.types.clone();
+            let members = DOMAIN_MEMBERS
+                .iter()
+                .filter(|(name, _)| self.domain.contains_key(*name))
+                .map(|(name, kind)| MemberType {
+                    name: name.to_string(),
+                    kind: kind.to_string(),
+                })
+                .collect();
+            types.insert(DOMAIN_TYPE.to_owned(), members);
+            hash_struct(&types, DOMAIN_TYPE, &self.domain)
+        }
+    }
+
+    /// Compute the struct hash of the message.
+    pub fn message_hash(&self) -> Result<[u8; 32]> {
+        hash_struct(&self.types, &self.primary_type, &self.message)
+    }
+
+    /// Compute the hash to be signed.
+    ///
+    /// The hash is `keccak256("\x19\x01" ‖ domainSeparator ‖
+    /// hashStruct(message))` and can be used as the prehashed
+    /// message for CGGMP signing.
+    pub fn signing_hash(&self) -> Result<[u8; 32]> {
+        let mut digest = Keccak256::new();
+        digest.update([0x19, 0x01]);
+        digest.update(self.domain_separator()?);
+        // Only the domain is signed when it is the primary type
+        if self.primary_type != DOMAIN_TYPE {
+            digest.update(self.message_hash()?);
+        }
+        Ok(digest.finalize().into())
+    }
+}
+
+/// Compute the struct hash for a type.
+fn hash_struct(
+    types: &Types,
+    name: &str,
+    data: &Map<String, Value>,
+) -> Result<[u8; 32]> {
+    let members = types
+        .get(name)
+        .ok_or_else(|| Error::Eip712Type(name.to_owned()))?;
+
+    let mut encoded = Vec::with_capacity(32 * (members.len() + 1));
+    encoded.extend_from_slice(&keccak256(
+        encode_type(types, name)?.as_bytes(),
+    ));
+    for member in members {
+        let value = data.get(&member.name).ok_or_else(|| {
+            Error::Eip712Member(name.to_owned(), member.name.clone())
+        })?;
+        encoded.extend_from_slice(&encode_value(
+            types,
+            &member.kind,
+            value,
+        )?);
+    }
+    Ok(keccak256(&encoded))
+}
+
+/// Encode a type and the struct types it references
+/// sorted by name.
+fn encode_type(types: &Types, name: &str) -> Result<String> {
+    let mut dependencies = BTreeSet::new();
+    find_dependencies(types, name, &mut dependencies);
+    dependencies.remove(name);
+
+    let mut encoded = String::new();
+    for name in std::iter::once(name)
+        .chain(dependencies.iter().map(String::as_str))
+    {
+        let members = types
+            .get(name)
+            .ok_or_else(|| Error::Eip712Type(name.to_owned()))?;
+        let members = members
+            .iter()
+            .map(|m| format!("{} {}", m.kind, m.name))
+            .collect::<Vec<_>>();
+        encoded.push_str(name);
+        encoded.push('(');
+        encoded.push_str(&members.join(","));
+        encoded.push(')');
+    }
+    Ok(encoded)
+}
+
+/// Find the struct types referenced by a type.
+fn find_dependencies(
+    types: &Types,
+    kind: &str,
+    found: &mut BTreeSet<String>,
+) {
+    let name = kind.split('[').next().unwrap_or(kind);
+    if found.contains(name) {
+        return;
+    }
+    if let Some(members) = types.get(name) {
+        found.insert(name.to_owned());
+        for member in members {
+            find_dependencies(types, &member.kind, found);
+        }
+    }
+}
+
+/// Encode a value as a 32 byte word.
+fn encode_value(
+    types: &Types,
+    kind: &str,
+    value: &Value,
+) -> Result<[u8; 32]> {
+    let invalid = || Error::Eip712Value(kind.to_owned());
+
+    // Arrays are the hash of the concatenated encoded items
+    if let Some(open) =
+        kind.strip_suffix(']').and_then(|_| kind.rfind('['))
+    {
+        let item_kind = &kind[..open];
+        let size = &kind[open + 1..kind.len() - 1];
+        let items = value.as_array().ok_or_else(invalid)?;
+        if !size.is_empty()
+            && size.parse::<usize>().map_err(|_| invalid())?
+                != items.len()
+        {
+            return Err(invalid());
+        }
+
+        let mut encoded = Vec::with_capacity(32 * items.len());
+        for item in items {
+            encoded.extend_from_slice(&encode_value(
+                types, item_kind, item,
+            )?);
+        }
+        return Ok(keccak256(&encoded));
+    }
+
+    if types.contains_key(kind) {
+        let data = value.as_object().ok_or_else(invalid)?;
+        return hash_struct(types, kind, data);
+    }
+
+    let mut word = [0u8; 32];
+    match kind {
+        "string" => {
+            let value = value.as_str().ok_or_else(invalid)?;
+            Ok(keccak256(value.as_bytes()))
+        }
+        "bytes" => {
+            Ok(keccak256(&decode_hex(value).ok_or_else(invalid)?))
+        }
+        "bool" => {
+            word[31] = value.as_bool().ok_or_else(invalid)? as u8;
+            Ok(word)
+        }
+        "address" => {
+            let bytes = decode_hex(value).ok_or_else(invalid)?;
+            if bytes.len() != 20 {
+                return Err(invalid());
+            }
+            word[12..].copy_from_slice(&bytes);
+            Ok(word)
+        }
+        _ if kind.starts_with("bytes") => {
+            let size: usize =
+                kind[5..].parse().map_err(|_| invalid())?;
+            let bytes = decode_hex(value).ok_or_else(invalid)?;
+            if size == 0 || size > 32 || bytes.len() != size {
+                return Err(invalid());
+            }
+            word[..size].copy_from_slice(&bytes);
+            Ok(word)
+        }
+        _ if kind.starts_with("uint") => {
+            let bits =
+                integer_bits(&kind[4..]).ok_or_else(invalid)?;
+            let (negative, magnitude) =
+                parse_integer(value).ok_or_else(invalid)?;
+            if negative || bit_length(&magnitude) > bits {
+                return Err(invalid());
+            }
+            Ok(magnitude)
+        }
+        _ if kind.starts_with("int") => {
+            let bits =
+                integer_bits(&kind[3..]).ok_or_else(invalid)?;
+            let (negative, magnitude) =
+                parse_integer(value).ok_or_else(invalid)?;
+            let length = bit_length(&magnitude);
+            if negative {
+                // Smallest value is -2^(bits - 1)
+                let is_min = length == bits
+                    && magnitude
+                        .iter()
+                        .map(|b| b.count_ones())
+                        .sum::<u32>()
+                        == 1;
+                if length >= bits && !is_min {
+                    return Err(invalid());
+                }
+                Ok(negate(magnitude))
+            } else {
+                if length >= bits {
+                    return Err(invalid());
+                }
+                Ok(magnitude)
+            }
+        }
+        _ => Err(Error::Eip712Type(kind.to_owned())),
+    }
+}
+
+/// Parse the size of an integer type.
+fn integer_bits(size: &str) -> Option<u32> {
+    let bits: u32 = size.parse().ok()?;
+    if bits == 0 || bits > 256 || bits % 8 != 0 {
+        return None;
+    }
+    Some(bits)
+}
+
+/// Parse an integer from a JSON number or a decimal or
+/// hexadecimal string into a sign and big-endian magnitude.
+fn parse_integer(value: &Value) -> Option<(bool, [u8; 32])> {
+    let mut magnitude = [0u8; 32];
+    match value {
+        Value::Number(number) => {
+            let (negative, value) =
+                if let Some(value) = number.as_u64() {
+                    (false, value)
+                } else {
+                    let value = number.as_i64()?;
+                    (value < 0, value.unsigned_abs())
+                };
+            magnitude[24..].copy_from_slice(&value.to_be_bytes());
+            Some((negative, magnitude))
+        }
+        Value::String(value) => {
+            let value = value.trim();
+            let (negative, value) = match value.strip_prefix('-') {
+                Some(value) => (true, value),
+                None => (false, value),
+            };
+            if let Some(digits) = value
+                .strip_prefix("0x")
+                .or_else(|| value.strip_prefix("0X"))
+            {
+                let digits = if digits.len() % 2 == 1 {
+                    format!("0{}", digits)
+                } else {
+                    digits.to_owned()
+                };
+                let bytes = hex::decode(digits).ok()?;
+                if bytes.is_empty() || bytes.len() > 32 {
+                    return None;
+                }
+                magnitude[32 - bytes.len()..].copy_from_slice(&bytes);
+            } else {
+                if value.is_empty() {
+                    return None;
+                }
+                for digit in value.chars() {
+                    let digit = digit.to_digit(10)?;
+                    if mul_add(&mut magnitude, 10, digit) {
+                        return None;
+                    }
+                }
+            }
+            Some((negative, magnitude))
+        }
+        _ => None,
+    }
+}
+
+/// Multiply a big-endian integer and add a value returning
+/// whether the result overflowed.
+fn mul_add(value: &mut [u8; 32], mul: u32, add: u32) -> bool {
+    let mut carry = add as u64;
+    for byte in value.iter_mut().rev() {
+        let next = *byte as u64 * mul as u64 + carry;
+        *byte = next as u8;
+        carry = next >> 8;
+    }
+    carry != 0
+}
+
+/// Number of bits needed for a big-endian integer.
+fn bit_length(value: &[u8; 32]) -> u32 {
+    match value.iter().position(|b| *b != 0) {
+        Some(index) => {
+            (32 - index as u32) * 8 - value[index].leading_zeros()
+        }
+        None => 0,
+    }
+}
+
+/// Two's complement of a big-endian integer.
+fn negate(mut value: [u8; 32]) -> [u8; 32] {
+    for byte in value.iter_mut() {
+        *byte = !*byte;
+    }
+    mul_add(&mut value, 1, 1);
+    value
+}
+
+/// Decode a hexadecimal string with an optional `0x` prefix.
+fn decode_hex(value: &Value) -> Option<Vec<u8>> {
+    let value = value.as_str()?;
+    let value = value
+        .strip_prefix("0x")
+        .or_else(|| value.strip_prefix("0X"))
+        .unwrap_or(value);
+    hex::decode(value).ok()
+}
+
+fn keccak256(bytes: &[u8]) -> [u8; 32] {
+    Keccak256::digest(bytes).into()
+}
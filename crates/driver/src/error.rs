@@ -12,6 +12,26 @@ pub enum Error {
     #[error("session identifier required")]
     SessionIdRequired,
 
+    /// Error when an incoming message is delivered by a peer
+    /// that is not a participant in the session.
+    #[error("message peer '{0}' is not a session participant")]
+    UnknownPeer(String),
+
+    /// Error when the sender of an incoming message does not
+    /// belong to the peer that delivered the message.
+    #[error("message sender does not match peer for party {0}")]
+    SenderMismatch(u16),
+
+    /// Error when an incoming message is addressed to
+    /// another party.
+    #[error("message for party {0} delivered to party {1}")]
+    ReceiverMismatch(u16, u16),
+
+    /// Error when an incoming message is for a round that
+    /// has already completed.
+    #[error("message for round {0} received in round {1}")]
+    StaleRound(u16, u16),
+
     /// Signing key does not exist in list of verifying keys.
     #[error("signer is not a verifying party")]
     NotVerifyingParty,
@@ -21,6 +41,138 @@ pub enum Error {
     #[error("number of participants '{0}' does not match number of verifying keys '{1}'")]
     ParticipantVerifierLength(usize, usize),
 
+    /// Error when a type in EIP-712 typed data is not defined.
+    #[cfg(any(feature = "ecdsa", feature = "cggmp"))]
+    #[error("unknown EIP-712 type '{0}'")]
+    Eip712Type(String),
+
+    /// Error when a struct member is missing from the values
+    /// in EIP-712 typed data.
+    #[cfg(any(feature = "ecdsa", feature = "cggmp"))]
+    #[error("EIP-712 type '{0}' is missing member '{1}'")]
+    Eip712Member(String, String),
+
+    /// Error when a value in EIP-712 typed data is not valid
+    /// for the member type.
+    #[cfg(any(feature = "ecdsa", feature = "cggmp"))]
+    #[error("invalid value for EIP-712 type '{0}'")]
+    Eip712Value(String),
+
+    /// Error when a base58 string is not a valid public key
+    /// or hash.
+    #[cfg(feature = "solana")]
+    #[error("invalid base58 public key or hash '{0}'")]
+    SolanaBase58(String),
+
+    /// Error when a public key is not a signer for a
+    /// Solana message.
+    #[cfg(feature = "solana")]
+    #[error("'{0}' is not a signer for the message")]
+    SolanaSigner(String),
+
+    /// Error when a Solana message has too many accounts.
+    #[cfg(feature = "solana")]
+    #[error("message has {0} accounts but at most 256 are allowed")]
+    SolanaAccounts(usize),
+
+    /// Error when a signature is not 64 bytes.
+    #[cfg(feature = "solana")]
+    #[error("signature must be 64 bytes")]
+    SolanaSignature,
+
+    /// Error when an off-chain message is empty, too long
+    /// or not valid UTF-8.
+    #[cfg(feature = "solana")]
+    #[error("invalid off-chain message")]
+    SolanaOffchainMessage,
+
+    /// Error when a Bitcoin transaction or PSBT could
+    /// not be decoded.
+    #[cfg(feature = "bitcoin")]
+    #[error("invalid Bitcoin transaction or PSBT encoding")]
+    BitcoinDecode,
+
+    /// Error when an input index is out of range.
+    #[cfg(feature = "bitcoin")]
+    #[error("input index {0} is out of range")]
+    BitcoinInputIndex(usize),
+
+    /// Error when the output spent by an input is required
+    /// but not available.
+    #[cfg(feature = "bitcoin")]
+    #[error("previous output for input {0} is required")]
+    BitcoinPrevout(usize),
+
+    /// Error when a PSBT does not start with the magic bytes.
+    #[cfg(feature = "bitcoin")]
+    #[error("invalid PSBT magic bytes")]
+    PsbtMagic,
+
+    /// Error when a PSBT map has a duplicate key.
+    #[cfg(feature = "bitcoin")]
+    #[error("duplicate key in PSBT map")]
+    PsbtDuplicateKey,
+
+    /// Error when a PSBT does not have an unsigned transaction
+    /// or the transaction has signatures.
+    #[cfg(feature = "bitcoin")]
+    #[error("PSBT unsigned transaction is missing or signed")]
+    PsbtUnsignedTx,
+
+    /// Error when the value of a PSBT field is invalid.
+    #[cfg(feature = "bitcoin")]
+    #[error("invalid value for PSBT field type {0:#04x}")]
+    PsbtField(u8),
+
+    /// Error when the number of previous outputs does not
+    /// match the number of transaction inputs.
+    #[cfg(feature = "bitcoin")]
+    #[error("expected {0} previous outputs but got {1}")]
+    SighashPrevouts(usize, usize),
+
+    /// Error when a signature hash type is not valid
+    /// for Taproot.
+    #[cfg(feature = "bitcoin")]
+    #[error("invalid Taproot signature hash type {0:#x}")]
+    SighashType(u32),
+
+    /// Error when an input signed with `SIGHASH_SINGLE` does
+    /// not have an output with the same index.
+    #[cfg(feature = "bitcoin")]
+    #[error("no output for SIGHASH_SINGLE input {0}")]
+    SighashSingle(usize),
+
+    /// Error when a key could not be tweaked for Taproot.
+    #[cfg(feature = "bitcoin")]
+    #[error("invalid Taproot output key tweak")]
+    TaprootOutputKey,
+
+    /// Error when a Taproot signature is not 64 bytes.
+    #[cfg(feature = "bitcoin")]
+    #[error("Taproot signature must be 64 bytes")]
+    TaprootSignature,
+
+    /// Error when a public key is not valid for an address.
+    #[cfg(feature = "addresses")]
+    #[error("invalid public key for address")]
+    AddressPublicKey,
+
+    /// Error when a public key type is not supported
+    /// by an address encoding.
+    #[cfg(feature = "addresses")]
+    #[error("public key type is not supported for {0} addresses")]
+    AddressKind(&'static str),
+
+    /// Error when a bech32 human readable part is invalid.
+    #[cfg(feature = "addresses")]
+    #[error("invalid address prefix '{0}'")]
+    AddressPrefix(String),
+
+    /// Error when a Bitcoin network name is unknown.
+    #[cfg(feature = "addresses")]
+    #[error("unknown Bitcoin network '{0}'")]
+    AddressNetwork(String),
+
     /// JSON error.
     #[error(transparent)]
     Json(#[from] serde_json::Error),
@@ -31,7 +183,7 @@ pub enum Error {
     Cggmp(#[from] crate::cggmp::Error),
 
     /// FROST driver errors.
-    #[cfg(feature = "frost-ed25519")]
+    #[cfg(feature = "frost")]
     #[error(transparent)]
     Frost(#[from] crate::frost::Error),
 
@@ -0,0 +1,569 @@
+//! Macro to generate coordinated signing drivers for FROST.
+//!
+//! Follows the roles in RFC 9591: signing participants send
+//! their commitments and signature shares to a coordinator
+//! which sends the signing package and aggregates the shares.
+use serde::{Deserialize, Serialize};
+
+/// Role of a party in coordinated signing.
+#[derive(
+    Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize,
+)]
+pub enum Role<I> {
+    /// Collects the commitments, sends the signing package and
+    /// aggregates the signature shares.
+    ///
+    /// The coordinator does not need a key share.
+    Coordinator,
+    /// Signing participant with the identifier of the key share.
+    Participant(I),
+}
+
+macro_rules! frost_coordinator_impl {
+    ($r1pub:ty,
+     $r1priv:ty,
+     $r2pub:ty,
+     $r2priv:ty,
+     $id:ty,
+     $out:ty,
+     $pubkeys:ty) => {
+        #[derive(Debug, Serialize, Deserialize)]
+        pub enum CoordinatorPackage {
+            Commitments($r1pub),
+            SigningPackage($r2priv),
+            SignatureShare($r2pub),
+        }
+
+        /// Find the party number of the coordinator.
+        fn coordinator_party(
+            roles: &[Role<$id>],
+        ) -> Result<NonZeroU16> {
+            let mut coordinators =
+                roles.iter().enumerate().filter(|(_, role)| {
+                    matches!(role, Role::Coordinator)
+                });
+            match (coordinators.next(), coordinators.next()) {
+                (Some((index, _)), None) => {
+                    Ok(NonZeroU16::new((index + 1) as u16).unwrap())
+                }
+                _ => Err(Error::CoordinatorRole),
+            }
+        }
+
+        /// FROST signing coordinator driver.
+        ///
+        /// Round packages are signed by the participant signing
+        /// key and verified against the verifier for the sender.
+        pub struct CoordinatorDriver {
+            session_id: SessionId,
+            #[allow(dead_code)]
+            party_number: NonZeroU16,
+            roles: Vec<Role<$id>>,
+            signer: SigningKey,
+            verifiers: Vec<VerifyingKey>,
+            identifiers: Vec<$id>,
+            round_number: u8,
+            public_key_package: $pubkeys,
+            message: Vec<u8>,
+            commitments: BTreeMap<$id, $r1pub>,
+            signing_package: Option<$r2priv>,
+            signature_shares: BTreeMap<$id, $r2pub>,
+        }
+
+        impl CoordinatorDriver {
+            /// Create a driver.
+            ///
+            /// Roles and verifiers are indexed by party number;
+            /// exactly one party must be the coordinator.
+            pub fn new(
+                session_id: SessionId,
+                party_number: NonZeroU16,
+                roles: Vec<Role<$id>>,
+                signer: SigningKey,
+                verifiers: Vec<VerifyingKey>,
+                public_key_package: $pubkeys,
+                message: Vec<u8>,
+            ) -> Result<Self> {
+                if verifiers.len() != roles.len() {
+                    return Err(Error::VerifiersLength(
+                        roles.len(),
+                        verifiers.len(),
+                    ));
+                }
+
+                if coordinator_party(&roles)? != party_number {
+                    return Err(Error::CoordinatorRole);
+                }
+
+                let identifiers = roles
+                    .iter()
+                    .filter_map(|role| match role {
+                        Role::Participant(id) => Some(*id),
+                        Role::Coordinator => None,
+                    })
+                    .collect();
+
+                Ok(Self {
+                    session_id,
+                    party_number,
+                    roles,
+                    signer,
+                    verifiers,
+                    identifiers,
+                    round_number: ROUND_1,
+                    public_key_package,
+                    message,
+                    commitments: BTreeMap::new(),
+                    signing_package: None,
+                    signature_shares: BTreeMap::new(),
+                })
+            }
+        }
+
+        impl ProtocolDriver for CoordinatorDriver {
+            type Error = Error;
+            type Message = RoundMessage<
+                SignedPackage<CoordinatorPackage>,
+                Role<$id>,
+            >;
+            type Output = $out;
+
+            fn round_info(&self) -> Result<RoundInfo> {
+                let round_number = self.round_number;
+                let is_echo = false;
+                let can_finalize = match self.round_number {
+                    ROUND_2 => {
+                        self.commitments.len()
+                            == self.identifiers.len()
+                    }
+                    ROUND_3 => {
+                        self.signature_shares.len()
+                            == self.identifiers.len()
+                    }
+                    _ => false,
+                };
+                Ok(RoundInfo {
+                    round_number,
+                    can_finalize,
+                    is_echo,
+                })
+            }
+
+            fn proceed(&mut self) -> Result<Vec<Self::Message>> {
+                match self.round_number {
+                    // Wait for the commitments from the participants
+                    ROUND_1 => {
+                        self.round_number =
+                            self.round_number.checked_add(1).unwrap();
+                        Ok(Vec::new())
+                    }
+                    // Send the signing package to the participants
+                    ROUND_2 => {
+                        let mut messages = Vec::with_capacity(
+                            self.identifiers.len(),
+                        );
+
+                        let signing_package = <$r2priv>::new(
+                            self.commitments.clone(),
+                            &self.message,
+                        );
+
+                        for (index, role) in
+                            self.roles.iter().enumerate()
+                        {
+                            if let Role::Coordinator = role {
+                                continue;
+                            }
+
+                            let receiver =
+                                NonZeroU16::new((index + 1) as u16)
+                                    .unwrap();
+                            let round = NonZeroU16::new(
+                                self.round_number.into(),
+                            )
+                            .unwrap();
+                            let body = sign_package(
+                                &self.signer,
+                                &self.session_id,
+                                round,
+                                &Role::<$id>::Coordinator,
+                                receiver,
+                                CoordinatorPackage::SigningPackage(
+                                    signing_package.clone(),
+                                ),
+                            )?;
+
+                            messages.push(RoundMessage {
+                                round,
+                                sender: Role::Coordinator,
+                                receiver,
+                                body,
+                            });
+                        }
+
+                        self.signing_package = Some(signing_package);
+
+                        self.round_number =
+                            self.round_number.checked_add(1).unwrap();
+
+                        Ok(messages)
+                    }
+                    _ => Err(Error::InvalidRound(self.round_number)),
+                }
+            }
+
+            fn handle_incoming(
+                &mut self,
+                message: Self::Message,
+            ) -> Result<()> {
+                let round_number = message.round.get() as u8;
+                let party_index = self
+                    .roles
+                    .iter()
+                    .position(|v| v == &message.sender)
+                    .ok_or(Error::SenderVerifier)?;
+                let id = match message.sender {
+                    Role::Participant(id) => id,
+                    Role::Coordinator => {
+                        return Err(Error::SenderIdentifier(
+                            round_number,
+                            party_index,
+                        ))
+                    }
+                };
+                let verifier = self
+                    .verifiers
+                    .get(party_index)
+                    .ok_or(Error::SenderIdentifier(
+                        round_number,
+                        party_index,
+                    ))?;
+
+                let package = verify_package(
+                    verifier,
+                    &self.session_id,
+                    message.round,
+                    &message.sender,
+                    message.receiver,
+                    message.body,
+                )?;
+
+                match (round_number, package) {
+                    (
+                        ROUND_1,
+                        CoordinatorPackage::Commitments(commitments),
+                    ) => {
+                        self.commitments.insert(id, commitments);
+                        Ok(())
+                    }
+                    (
+                        ROUND_2,
+                        CoordinatorPackage::SignatureShare(share),
+                    ) => {
+                        self.signature_shares.insert(id, share);
+                        Ok(())
+                    }
+                    (ROUND_1, _) | (ROUND_2, _) => {
+                        Err(Error::RoundPayload(round_number))
+                    }
+                    _ => Err(Error::InvalidRound(round_number)),
+                }
+            }
+
+            fn try_finalize_round(
+                &mut self,
+            ) -> Result<Option<Self::Output>> {
+                if self.round_number == ROUND_3
+                    && self.signature_shares.len()
+                        == self.identifiers.len()
+                {
+                    let signing_package = self
+                        .signing_package
+                        .take()
+                        .ok_or(Error::Round3TooEarly)?;
+
+                    let group_signature = match aggregate(
+                        &signing_package,
+                        &self.signature_shares,
+                        &self.public_key_package,
+                    ) {
+                        Ok(signature) => signature,
+                        Err(e) => {
+                            let culprits = find_culprits(
+                                &self.public_key_package,
+                                &self.signature_shares,
+                                &signing_package,
+                                |id| {
+                                    let role = Role::Participant(*id);
+                                    self.roles
+                                        .iter()
+                                        .position(|v| v == &role)
+                                        .map(|i| (i + 1) as u16)
+                                },
+                            );
+                            if culprits.is_empty() {
+                                return Err(e.into());
+                            }
+                            return Err(
+                                Error::InvalidSignatureShares(
+                                    culprits,
+                                ),
+                            );
+                        }
+                    };
+
+                    Ok(Some(group_signature))
+                } else {
+                    // Commitments are complete, proceed to send
+                    // the signing package
+                    Ok(None)
+                }
+            }
+        }
+
+        /// FROST signing participant driver for coordinated
+        /// signing.
+        ///
+        /// Commitments and the signature share are only sent
+        /// to the coordinator which assembles the signature.
+        pub struct ParticipantDriver {
+            session_id: SessionId,
+            #[allow(dead_code)]
+            party_number: NonZeroU16,
+            roles: Vec<Role<$id>>,
+            signer: SigningKey,
+            verifiers: Vec<VerifyingKey>,
+            id: $id,
+            coordinator: NonZeroU16,
+            round_number: u8,
+            key_share: KeyShare,
+            message: Vec<u8>,
+            nonces: Option<$r1priv>,
+            commitments: Option<$r1pub>,
+            signing_package: Option<$r2priv>,
+        }
+
+        impl ParticipantDriver {
+            /// Create a driver.
+            ///
+            /// Roles and verifiers are indexed by party number;
+            /// exactly one party must be the coordinator.
+            pub fn new(
+                session_id: SessionId,
+                party_number: NonZeroU16,
+                roles: Vec<Role<$id>>,
+                signer: SigningKey,
+                verifiers: Vec<VerifyingKey>,
+                key_share: KeyShare,
+                message: Vec<u8>,
+            ) -> Result<Self> {
+                if verifiers.len() != roles.len() {
+                    return Err(Error::VerifiersLength(
+                        roles.len(),
+                        verifiers.len(),
+                    ));
+                }
+
+                let coordinator = coordinator_party(&roles)?;
+
+                let party_index: usize = party_number.get() as usize;
+                let id = match roles.get(party_index - 1) {
+                    Some(Role::Participant(id))
+                        if id == key_share.0.identifier() =>
+                    {
+                        *id
+                    }
+                    _ => return Err(Error::ParticipantRole),
+                };
+
+                Ok(Self {
+                    session_id,
+                    party_number,
+                    roles,
+                    signer,
+                    verifiers,
+                    id,
+                    coordinator,
+                    round_number: ROUND_1,
+                    key_share,
+                    message,
+                    nonces: None,
+                    commitments: None,
+                    signing_package: None,
+                })
+            }
+
+            /// Check the signing package from the coordinator is
+            /// for the expected message and our commitments.
+            fn verify_signing_package(
+                &self,
+                signing_package: &$r2priv,
+            ) -> Result<()> {
+                if signing_package.message() != &self.message {
+                    return Err(Error::SigningPackageMessage);
+                }
+
+                let commitments =
+                    signing_package.signing_commitments();
+                if commitments.get(&self.id)
+                    != self.commitments.as_ref()
+                {
+                    return Err(Error::SigningPackageCommitments);
+                }
+
+                for id in commitments.keys() {
+                    if !self.roles.contains(&Role::Participant(*id)) {
+                        return Err(Error::SigningPackageCommitments);
+                    }
+                }
+
+                Ok(())
+            }
+        }
+
+        impl ProtocolDriver for ParticipantDriver {
+            type Error = Error;
+            type Message = RoundMessage<
+                SignedPackage<CoordinatorPackage>,
+                Role<$id>,
+            >;
+            type Output = ();
+
+            fn round_info(&self) -> Result<RoundInfo> {
+                let round_number = self.round_number;
+                let is_echo = false;
+                let can_finalize = match self.round_number {
+                    ROUND_2 => self.signing_package.is_some(),
+                    // Signature share was sent to the coordinator
+                    ROUND_3 => true,
+                    _ => false,
+                };
+                Ok(RoundInfo {
+                    round_number,
+                    can_finalize,
+                    is_echo,
+                })
+            }
+
+            fn proceed(&mut self) -> Result<Vec<Self::Message>> {
+                let receiver = self.coordinator;
+                let round = NonZeroU16::new(self.round_number.into())
+                    .unwrap();
+                let sender = Role::Participant(self.id);
+
+                let package = match self.round_number {
+                    ROUND_1 => {
+                        let (nonces, commitments) = round1::commit(
+                            self.key_share.0.signing_share(),
+                            &mut OsRng,
+                        );
+                        self.nonces = Some(nonces);
+                        self.commitments = Some(commitments.clone());
+                        CoordinatorPackage::Commitments(commitments)
+                    }
+                    ROUND_2 => {
+                        let signing_package = self
+                            .signing_package
+                            .take()
+                            .ok_or(Error::Round3TooEarly)?;
+                        self.verify_signing_package(
+                            &signing_package,
+                        )?;
+
+                        let nonces = self
+                            .nonces
+                            .take()
+                            .ok_or(Error::Round3TooEarly)?;
+
+                        let signature_share = round2::sign(
+                            &signing_package,
+                            &nonces,
+                            &self.key_share.0,
+                        )?;
+                        CoordinatorPackage::SignatureShare(
+                            signature_share,
+                        )
+                    }
+                    _ => {
+                        return Err(Error::InvalidRound(
+                            self.round_number,
+                        ))
+                    }
+                };
+
+                let body = sign_package(
+                    &self.signer,
+                    &self.session_id,
+                    round,
+                    &sender,
+                    receiver,
+                    package,
+                )?;
+
+                self.round_number =
+                    self.round_number.checked_add(1).unwrap();
+
+                Ok(vec![RoundMessage {
+                    round,
+                    sender,
+                    receiver,
+                    body,
+                }])
+            }
+
+            fn handle_incoming(
+                &mut self,
+                message: Self::Message,
+            ) -> Result<()> {
+                let round_number = message.round.get() as u8;
+                if message.sender != Role::Coordinator {
+                    return Err(Error::SenderVerifier);
+                }
+                let party_index = self.coordinator.get() as usize - 1;
+                let verifier = self
+                    .verifiers
+                    .get(party_index)
+                    .ok_or(Error::SenderVerifier)?;
+
+                let package = verify_package(
+                    verifier,
+                    &self.session_id,
+                    message.round,
+                    &message.sender,
+                    message.receiver,
+                    message.body,
+                )?;
+
+                match (round_number, package) {
+                    (
+                        ROUND_2,
+                        CoordinatorPackage::SigningPackage(
+                            signing_package,
+                        ),
+                    ) => {
+                        self.signing_package = Some(signing_package);
+                        Ok(())
+                    }
+                    (ROUND_2, _) => {
+                        Err(Error::RoundPayload(round_number))
+                    }
+                    _ => Err(Error::InvalidRound(round_number)),
+                }
+            }
+
+            fn try_finalize_round(
+                &mut self,
+            ) -> Result<Option<Self::Output>> {
+                if self.round_number == ROUND_3 {
+                    Ok(Some(()))
+                } else {
+                    // Signing package received, proceed to send
+                    // the signature share
+                    Ok(None)
+                }
+            }
+        }
+    };
+}
+
+pub(crate) use frost_coordinator_impl;
@@ -0,0 +1,51 @@
+//! Macro to generate signature share verification for FROST.
+macro_rules! frost_culprits_impl {
+    ($r2pub:ty,
+     $r2priv:ty,
+     $id:ty,
+     $pubkey:ty) => {
+        /// Verify each signature share against the verifying
+        /// share of the sender to find signers that sent an
+        /// invalid signature share.
+        ///
+        /// The party number for the identifier of a culprit
+        /// is resolved with `party_number`; culprits that do
+        /// not map to a party are reported with party number
+        /// zero.
+        pub(crate) fn find_culprits(
+            public_key_package: &$pubkey,
+            signature_shares: &BTreeMap<$id, $r2pub>,
+            signing_package: &$r2priv,
+            party_number: impl Fn(&$id) -> Option<u16>,
+        ) -> Vec<Culprit> {
+            let mut culprits = Vec::new();
+            for (id, share) in signature_shares {
+                let is_valid = public_key_package
+                    .verifying_shares()
+                    .get(id)
+                    .map(|verifying_share| {
+                        verify_signature_share(
+                            *id,
+                            verifying_share,
+                            share,
+                            signing_package,
+                            public_key_package.verifying_key(),
+                        )
+                        .is_ok()
+                    })
+                    .unwrap_or(false);
+
+                if !is_valid {
+                    culprits.push(Culprit {
+                        party_number: party_number(id)
+                            .unwrap_or_default(),
+                        identifier: id.serialize(),
+                    });
+                }
+            }
+            culprits
+        }
+    };
+}
+
+pub(crate) use frost_culprits_impl;
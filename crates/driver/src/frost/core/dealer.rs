@@ -0,0 +1,67 @@
+//! Macro to generate trusted dealer key import for FROST.
+macro_rules! frost_dealer_impl {
+    () => {
+        /// Import a single-party signing key by splitting it
+        /// into key shares using a trusted dealer.
+        ///
+        /// The key shares are returned in the same order as
+        /// the identifiers and each key share is verified
+        /// against the verifying key for the signing key so
+        /// the address does not change.
+        ///
+        /// The dealer learns every key share so the signing
+        /// key and the key shares must be discarded from the
+        /// dealer once they have been distributed.
+        pub fn import_key(
+            signing_key: &SigningKey,
+            identifiers: &[Identifier],
+            min_signers: u16,
+        ) -> Result<Vec<crate::KeyShare>> {
+            let secret =
+                FrostSigningKey::deserialize(&signing_key_bytes(
+                    signing_key,
+                ))?;
+            let max_signers = identifiers.len() as u16;
+            let (mut shares, public_key_package) = split(
+                &secret,
+                max_signers,
+                min_signers,
+                IdentifierList::Custom(identifiers),
+                &mut OsRng,
+            )?;
+
+            let verifying_key = signing_key.verifying_key();
+            let mut key_shares = Vec::with_capacity(shares.len());
+            for (index, id) in identifiers.iter().enumerate() {
+                let share = shares
+                    .remove(id)
+                    .ok_or(Error::IndexIdentifier(index + 1))?;
+                // Verifies the share against the commitment
+                let key_package: KeyPackage = share.try_into()?;
+                let key_share: KeyShare =
+                    (key_package, public_key_package.clone());
+                verify_key_share(&key_share, &verifying_key)?;
+                key_shares.push((&key_share).try_into()?);
+            }
+            Ok(key_shares)
+        }
+
+        /// Verify a key share matches the verifying key
+        /// of an imported signing key.
+        pub fn verify_key_share(
+            key_share: &KeyShare,
+            verifying_key: &VerifyingKey,
+        ) -> Result<()> {
+            let expected = verifying_key_bytes(verifying_key);
+            if key_share.0.verifying_key().serialize()? != expected
+                || key_share.1.verifying_key().serialize()?
+                    != expected
+            {
+                return Err(Error::DealerVerifyingKey);
+            }
+            Ok(())
+        }
+    };
+}
+
+pub(crate) use frost_dealer_impl;
@@ -0,0 +1,196 @@
+//! Macro to generate non-hardened key derivation for FROST.
+//!
+//! A child key is derived by adding the same tweak to the
+//! signing share of every participant, to the verifying
+//! shares and to the group verifying key. The tweak for each
+//! child index is computed from the chain code and the parent
+//! verifying key using HMAC-SHA512 as in BIP32, so child
+//! verifying keys can be derived from the public key package
+//! alone.
+use hmac::{Hmac, Mac};
+use sha2::Sha512;
+
+use crate::frost::{Error, Result};
+
+/// Chain code for key derivation.
+pub type ChainCode = [u8; 32];
+
+/// First child index for hardened derivation which needs
+/// the secret key so is not supported.
+const HARDENED_INDEX: u32 = 1 << 31;
+
+/// Compute the left and right halves of the HMAC-SHA512
+/// output for a child index.
+///
+/// The left half is used for the tweak and the right half
+/// is the chain code for the child key.
+pub(crate) fn derive_child(
+    chain_code: &ChainCode,
+    public_key: &[u8],
+    index: u32,
+) -> Result<([u8; 32], ChainCode)> {
+    if index >= HARDENED_INDEX {
+        return Err(Error::HardenedDerivation(index));
+    }
+
+    let mut mac = Hmac::<Sha512>::new_from_slice(chain_code)
+        .map_err(|_| Error::InvalidDerivation(index))?;
+    mac.update(public_key);
+    mac.update(&index.to_be_bytes());
+    let output = mac.finalize().into_bytes();
+
+    let mut tweak = [0u8; 32];
+    let mut child_chain_code = [0u8; 32];
+    tweak.copy_from_slice(&output[..32]);
+    child_chain_code.copy_from_slice(&output[32..]);
+    Ok((tweak, child_chain_code))
+}
+
+macro_rules! frost_derive_impl {
+    ($suite:ty, $tweak:ident, $(#[$scheme:meta])*) => {
+        type SuiteGroup = <$suite as Ciphersuite>::Group;
+        type ScalarField = <SuiteGroup as Group>::Field;
+        type Scalar = <ScalarField as Field>::Scalar;
+        type Element = <SuiteGroup as Group>::Element;
+
+        /// Derive a child key share for a non-hardened path.
+        ///
+        $(#[$scheme])*
+        ///
+        /// Every participant must use the same chain code and
+        /// path; signatures from the child key shares verify
+        /// against the verifying key of the public key package
+        /// returned by `derive_public_key_package()`.
+        ///
+        /// If an index yields an invalid child key the caller
+        /// should use the next index.
+        pub fn derive_key_share(
+            key_share: &KeyShare,
+            chain_code: &ChainCode,
+            path: &[u32],
+        ) -> Result<KeyShare> {
+            let (key_package, public_key_package) = key_share;
+            let (tweak, public_key_package) =
+                derive_public(public_key_package, chain_code, path)?;
+
+            let signing_share =
+                to_scalar(key_package.signing_share().serialize())?
+                    + tweak;
+            let signing_share = SigningShare::deserialize(
+                ScalarField::serialize(&signing_share).as_ref(),
+            )?;
+            let verifying_share =
+                VerifyingShare::deserialize(&element_bytes(
+                    &(to_element(
+                        key_package.verifying_share().serialize()?,
+                    )? + SuiteGroup::generator() * tweak),
+                )?)?;
+
+            let key_package = KeyPackage::new(
+                *key_package.identifier(),
+                signing_share,
+                verifying_share,
+                *public_key_package.verifying_key(),
+                *key_package.min_signers(),
+            );
+
+            Ok((key_package, public_key_package))
+        }
+
+        /// Derive a child public key package for a non-hardened
+        /// path.
+        ///
+        $(#[$scheme])*
+        ///
+        /// Does not need a key share so a server can derive
+        /// child verifying keys to generate addresses.
+        pub fn derive_public_key_package(
+            public_key_package: &PublicKeyPackage,
+            chain_code: &ChainCode,
+            path: &[u32],
+        ) -> Result<PublicKeyPackage> {
+            Ok(derive_public(public_key_package, chain_code, path)?.1)
+        }
+
+        fn derive_public(
+            public_key_package: &PublicKeyPackage,
+            chain_code: &ChainCode,
+            path: &[u32],
+        ) -> Result<(Scalar, PublicKeyPackage)> {
+            let mut chain_code = *chain_code;
+            let mut tweak = ScalarField::zero();
+            let mut verifying_key = to_element(
+                public_key_package.verifying_key().serialize()?,
+            )?;
+
+            for index in path {
+                let public_key = element_bytes(&verifying_key)?;
+                let (bytes, child_chain_code) =
+                    derive_child(&chain_code, &public_key, *index)?;
+                let child_tweak = to_scalar($tweak(bytes).to_vec())
+                    .map_err(|_| {
+                    Error::InvalidDerivation(*index)
+                })?;
+
+                verifying_key = verifying_key
+                    + SuiteGroup::generator() * child_tweak;
+                if verifying_key == SuiteGroup::identity() {
+                    return Err(Error::InvalidDerivation(*index));
+                }
+
+                tweak = tweak + child_tweak;
+                chain_code = child_chain_code;
+            }
+
+            let offset = SuiteGroup::generator() * tweak;
+            let mut verifying_shares = BTreeMap::new();
+            for (id, share) in public_key_package.verifying_shares() {
+                let element =
+                    to_element(share.serialize()?)? + offset;
+                verifying_shares.insert(
+                    *id,
+                    VerifyingShare::deserialize(&element_bytes(
+                        &element,
+                    )?)?,
+                );
+            }
+
+            let verifying_key = VerifyingKey::deserialize(
+                &element_bytes(&verifying_key)?,
+            )?;
+
+            Ok((
+                tweak,
+                PublicKeyPackage::new(
+                    verifying_shares,
+                    verifying_key,
+                ),
+            ))
+        }
+
+        fn to_scalar(bytes: Vec<u8>) -> Result<Scalar> {
+            let bytes: <ScalarField as Field>::Serialization = bytes
+                .try_into()
+                .map_err(|_| Error::DeriveEncoding)?;
+            ScalarField::deserialize(&bytes)
+                .map_err(|_| Error::DeriveEncoding)
+        }
+
+        fn to_element(bytes: Vec<u8>) -> Result<Element> {
+            let bytes: <SuiteGroup as Group>::Serialization = bytes
+                .try_into()
+                .map_err(|_| Error::DeriveEncoding)?;
+            SuiteGroup::deserialize(&bytes)
+                .map_err(|_| Error::DeriveEncoding)
+        }
+
+        fn element_bytes(element: &Element) -> Result<Vec<u8>> {
+            Ok(SuiteGroup::serialize(element)
+                .map_err(|_| Error::DeriveEncoding)?
+                .as_ref()
+                .to_vec())
+        }
+    };
+}
+
+pub(crate) use frost_derive_impl;
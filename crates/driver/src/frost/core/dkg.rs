@@ -16,11 +16,17 @@ macro_rules! frost_dkg_impl {
         }
 
         /// FROST keygen driver.
+        ///
+        /// Round packages are signed by the participant signing
+        /// key and verified against the verifier for the sender.
         pub struct DkgDriver {
+            session_id: SessionId,
             #[allow(dead_code)]
             party_number: NonZeroU16,
             params: Parameters,
             identifiers: Vec<$id>,
+            signer: SigningKey,
+            verifiers: Vec<VerifyingKey>,
             id: Identifier,
             round_number: u8,
 
@@ -33,10 +39,16 @@ macro_rules! frost_dkg_impl {
 
         impl DkgDriver {
             /// Create a key generator.
+            ///
+            /// Verifiers must be in the same order as the
+            /// identifiers.
             pub fn new(
+                session_id: SessionId,
                 party_number: NonZeroU16,
                 params: Parameters,
                 identifiers: Vec<$id>,
+                signer: SigningKey,
+                verifiers: Vec<VerifyingKey>,
             ) -> Result<Self> {
                 let party_index: usize = party_number.get() as usize;
                 let self_index = party_index - 1;
@@ -44,10 +56,20 @@ macro_rules! frost_dkg_impl {
                     .get(self_index)
                     .ok_or(Error::IndexIdentifier(party_index))?;
 
+                if verifiers.len() != identifiers.len() {
+                    return Err(Error::VerifiersLength(
+                        identifiers.len(),
+                        verifiers.len(),
+                    ));
+                }
+
                 Ok(Self {
+                    session_id,
                     party_number,
                     params,
                     identifiers,
+                    signer,
+                    verifiers,
                     id,
                     round_number: ROUND_1,
 
@@ -62,7 +84,8 @@ macro_rules! frost_dkg_impl {
 
         impl ProtocolDriver for DkgDriver {
             type Error = Error;
-            type Message = RoundMessage<DkgPackage, $id>;
+            type Message =
+                RoundMessage<SignedPackage<DkgPackage>, $id>;
             type Output = $out;
 
             fn round_info(&self) -> Result<RoundInfo> {
@@ -115,16 +138,26 @@ macro_rules! frost_dkg_impl {
                                 NonZeroU16::new((index + 1) as u16)
                                     .unwrap();
 
-                            let message = RoundMessage {
-                                round: NonZeroU16::new(
-                                    self.round_number.into(),
-                                )
-                                .unwrap(),
-                                sender: self.id.clone(),
+                            let round = NonZeroU16::new(
+                                self.round_number.into(),
+                            )
+                            .unwrap();
+                            let body = sign_package(
+                                &self.signer,
+                                &self.session_id,
+                                round,
+                                &self.id,
                                 receiver,
-                                body: DkgPackage::Round1(
+                                DkgPackage::Round1(
                                     public_package.clone(),
                                 ),
+                            )?;
+
+                            let message = RoundMessage {
+                                round,
+                                sender: self.id.clone(),
+                                receiver,
+                                body,
                             };
 
                             messages.push(message);
@@ -168,14 +201,24 @@ macro_rules! frost_dkg_impl {
                                 NonZeroU16::new((index + 1) as u16)
                                     .unwrap();
 
+                            let round = NonZeroU16::new(
+                                self.round_number.into(),
+                            )
+                            .unwrap();
+                            let body = sign_package(
+                                &self.signer,
+                                &self.session_id,
+                                round,
+                                &self.id,
+                                receiver,
+                                DkgPackage::Round2(package),
+                            )?;
+
                             let message = RoundMessage {
-                                round: NonZeroU16::new(
-                                    self.round_number.into(),
-                                )
-                                .unwrap(),
+                                round,
                                 sender: self.id.clone(),
                                 receiver,
-                                body: DkgPackage::Round2(package),
+                                body,
                             };
 
                             messages.push(message);
@@ -195,52 +238,48 @@ macro_rules! frost_dkg_impl {
                 message: Self::Message,
             ) -> Result<()> {
                 let round_number = message.round.get() as u8;
-                match round_number {
-                    ROUND_1 => match message.body {
-                        DkgPackage::Round1(package) => {
-                            let party_index = self
-                                .identifiers
-                                .iter()
-                                .position(|v| v == &message.sender)
-                                .ok_or(Error::SenderVerifier)?;
-                            if let Some(id) =
-                                self.identifiers.get(party_index)
-                            {
-                                self.received_round1_packages
-                                    .insert(id.clone(), package);
-
-                                Ok(())
-                            } else {
-                                Err(Error::SenderIdentifier(
-                                    round_number,
-                                    party_index,
-                                ))
-                            }
-                        }
-                        _ => Err(Error::RoundPayload(round_number)),
-                    },
-                    ROUND_2 => match message.body {
-                        DkgPackage::Round2(package) => {
-                            let party_index = self
-                                .identifiers
-                                .iter()
-                                .position(|v| v == &message.sender)
-                                .ok_or(Error::SenderVerifier)?;
-                            if let Some(id) =
-                                self.identifiers.get(party_index)
-                            {
-                                self.received_round2_packages
-                                    .insert(id.clone(), package);
-                                Ok(())
-                            } else {
-                                Err(Error::SenderIdentifier(
-                                    round_number,
-                                    party_index,
-                                ))
-                            }
-                        }
-                        _ => Err(Error::RoundPayload(round_number)),
-                    },
+                let party_index = self
+                    .identifiers
+                    .iter()
+                    .position(|v| v == &message.sender)
+                    .ok_or(Error::SenderVerifier)?;
+                let id = *self.identifiers.get(party_index).ok_or(
+                    Error::SenderIdentifier(
+                        round_number,
+                        party_index,
+                    ),
+                )?;
+                let verifier = self
+                    .verifiers
+                    .get(party_index)
+                    .ok_or(Error::SenderIdentifier(
+                        round_number,
+                        party_index,
+                    ))?;
+
+                let package = verify_package(
+                    verifier,
+                    &self.session_id,
+                    message.round,
+                    &message.sender,
+                    message.receiver,
+                    message.body,
+                )?;
+
+                match (round_number, package) {
+                    (ROUND_1, DkgPackage::Round1(package)) => {
+                        self.received_round1_packages
+                            .insert(id, package);
+                        Ok(())
+                    }
+                    (ROUND_2, DkgPackage::Round2(package)) => {
+                        self.received_round2_packages
+                            .insert(id, package);
+                        Ok(())
+                    }
+                    (ROUND_1, _) | (ROUND_2, _) => {
+                        Err(Error::RoundPayload(round_number))
+                    }
                     _ => Err(Error::InvalidRound(round_number)),
                 }
             }
@@ -0,0 +1,43 @@
+//! Macro to derive FROST identifiers from verifying keys.
+macro_rules! frost_identifiers_impl {
+    () => {
+        /// Derive an identifier for each participant from
+        /// the participant verifying key.
+        ///
+        /// The same verifying key always derives the same
+        /// identifier so identifiers do not need to be stored
+        /// alongside the key shares.
+        pub fn derive_identifiers(
+            verifiers: &[VerifyingKey],
+        ) -> Result<Vec<Identifier>> {
+            let mut identifiers = Vec::with_capacity(verifiers.len());
+            for verifier in verifiers {
+                identifiers.push(Identifier::derive(
+                    &verifying_key_bytes(verifier),
+                )?);
+            }
+            Ok(identifiers)
+        }
+
+        /// Derive identifiers for a subset of signers and check
+        /// each identifier belongs to the key share.
+        pub fn derive_signer_identifiers(
+            verifiers: &[VerifyingKey],
+            key_share: &KeyShare,
+        ) -> Result<Vec<Identifier>> {
+            let identifiers = derive_identifiers(verifiers)?;
+            let verifying_shares = key_share.1.verifying_shares();
+            for id in &identifiers {
+                if !verifying_shares.contains_key(id) {
+                    return Err(Error::DerivedIdentifier);
+                }
+            }
+            if !identifiers.contains(key_share.0.identifier()) {
+                return Err(Error::DerivedIdentifier);
+            }
+            Ok(identifiers)
+        }
+    };
+}
+
+pub(crate) use frost_identifiers_impl;
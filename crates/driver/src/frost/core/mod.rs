@@ -1,6 +1,19 @@
 //! Macros for the FROST protocol.
+pub(crate) mod coordinator;
+pub(crate) mod culprits;
+pub(crate) mod dealer;
+#[cfg(feature = "derive")]
+pub(crate) mod derive;
 pub(crate) mod dkg;
+pub(crate) mod identifiers;
+pub(crate) mod preprocess;
+#[cfg(feature = "reconstruct")]
+pub(crate) mod reconstruct;
+pub(crate) mod refresh;
+pub(crate) mod repair;
+pub(crate) mod reshare;
 pub(crate) mod sign;
+pub(crate) mod signed;
 
 macro_rules! key_share_pem {
     () => {
@@ -0,0 +1,325 @@
+//! Macro to generate nonce preprocessing driver for FROST.
+macro_rules! frost_preprocess_impl {
+    ($r1pub:ty,
+     $r1priv:ty,
+     $id:ty,
+     $part1:ident) => {
+        #[derive(Debug, Serialize, Deserialize)]
+        pub enum PreprocessPackage {
+            Round1(Vec<$r1pub>),
+        }
+
+        /// Preprocessed nonces for a single signing session.
+        ///
+        /// Contains the signing nonces for this participant and
+        /// the commitments of every participant for the same
+        /// index in the batch.
+        #[derive(Debug, Serialize, Deserialize)]
+        pub struct PreprocessedNonces {
+            index: u32,
+            nonces: $r1priv,
+            commitments: BTreeMap<$id, $r1pub>,
+        }
+
+        impl PreprocessedNonces {
+            /// Index of the nonces in the batch.
+            pub fn index(&self) -> u32 {
+                self.index
+            }
+
+            /// Commitments of each participant.
+            pub fn commitments(&self) -> &BTreeMap<$id, $r1pub> {
+                &self.commitments
+            }
+
+            /// Split into the signing nonces and the commitments.
+            pub fn into_parts(
+                self,
+            ) -> ($r1priv, BTreeMap<$id, $r1pub>) {
+                (self.nonces, self.commitments)
+            }
+        }
+
+        /// Store of preprocessed nonces.
+        ///
+        /// Nonces are removed from the store when they are taken
+        /// so they can never be used to sign twice; callers that
+        /// persist the store must do so after every call to
+        /// `take()`.
+        #[derive(Debug, Default, Serialize, Deserialize)]
+        pub struct NonceStore {
+            entries: BTreeMap<u32, PreprocessedNonces>,
+        }
+
+        impl NonceStore {
+            /// Number of unused nonces.
+            pub fn len(&self) -> usize {
+                self.entries.len()
+            }
+
+            /// Whether all the nonces have been used.
+            pub fn is_empty(&self) -> bool {
+                self.entries.is_empty()
+            }
+
+            /// Indices of the unused nonces.
+            pub fn indices(&self) -> Vec<u32> {
+                self.entries.keys().copied().collect()
+            }
+
+            /// Take the nonces at an index.
+            pub fn take(
+                &mut self,
+                index: u32,
+            ) -> Result<PreprocessedNonces> {
+                self.entries
+                    .remove(&index)
+                    .ok_or(Error::NonceUnavailable(index))
+            }
+        }
+
+        /// FROST nonce preprocessing driver.
+        ///
+        /// Generates a batch of signing nonces and exchanges the
+        /// commitments with all the other participants ahead of
+        /// signing.
+        pub struct PreprocessDriver {
+            session_id: SessionId,
+            #[allow(dead_code)]
+            party_number: NonZeroU16,
+            identifiers: Vec<$id>,
+            signer: SigningKey,
+            verifiers: Vec<VerifyingKey>,
+            id: Identifier,
+            batch_size: u32,
+            round_number: u8,
+            key_share: KeyShare,
+            nonces: Vec<$r1priv>,
+            commitments: BTreeMap<$id, Vec<$r1pub>>,
+        }
+
+        impl PreprocessDriver {
+            /// Create a driver.
+            ///
+            /// Verifiers must be in the same order as the
+            /// identifiers.
+            pub fn new(
+                session_id: SessionId,
+                party_number: NonZeroU16,
+                identifiers: Vec<$id>,
+                signer: SigningKey,
+                verifiers: Vec<VerifyingKey>,
+                key_share: KeyShare,
+                batch_size: u32,
+            ) -> Result<Self> {
+                let party_index: usize = party_number.get() as usize;
+                let self_index = party_index - 1;
+                let id = *identifiers
+                    .get(self_index)
+                    .ok_or(Error::IndexIdentifier(party_index))?;
+
+                if verifiers.len() != identifiers.len() {
+                    return Err(Error::VerifiersLength(
+                        identifiers.len(),
+                        verifiers.len(),
+                    ));
+                }
+
+                if batch_size == 0 {
+                    return Err(Error::PreprocessEmptyBatch);
+                }
+
+                Ok(Self {
+                    session_id,
+                    party_number,
+                    identifiers,
+                    signer,
+                    verifiers,
+                    id,
+                    batch_size,
+                    round_number: ROUND_1,
+                    key_share,
+                    nonces: Vec::new(),
+                    commitments: BTreeMap::new(),
+                })
+            }
+        }
+
+        impl ProtocolDriver for PreprocessDriver {
+            type Error = Error;
+            type Message =
+                RoundMessage<SignedPackage<PreprocessPackage>, $id>;
+            type Output = NonceStore;
+
+            fn round_info(&self) -> Result<RoundInfo> {
+                let round_number = self.round_number;
+                let is_echo = false;
+                let can_finalize = match self.round_number {
+                    ROUND_2 => {
+                        self.commitments.len()
+                            == self.identifiers.len()
+                    }
+                    _ => false,
+                };
+                Ok(RoundInfo {
+                    round_number,
+                    can_finalize,
+                    is_echo,
+                })
+            }
+
+            fn proceed(&mut self) -> Result<Vec<Self::Message>> {
+                match self.round_number {
+                    // Round 1 is a broadcast round, the same batch
+                    // of commitments is sent to all participants
+                    ROUND_1 => {
+                        let mut messages = Vec::with_capacity(
+                            self.identifiers.len() - 1,
+                        );
+
+                        let mut commitments = Vec::with_capacity(
+                            self.batch_size as usize,
+                        );
+                        for _ in 0..self.batch_size {
+                            let (nonces, commitment) = $part1::commit(
+                                self.key_share.0.signing_share(),
+                                &mut OsRng,
+                            );
+                            self.nonces.push(nonces);
+                            commitments.push(commitment);
+                        }
+
+                        for (index, id) in
+                            self.identifiers.iter().enumerate()
+                        {
+                            if id == &self.id {
+                                continue;
+                            }
+
+                            let receiver =
+                                NonZeroU16::new((index + 1) as u16)
+                                    .unwrap();
+                            let round = NonZeroU16::new(
+                                self.round_number.into(),
+                            )
+                            .unwrap();
+                            let body = sign_package(
+                                &self.signer,
+                                &self.session_id,
+                                round,
+                                &self.id,
+                                receiver,
+                                PreprocessPackage::Round1(
+                                    commitments.clone(),
+                                ),
+                            )?;
+
+                            let message = RoundMessage {
+                                round,
+                                sender: self.id.clone(),
+                                receiver,
+                                body,
+                            };
+
+                            messages.push(message);
+                        }
+
+                        self.commitments
+                            .insert(self.id.clone(), commitments);
+
+                        self.round_number =
+                            self.round_number.checked_add(1).unwrap();
+
+                        Ok(messages)
+                    }
+                    _ => Err(Error::InvalidRound(self.round_number)),
+                }
+            }
+
+            fn handle_incoming(
+                &mut self,
+                message: Self::Message,
+            ) -> Result<()> {
+                let round_number = message.round.get() as u8;
+                let party_index = self
+                    .identifiers
+                    .iter()
+                    .position(|v| v == &message.sender)
+                    .ok_or(Error::SenderVerifier)?;
+                let id = *self.identifiers.get(party_index).ok_or(
+                    Error::SenderIdentifier(
+                        round_number,
+                        party_index,
+                    ),
+                )?;
+                let verifier = self
+                    .verifiers
+                    .get(party_index)
+                    .ok_or(Error::SenderIdentifier(
+                        round_number,
+                        party_index,
+                    ))?;
+
+                let package = verify_package(
+                    verifier,
+                    &self.session_id,
+                    message.round,
+                    &message.sender,
+                    message.receiver,
+                    message.body,
+                )?;
+
+                match (round_number, package) {
+                    (ROUND_1, PreprocessPackage::Round1(batch)) => {
+                        if batch.len() != self.batch_size as usize {
+                            return Err(Error::PreprocessBatchSize(
+                                self.batch_size,
+                                batch.len(),
+                            ));
+                        }
+                        self.commitments.insert(id, batch);
+                        Ok(())
+                    }
+                    _ => Err(Error::InvalidRound(round_number)),
+                }
+            }
+
+            fn try_finalize_round(
+                &mut self,
+            ) -> Result<Option<Self::Output>> {
+                if self.round_number == ROUND_2
+                    && self.commitments.len()
+                        == self.identifiers.len()
+                {
+                    let mut store = NonceStore::default();
+                    let nonces = std::mem::take(&mut self.nonces);
+                    for (index, nonces) in
+                        nonces.into_iter().enumerate()
+                    {
+                        let commitments = self
+                            .commitments
+                            .iter()
+                            .map(|(id, batch)| {
+                                (*id, batch[index].clone())
+                            })
+                            .collect();
+                        let index = index as u32;
+                        store.entries.insert(
+                            index,
+                            PreprocessedNonces {
+                                index,
+                                nonces,
+                                commitments,
+                            },
+                        );
+                    }
+                    Ok(Some(store))
+                } else {
+                    Ok(None)
+                }
+            }
+        }
+    };
+}
+
+pub(crate) use frost_preprocess_impl;
@@ -0,0 +1,45 @@
+//! Macro to generate signing key reconstruction for FROST.
+macro_rules! frost_reconstruct_impl {
+    () => {
+        // Reconstruct the FROST signing key and verify it
+        // matches the group verifying key.
+        fn reconstruct_signing_key(
+            key_shares: &[KeyShare],
+        ) -> Result<FrostSigningKey> {
+            let min_signers = key_shares
+                .first()
+                .map(|k| *k.0.min_signers())
+                .unwrap_or(1);
+            if key_shares.len() < min_signers as usize {
+                return Err(Error::ReconstructThreshold(
+                    min_signers,
+                    key_shares.len(),
+                ));
+            }
+
+            let verifying_key = *key_shares[0].1.verifying_key();
+            for key_share in key_shares {
+                if key_share.0.verifying_key() != &verifying_key
+                    || key_share.1.verifying_key() != &verifying_key
+                {
+                    return Err(Error::ReconstructVerifyingKey);
+                }
+            }
+
+            let key_packages = key_shares
+                .iter()
+                .map(|k| k.0.clone())
+                .collect::<Vec<_>>();
+            let signing_key = reconstruct(&key_packages)?;
+
+            let reconstructed = FrostVerifyingKey::from(&signing_key);
+            if reconstructed != verifying_key {
+                return Err(Error::ReconstructVerifyingKey);
+            }
+
+            Ok(signing_key)
+        }
+    };
+}
+
+pub(crate) use frost_reconstruct_impl;
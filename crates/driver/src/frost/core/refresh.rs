@@ -0,0 +1,324 @@
+//! Macro to generate key share refresh driver for FROST.
+macro_rules! frost_refresh_impl {
+    ($suite:ty,
+     $share:ty,
+     $pub:ty,
+     $id:ty,
+     $out:ty,
+     $compute:ident,
+     $refresh:ident) => {
+        #[derive(Debug, Serialize, Deserialize)]
+        pub enum RefreshPackage {
+            /// Refreshing share and new public key package
+            /// sent by the dealer.
+            Round1($share, $pub),
+            /// Sent to the dealer by the other participants
+            /// to indicate they are ready.
+            Ready,
+        }
+
+        /// FROST key share refresh driver.
+        ///
+        /// The first party acts as the dealer and computes
+        /// the refreshing shares for all participants; the
+        /// group verifying key does not change.
+        ///
+        /// All the participants holding a key share should
+        /// take part otherwise the key shares for missing
+        /// participants are no longer valid.
+        ///
+        /// # ⚠️ Warning
+        ///
+        /// The dealer is trusted; it knows the refreshing share
+        /// of every participant so old key shares leaked to the
+        /// dealer remain useful after the refresh.
+        ///
+        /// Round packages are signed by the participant signing
+        /// key and verified against the verifier for the sender.
+        pub struct RefreshDriver {
+            session_id: SessionId,
+            #[allow(dead_code)]
+            party_number: NonZeroU16,
+            params: Parameters,
+            identifiers: Vec<$id>,
+            signer: SigningKey,
+            verifiers: Vec<VerifyingKey>,
+            id: $id,
+            dealer: $id,
+            round_number: u8,
+            key_share: $out,
+            refreshed: Option<$out>,
+            ready: BTreeSet<$id>,
+            refresh_package: Option<($share, $pub)>,
+        }
+
+        impl RefreshDriver {
+            /// Create a key share refresh driver.
+            ///
+            /// Verifiers must be in the same order as the
+            /// identifiers.
+            pub fn new(
+                session_id: SessionId,
+                party_number: NonZeroU16,
+                params: Parameters,
+                identifiers: Vec<$id>,
+                signer: SigningKey,
+                verifiers: Vec<VerifyingKey>,
+                key_share: $out,
+            ) -> Result<Self> {
+                let party_index: usize = party_number.get() as usize;
+                let self_index = party_index - 1;
+                let id = *identifiers
+                    .get(self_index)
+                    .ok_or(Error::IndexIdentifier(party_index))?;
+
+                if verifiers.len() != identifiers.len() {
+                    return Err(Error::VerifiersLength(
+                        identifiers.len(),
+                        verifiers.len(),
+                    ));
+                }
+                let dealer = *identifiers
+                    .first()
+                    .ok_or(Error::IndexIdentifier(1))?;
+
+                Ok(Self {
+                    session_id,
+                    party_number,
+                    params,
+                    identifiers,
+                    signer,
+                    verifiers,
+                    id,
+                    dealer,
+                    round_number: ROUND_1,
+                    key_share,
+                    refreshed: None,
+                    ready: BTreeSet::new(),
+                    refresh_package: None,
+                })
+            }
+
+            fn is_dealer(&self) -> bool {
+                self.id == self.dealer
+            }
+        }
+
+        impl ProtocolDriver for RefreshDriver {
+            type Error = Error;
+            type Message =
+                RoundMessage<SignedPackage<RefreshPackage>, $id>;
+            type Output = $out;
+
+            fn round_info(&self) -> Result<RoundInfo> {
+                let needs = self.identifiers.len() - 1;
+                let round_number = self.round_number;
+                let is_echo = false;
+                let can_finalize = match self.round_number {
+                    ROUND_2 => {
+                        if self.is_dealer() {
+                            self.ready.len() == needs
+                        } else {
+                            self.refresh_package.is_some()
+                        }
+                    }
+                    _ => false,
+                };
+                Ok(RoundInfo {
+                    round_number,
+                    can_finalize,
+                    is_echo,
+                })
+            }
+
+            fn proceed(&mut self) -> Result<Vec<Self::Message>> {
+                match self.round_number {
+                    // Round 1 the dealer sends a different package
+                    // to each of the other participants and the
+                    // other participants notify the dealer
+                    ROUND_1 => {
+                        let mut messages = Vec::with_capacity(
+                            self.identifiers.len() - 1,
+                        );
+
+                        let round =
+                            NonZeroU16::new(self.round_number.into())
+                                .unwrap();
+
+                        if self.is_dealer() {
+                            let (shares, public_key_package) =
+                                $compute(
+                                    self.key_share.1.clone(),
+                                    self.params.parties,
+                                    self.params.threshold,
+                                    &self.identifiers,
+                                    &mut OsRng,
+                                )?;
+
+                            for (index, (id, share)) in self
+                                .identifiers
+                                .iter()
+                                .zip(shares.into_iter())
+                                .enumerate()
+                            {
+                                if id == &self.id {
+                                    let key_package =
+                                        $refresh::<$suite>(
+                                            share,
+                                            &self.key_share.0,
+                                        )?;
+                                    self.refreshed = Some((
+                                        key_package,
+                                        public_key_package.clone(),
+                                    ));
+                                    continue;
+                                }
+
+                                let receiver = NonZeroU16::new(
+                                    (index + 1) as u16,
+                                )
+                                .unwrap();
+
+                                let body = sign_package(
+                                    &self.signer,
+                                    &self.session_id,
+                                    round,
+                                    &self.id,
+                                    receiver,
+                                    RefreshPackage::Round1(
+                                        share,
+                                        public_key_package.clone(),
+                                    ),
+                                )?;
+
+                                messages.push(RoundMessage {
+                                    round,
+                                    sender: self.id.clone(),
+                                    receiver,
+                                    body,
+                                });
+                            }
+                        } else {
+                            let receiver =
+                                NonZeroU16::new(1).unwrap();
+                            let body = sign_package(
+                                &self.signer,
+                                &self.session_id,
+                                round,
+                                &self.id,
+                                receiver,
+                                RefreshPackage::Ready,
+                            )?;
+                            messages.push(RoundMessage {
+                                round,
+                                sender: self.id.clone(),
+                                receiver,
+                                body,
+                            });
+                        }
+
+                        self.round_number =
+                            self.round_number.checked_add(1).unwrap();
+
+                        Ok(messages)
+                    }
+                    _ => Err(Error::InvalidRound(self.round_number)),
+                }
+            }
+
+            fn handle_incoming(
+                &mut self,
+                message: Self::Message,
+            ) -> Result<()> {
+                let round_number = message.round.get() as u8;
+                match round_number {
+                    ROUND_1 => {
+                        let party_index = self
+                            .identifiers
+                            .iter()
+                            .position(|v| v == &message.sender)
+                            .ok_or(Error::SenderVerifier)?;
+                        let id = self
+                            .identifiers
+                            .get(party_index)
+                            .ok_or(Error::SenderIdentifier(
+                                round_number,
+                                party_index,
+                            ))?;
+                        let verifier = self
+                            .verifiers
+                            .get(party_index)
+                            .ok_or(Error::SenderIdentifier(
+                                round_number,
+                                party_index,
+                            ))?;
+
+                        let package = verify_package(
+                            verifier,
+                            &self.session_id,
+                            message.round,
+                            &message.sender,
+                            message.receiver,
+                            message.body,
+                        )?;
+
+                        match package {
+                            RefreshPackage::Round1(
+                                share,
+                                public_key_package,
+                            ) if id == &self.dealer => {
+                                self.refresh_package =
+                                    Some((share, public_key_package));
+                                Ok(())
+                            }
+                            RefreshPackage::Ready
+                                if self.is_dealer() =>
+                            {
+                                self.ready.insert(id.clone());
+                                Ok(())
+                            }
+                            _ => {
+                                Err(Error::RoundPayload(round_number))
+                            }
+                        }
+                    }
+                    _ => Err(Error::InvalidRound(round_number)),
+                }
+            }
+
+            fn try_finalize_round(
+                &mut self,
+            ) -> Result<Option<Self::Output>> {
+                if self.round_number != ROUND_2 {
+                    return Ok(None);
+                }
+
+                if self.is_dealer() {
+                    if self.ready.len() == self.identifiers.len() - 1
+                    {
+                        return Ok(self.refreshed.take());
+                    }
+                } else if let Some((share, public_key_package)) =
+                    self.refresh_package.take()
+                {
+                    if public_key_package.verifying_key()
+                        != self.key_share.1.verifying_key()
+                    {
+                        return Err(Error::RefreshVerifyingKey);
+                    }
+
+                    let key_package =
+                        $refresh::<$suite>(share, &self.key_share.0)?;
+                    return Ok(Some((
+                        key_package,
+                        public_key_package,
+                    )));
+                }
+
+                Ok(None)
+            }
+        }
+    };
+}
+
+pub(crate) use frost_refresh_impl;
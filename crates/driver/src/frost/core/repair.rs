@@ -0,0 +1,445 @@
+//! Macro to generate key share repair driver for FROST.
+macro_rules! frost_repair_impl {
+    ($suite:ty,
+     $id:ty,
+     $out:ty,
+     $step1:ident,
+     $step2:ident,
+     $step3:ident) => {
+        type ScalarField =
+            <<$suite as Ciphersuite>::Group as Group>::Field;
+        type Scalar = <ScalarField as Field>::Scalar;
+
+        #[derive(Debug, Serialize, Deserialize)]
+        pub enum RepairPackage {
+            /// Delta sent from a helper to another helper.
+            Round1(Vec<u8>),
+            /// Sigma and public key package sent from a helper
+            /// to the participant being repaired.
+            Round2(Vec<u8>, PublicKeyPackage),
+            /// Sent from a helper to the other helpers once
+            /// the sigma has been sent.
+            Done,
+        }
+
+        /// FROST key share repair driver.
+        ///
+        /// Implements the repairable threshold scheme so that
+        /// at least threshold helpers can recover the key share
+        /// for a participant that has lost their key share
+        /// without changing the group verifying key.
+        ///
+        /// Round packages are signed by the participant signing
+        /// key and verified against the verifier for the sender.
+        pub struct RepairDriver {
+            session_id: SessionId,
+            #[allow(dead_code)]
+            party_number: NonZeroU16,
+            params: Parameters,
+            identifiers: Vec<$id>,
+            signer: SigningKey,
+            verifiers: Vec<VerifyingKey>,
+            helpers: Vec<$id>,
+            id: $id,
+            lost: $id,
+            round_number: u8,
+            key_share: Option<$out>,
+            deltas: BTreeMap<$id, Scalar>,
+            sigmas: BTreeMap<$id, Scalar>,
+            done: BTreeSet<$id>,
+            public_key_package: Option<PublicKeyPackage>,
+        }
+
+        impl RepairDriver {
+            /// Create a key share repair driver.
+            ///
+            /// Helpers must provide their key share and the
+            /// participant being repaired must not.
+            ///
+            /// Verifiers must be in the same order as the
+            /// identifiers.
+            pub fn new(
+                session_id: SessionId,
+                party_number: NonZeroU16,
+                params: Parameters,
+                identifiers: Vec<$id>,
+                signer: SigningKey,
+                verifiers: Vec<VerifyingKey>,
+                lost: $id,
+                key_share: Option<$out>,
+            ) -> Result<Self> {
+                let party_index: usize = party_number.get() as usize;
+                let self_index = party_index - 1;
+                let id = *identifiers
+                    .get(self_index)
+                    .ok_or(Error::IndexIdentifier(party_index))?;
+
+                if verifiers.len() != identifiers.len() {
+                    return Err(Error::VerifiersLength(
+                        identifiers.len(),
+                        verifiers.len(),
+                    ));
+                }
+
+                if !identifiers.contains(&lost) {
+                    return Err(Error::RepairParticipant);
+                }
+
+                if (id == lost) == key_share.is_some() {
+                    return Err(Error::RepairKeyShare);
+                }
+
+                let helpers = identifiers
+                    .iter()
+                    .filter(|i| *i != &lost)
+                    .copied()
+                    .collect::<Vec<_>>();
+                if helpers.len() < params.threshold as usize {
+                    return Err(Error::RepairHelpers(
+                        params.threshold,
+                        helpers.len(),
+                    ));
+                }
+
+                Ok(Self {
+                    session_id,
+                    party_number,
+                    params,
+                    identifiers,
+                    signer,
+                    verifiers,
+                    helpers,
+                    id,
+                    lost,
+                    round_number: ROUND_1,
+                    key_share,
+                    deltas: BTreeMap::new(),
+                    sigmas: BTreeMap::new(),
+                    done: BTreeSet::new(),
+                    public_key_package: None,
+                })
+            }
+
+            fn is_lost(&self) -> bool {
+                self.id == self.lost
+            }
+
+            fn receiver(&self, id: &$id) -> NonZeroU16 {
+                let index = self
+                    .identifiers
+                    .iter()
+                    .position(|i| i == id)
+                    .unwrap();
+                NonZeroU16::new((index + 1) as u16).unwrap()
+            }
+
+            fn message(
+                &self,
+                round: NonZeroU16,
+                receiver: &$id,
+                package: RepairPackage,
+            ) -> Result<<Self as ProtocolDriver>::Message> {
+                let receiver = self.receiver(receiver);
+                let body = sign_package(
+                    &self.signer,
+                    &self.session_id,
+                    round,
+                    &self.id,
+                    receiver,
+                    package,
+                )?;
+                Ok(RoundMessage {
+                    round,
+                    sender: self.id.clone(),
+                    receiver,
+                    body,
+                })
+            }
+
+            fn serialize_scalar(scalar: &Scalar) -> Vec<u8> {
+                ScalarField::serialize(scalar).as_ref().to_vec()
+            }
+
+            fn deserialize_scalar(bytes: Vec<u8>) -> Result<Scalar> {
+                let bytes: <ScalarField as Field>::Serialization =
+                    bytes
+                        .try_into()
+                        .map_err(|_| Error::RepairScalar)?;
+                ScalarField::deserialize(&bytes)
+                    .map_err(|_| Error::RepairScalar)
+            }
+
+            // Commitment is not used to compute the deltas
+            // or the signing share so an empty commitment
+            // is used when calling the repair functions.
+            fn empty_commitment(
+            ) -> Result<VerifiableSecretSharingCommitment> {
+                Ok(VerifiableSecretSharingCommitment::deserialize(
+                    Vec::new(),
+                )?)
+            }
+        }
+
+        impl ProtocolDriver for RepairDriver {
+            type Error = Error;
+            type Message =
+                RoundMessage<SignedPackage<RepairPackage>, $id>;
+            type Output = $out;
+
+            fn round_info(&self) -> Result<RoundInfo> {
+                let round_number = self.round_number;
+                let is_echo = false;
+                let can_finalize = match self.round_number {
+                    ROUND_2 => {
+                        if self.is_lost() {
+                            self.sigmas.len() == self.helpers.len()
+                        } else {
+                            self.deltas.len() == self.helpers.len()
+                        }
+                    }
+                    ROUND_3 => {
+                        self.done.len() == self.helpers.len() - 1
+                    }
+                    _ => false,
+                };
+                Ok(RoundInfo {
+                    round_number,
+                    can_finalize,
+                    is_echo,
+                })
+            }
+
+            fn proceed(&mut self) -> Result<Vec<Self::Message>> {
+                let round = NonZeroU16::new(self.round_number.into())
+                    .unwrap();
+                match self.round_number {
+                    // Round 1 is a p2p round, each helper sends
+                    // a different delta to each of the other helpers
+                    ROUND_1 => {
+                        let mut messages = Vec::with_capacity(
+                            self.helpers.len() - 1,
+                        );
+
+                        if let Some(key_share) = &self.key_share {
+                            let share = SecretShare::new(
+                                *key_share.0.identifier(),
+                                *key_share.0.signing_share(),
+                                Self::empty_commitment()?,
+                            );
+
+                            let deltas = $step1::<$suite, _>(
+                                &self.helpers,
+                                &share,
+                                &mut OsRng,
+                                self.lost,
+                            )?;
+
+                            for (id, delta) in deltas {
+                                if id == self.id {
+                                    self.deltas.insert(id, delta);
+                                    continue;
+                                }
+
+                                messages.push(self.message(
+                                    round,
+                                    &id,
+                                    RepairPackage::Round1(
+                                        Self::serialize_scalar(
+                                            &delta,
+                                        ),
+                                    ),
+                                )?);
+                            }
+                        }
+
+                        self.round_number =
+                            self.round_number.checked_add(1).unwrap();
+
+                        Ok(messages)
+                    }
+                    // Round 2 each helper sends the sigma to the
+                    // participant being repaired
+                    ROUND_2 if !self.is_lost() => {
+                        let mut messages =
+                            Vec::with_capacity(self.helpers.len());
+
+                        let key_share = self
+                            .key_share
+                            .as_ref()
+                            .ok_or(Error::RepairKeyShare)?;
+
+                        let deltas = self
+                            .deltas
+                            .values()
+                            .copied()
+                            .collect::<Vec<_>>();
+                        let sigma = $step2(&deltas);
+
+                        messages.push(self.message(
+                            round,
+                            &self.lost,
+                            RepairPackage::Round2(
+                                Self::serialize_scalar(&sigma),
+                                key_share.1.clone(),
+                            ),
+                        )?);
+
+                        for id in &self.helpers {
+                            if id == &self.id {
+                                continue;
+                            }
+
+                            messages.push(self.message(
+                                round,
+                                id,
+                                RepairPackage::Done,
+                            )?);
+                        }
+
+                        self.round_number =
+                            self.round_number.checked_add(1).unwrap();
+
+                        Ok(messages)
+                    }
+                    _ => Err(Error::InvalidRound(self.round_number)),
+                }
+            }
+
+            fn handle_incoming(
+                &mut self,
+                message: Self::Message,
+            ) -> Result<()> {
+                let round_number = message.round.get() as u8;
+                let sender = *self
+                    .helpers
+                    .iter()
+                    .find(|v| *v == &message.sender)
+                    .ok_or(Error::SenderVerifier)?;
+                let party_index = self
+                    .identifiers
+                    .iter()
+                    .position(|v| v == &sender)
+                    .ok_or(Error::SenderVerifier)?;
+                let verifier = self
+                    .verifiers
+                    .get(party_index)
+                    .ok_or(Error::SenderIdentifier(
+                        round_number,
+                        party_index,
+                    ))?;
+
+                let package = verify_package(
+                    verifier,
+                    &self.session_id,
+                    message.round,
+                    &message.sender,
+                    message.receiver,
+                    message.body,
+                )?;
+
+                match (round_number, package) {
+                    (ROUND_1, RepairPackage::Round1(delta))
+                        if !self.is_lost() =>
+                    {
+                        let delta = Self::deserialize_scalar(delta)?;
+                        self.deltas.insert(sender, delta);
+                        Ok(())
+                    }
+                    (
+                        ROUND_2,
+                        RepairPackage::Round2(
+                            sigma,
+                            public_key_package,
+                        ),
+                    ) if self.is_lost() => {
+                        if let Some(existing) =
+                            &self.public_key_package
+                        {
+                            if existing != &public_key_package {
+                                return Err(
+                                    Error::RepairPublicKeyPackage,
+                                );
+                            }
+                        } else {
+                            self.public_key_package =
+                                Some(public_key_package);
+                        }
+                        let sigma = Self::deserialize_scalar(sigma)?;
+                        self.sigmas.insert(sender, sigma);
+                        Ok(())
+                    }
+                    (ROUND_2, RepairPackage::Done)
+                        if !self.is_lost() =>
+                    {
+                        self.done.insert(sender);
+                        Ok(())
+                    }
+                    (ROUND_1, _) | (ROUND_2, _) => {
+                        Err(Error::RoundPayload(round_number))
+                    }
+                    _ => Err(Error::InvalidRound(round_number)),
+                }
+            }
+
+            fn try_finalize_round(
+                &mut self,
+            ) -> Result<Option<Self::Output>> {
+                match self.round_number {
+                    ROUND_2
+                        if self.is_lost()
+                            && self.sigmas.len()
+                                == self.helpers.len() =>
+                    {
+                        let public_key_package = self
+                            .public_key_package
+                            .take()
+                            .ok_or(Error::Round3TooEarly)?;
+
+                        let sigmas = self
+                            .sigmas
+                            .values()
+                            .copied()
+                            .collect::<Vec<_>>();
+                        let share = $step3(
+                            &sigmas,
+                            self.id,
+                            &Self::empty_commitment()?,
+                        );
+                        let signing_share = *share.signing_share();
+
+                        // Verify the repaired signing share against
+                        // the verifying share for this participant
+                        let verifying_share = *public_key_package
+                            .verifying_shares()
+                            .get(&self.id)
+                            .ok_or(Error::RepairPublicKeyPackage)?;
+                        if VerifyingShare::from(signing_share)
+                            != verifying_share
+                        {
+                            return Err(Error::RepairVerifyingShare);
+                        }
+
+                        let key_package = KeyPackage::new(
+                            self.id,
+                            signing_share,
+                            verifying_share,
+                            *public_key_package.verifying_key(),
+                            self.params.threshold,
+                        );
+
+                        Ok(Some((key_package, public_key_package)))
+                    }
+                    ROUND_3
+                        if self.done.len()
+                            == self.helpers.len() - 1 =>
+                    {
+                        Ok(self.key_share.take())
+                    }
+                    _ => Ok(None),
+                }
+            }
+        }
+    };
+}
+
+pub(crate) use frost_repair_impl;
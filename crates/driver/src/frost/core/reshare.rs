@@ -0,0 +1,638 @@
+//! Macro to generate key resharing driver for FROST.
+macro_rules! frost_reshare_impl {
+    ($suite:ty,
+     $id:ty,
+     $out:ty) => {
+        type SuiteGroup = <$suite as Ciphersuite>::Group;
+        type ScalarField = <SuiteGroup as Group>::Field;
+        type Scalar = <ScalarField as Field>::Scalar;
+        type Element = <SuiteGroup as Group>::Element;
+
+        #[derive(Debug, Serialize, Deserialize)]
+        pub enum ResharePackage {
+            /// Sent by an old participant to each of the other
+            /// participants.
+            Round1 {
+                /// Commitments to the polynomial coefficients.
+                commitments: Vec<Vec<u8>>,
+                /// Secret share when the receiver is a new
+                /// participant.
+                share: Option<Vec<u8>>,
+            },
+            /// Sent by a new participant to each of the other
+            /// new participants to echo the commitments received
+            /// from every old participant in party order.
+            Round2(Vec<Vec<Vec<u8>>>),
+        }
+
+        /// FROST key resharing driver.
+        ///
+        /// Old participants (at least the old threshold) share
+        /// their Lagrange-weighted signing shares with the new
+        /// participants using a new polynomial for the new
+        /// threshold; the group verifying key does not change.
+        ///
+        /// Each party in the session is represented by an optional
+        /// old identifier and an optional new identifier in party
+        /// number order. Parties with an old identifier must provide
+        /// their key share and parties with a new identifier receive
+        /// a new key share. Messages are sent using the old
+        /// identifier of a party or the new identifier when it
+        /// does not have an old identifier.
+        ///
+        /// Commitments are sent point to point so the new
+        /// participants echo the commitments they received to
+        /// each other before accepting a key share; an old
+        /// participant that sends different commitments to
+        /// different parties causes the resharing to fail.
+        ///
+        /// Round packages are signed by the participant signing
+        /// key and verified against the verifier for the sender.
+        pub struct ReshareDriver {
+            session_id: SessionId,
+            parties: Vec<(Option<$id>, Option<$id>)>,
+            senders: Vec<$id>,
+            signer: SigningKey,
+            verifiers: Vec<PartyVerifyingKey>,
+            dealers: Vec<$id>,
+            receivers: Vec<$id>,
+            self_index: usize,
+            new_threshold: u16,
+            verifying_key: VerifyingKey,
+            key_share: Option<$out>,
+            round_number: u8,
+            packages: BTreeMap<$id, (Vec<Element>, Option<Scalar>)>,
+            echo: Option<Vec<Vec<Vec<u8>>>>,
+            echoes: BTreeMap<usize, Vec<Vec<Vec<u8>>>>,
+        }
+
+        impl ReshareDriver {
+            /// Create a key resharing driver.
+            ///
+            /// Verifiers must be in the same order as the
+            /// parties.
+            ///
+            /// The old threshold must match the key shares of
+            /// the old participants and at least that many old
+            /// participants must take part.
+            #[allow(clippy::too_many_arguments)]
+            pub fn new(
+                session_id: SessionId,
+                party_number: NonZeroU16,
+                parties: Vec<(Option<$id>, Option<$id>)>,
+                signer: SigningKey,
+                verifiers: Vec<PartyVerifyingKey>,
+                old_threshold: u16,
+                new_threshold: u16,
+                verifying_key: VerifyingKey,
+                key_share: Option<$out>,
+            ) -> Result<Self> {
+                let party_index: usize = party_number.get() as usize;
+                let self_index = party_index - 1;
+                let (old_id, _) = parties
+                    .get(self_index)
+                    .ok_or(Error::IndexIdentifier(party_index))?;
+
+                if verifiers.len() != parties.len() {
+                    return Err(Error::VerifiersLength(
+                        parties.len(),
+                        verifiers.len(),
+                    ));
+                }
+
+                match (old_id, &key_share) {
+                    (Some(id), Some(key_share))
+                        if key_share.0.identifier() == id
+                            && *key_share.0.min_signers()
+                                == old_threshold => {}
+                    (None, None) => {}
+                    _ => return Err(Error::ReshareKeyShare),
+                }
+
+                let mut senders = Vec::with_capacity(parties.len());
+                for (old, new) in &parties {
+                    let sender =
+                        old.or(*new).ok_or(Error::ReshareParties)?;
+                    if senders.contains(&sender) {
+                        return Err(Error::ReshareParties);
+                    }
+                    senders.push(sender);
+                }
+
+                let dealers = parties
+                    .iter()
+                    .filter_map(|(old, _)| *old)
+                    .collect::<Vec<_>>();
+                let receivers = parties
+                    .iter()
+                    .filter_map(|(_, new)| *new)
+                    .collect::<Vec<_>>();
+
+                if old_threshold < 2
+                    || dealers.len() < old_threshold as usize
+                {
+                    return Err(Error::ReshareDealers(
+                        old_threshold,
+                        dealers.len(),
+                    ));
+                }
+
+                if new_threshold < 2
+                    || receivers.len() < new_threshold as usize
+                {
+                    return Err(Error::ReshareThreshold(
+                        new_threshold,
+                        receivers.len(),
+                    ));
+                }
+
+                Ok(Self {
+                    session_id,
+                    parties,
+                    senders,
+                    signer,
+                    verifiers,
+                    dealers,
+                    receivers,
+                    self_index,
+                    new_threshold,
+                    verifying_key,
+                    key_share,
+                    round_number: ROUND_1,
+                    packages: BTreeMap::new(),
+                    echo: None,
+                    echoes: BTreeMap::new(),
+                })
+            }
+
+            fn old_id(&self) -> Option<$id> {
+                self.parties[self.self_index].0
+            }
+
+            fn new_id(&self) -> Option<$id> {
+                self.parties[self.self_index].1
+            }
+
+            fn to_bytes<T: AsRef<[u8]>>(value: T) -> Vec<u8> {
+                value.as_ref().to_vec()
+            }
+
+            fn to_scalar(bytes: Vec<u8>) -> Result<Scalar> {
+                let bytes: <ScalarField as Field>::Serialization =
+                    bytes
+                        .try_into()
+                        .map_err(|_| Error::ReshareEncoding)?;
+                ScalarField::deserialize(&bytes)
+                    .map_err(|_| Error::ReshareEncoding)
+            }
+
+            fn to_element(bytes: Vec<u8>) -> Result<Element> {
+                let bytes: <SuiteGroup as Group>::Serialization =
+                    bytes
+                        .try_into()
+                        .map_err(|_| Error::ReshareEncoding)?;
+                SuiteGroup::deserialize(&bytes)
+                    .map_err(|_| Error::ReshareEncoding)
+            }
+
+            fn element_bytes(element: &Element) -> Result<Vec<u8>> {
+                Ok(Self::to_bytes(
+                    SuiteGroup::serialize(element)
+                        .map_err(|_| Error::ReshareEncoding)?,
+                ))
+            }
+
+            // Commitments from each old participant in party order.
+            fn commitments_echo(&self) -> Result<Vec<Vec<Vec<u8>>>> {
+                let mut echo = Vec::with_capacity(self.dealers.len());
+                for dealer in &self.dealers {
+                    let (commitments, _) = self
+                        .packages
+                        .get(dealer)
+                        .ok_or(Error::RoundPayload(ROUND_2))?;
+                    let mut encoded =
+                        Vec::with_capacity(commitments.len());
+                    for commitment in commitments {
+                        encoded
+                            .push(Self::element_bytes(commitment)?);
+                    }
+                    echo.push(encoded);
+                }
+                Ok(echo)
+            }
+
+            fn id_scalar(id: &$id) -> Result<Scalar> {
+                Self::to_scalar(Self::to_bytes(id.serialize()))
+            }
+
+            // Lagrange coefficient at zero for an old participant.
+            fn lagrange(&self, id: &$id) -> Result<Scalar> {
+                let x_i = Self::id_scalar(id)?;
+                let mut num = ScalarField::one();
+                let mut den = ScalarField::one();
+                for dealer in &self.dealers {
+                    if dealer == id {
+                        continue;
+                    }
+                    let x_j = Self::id_scalar(dealer)?;
+                    num = num * x_j;
+                    den = den * (x_j - x_i);
+                }
+                let den = ScalarField::invert(&den)
+                    .map_err(|_| Error::ReshareEncoding)?;
+                Ok(num * den)
+            }
+
+            fn evaluate(
+                coefficients: &[Scalar],
+                x: &Scalar,
+            ) -> Scalar {
+                let mut value = ScalarField::zero();
+                for coefficient in coefficients.iter().rev() {
+                    value = value * *x + *coefficient;
+                }
+                value
+            }
+
+            fn evaluate_commitments(
+                commitments: &[Element],
+                x: &Scalar,
+            ) -> Element {
+                let mut value = SuiteGroup::identity();
+                for commitment in commitments.iter().rev() {
+                    value = value * *x + *commitment;
+                }
+                value
+            }
+        }
+
+        impl ProtocolDriver for ReshareDriver {
+            type Error = Error;
+            type Message =
+                RoundMessage<SignedPackage<ResharePackage>, $id>;
+            type Output = Option<$out>;
+
+            fn round_info(&self) -> Result<RoundInfo> {
+                let round_number = self.round_number;
+                let is_echo = false;
+                let can_finalize = match self.round_number {
+                    ROUND_2 => {
+                        self.packages.len() == self.dealers.len()
+                    }
+                    ROUND_3 => {
+                        self.echoes.len() == self.receivers.len() - 1
+                    }
+                    _ => false,
+                };
+                Ok(RoundInfo {
+                    round_number,
+                    can_finalize,
+                    is_echo,
+                })
+            }
+
+            fn proceed(&mut self) -> Result<Vec<Self::Message>> {
+                match self.round_number {
+                    // Round 1 is a p2p round, each old participant
+                    // sends the commitments to all the other parties
+                    // and a secret share to each new participant
+                    ROUND_1 => {
+                        let mut messages = Vec::with_capacity(
+                            self.parties.len() - 1,
+                        );
+
+                        if let (Some(old_id), Some(key_share)) =
+                            (self.old_id(), &self.key_share)
+                        {
+                            let secret =
+                                Self::to_scalar(Self::to_bytes(
+                                    key_share
+                                        .0
+                                        .signing_share()
+                                        .serialize(),
+                                ))?;
+
+                            let mut coefficients = vec![
+                                secret * self.lagrange(&old_id)?,
+                            ];
+                            for _ in 1..self.new_threshold {
+                                coefficients.push(
+                                    ScalarField::random(&mut OsRng),
+                                );
+                            }
+
+                            let commitments = coefficients
+                                .iter()
+                                .map(|c| SuiteGroup::generator() * *c)
+                                .collect::<Vec<_>>();
+                            let mut encoded =
+                                Vec::with_capacity(commitments.len());
+                            for commitment in &commitments {
+                                encoded.push(Self::element_bytes(
+                                    commitment,
+                                )?);
+                            }
+
+                            let round = NonZeroU16::new(
+                                self.round_number.into(),
+                            )
+                            .unwrap();
+
+                            for (index, (_, new_id)) in
+                                self.parties.iter().enumerate()
+                            {
+                                let share = match new_id {
+                                    Some(new_id) => {
+                                        let x =
+                                            Self::id_scalar(new_id)?;
+                                        Some(Self::evaluate(
+                                            &coefficients,
+                                            &x,
+                                        ))
+                                    }
+                                    None => None,
+                                };
+                                let encoded_share = share.map(|s| {
+                                    Self::to_bytes(
+                                        ScalarField::serialize(&s),
+                                    )
+                                });
+
+                                if index == self.self_index {
+                                    self.packages.insert(
+                                        old_id,
+                                        (commitments.clone(), share),
+                                    );
+                                    continue;
+                                }
+
+                                let receiver = NonZeroU16::new(
+                                    (index + 1) as u16,
+                                )
+                                .unwrap();
+
+                                let body = sign_package(
+                                    &self.signer,
+                                    &self.session_id,
+                                    round,
+                                    &old_id,
+                                    receiver,
+                                    ResharePackage::Round1 {
+                                        commitments: encoded.clone(),
+                                        share: encoded_share,
+                                    },
+                                )?;
+
+                                messages.push(RoundMessage {
+                                    round,
+                                    sender: old_id,
+                                    receiver,
+                                    body,
+                                });
+                            }
+                        }
+
+                        self.round_number =
+                            self.round_number.checked_add(1).unwrap();
+
+                        Ok(messages)
+                    }
+                    // Round 2 is a broadcast round, each new
+                    // participant echoes the commitments it
+                    // received to the other new participants
+                    ROUND_2 => {
+                        let new_id = self.new_id().ok_or(
+                            Error::InvalidRound(self.round_number),
+                        )?;
+                        if self.packages.len() != self.dealers.len() {
+                            return Err(Error::Round2TooEarly);
+                        }
+
+                        let echo = self.commitments_echo()?;
+                        let round =
+                            NonZeroU16::new(self.round_number.into())
+                                .unwrap();
+                        let sender = self.senders[self.self_index];
+
+                        let mut messages = Vec::with_capacity(
+                            self.receivers.len() - 1,
+                        );
+                        for (index, (_, id)) in
+                            self.parties.iter().enumerate()
+                        {
+                            if id.is_none() || id == &Some(new_id) {
+                                continue;
+                            }
+
+                            let receiver =
+                                NonZeroU16::new((index + 1) as u16)
+                                    .unwrap();
+
+                            let body = sign_package(
+                                &self.signer,
+                                &self.session_id,
+                                round,
+                                &sender,
+                                receiver,
+                                ResharePackage::Round2(echo.clone()),
+                            )?;
+
+                            messages.push(RoundMessage {
+                                round,
+                                sender,
+                                receiver,
+                                body,
+                            });
+                        }
+
+                        self.echo = Some(echo);
+                        self.round_number =
+                            self.round_number.checked_add(1).unwrap();
+
+                        Ok(messages)
+                    }
+                    _ => Err(Error::InvalidRound(self.round_number)),
+                }
+            }
+
+            fn handle_incoming(
+                &mut self,
+                message: Self::Message,
+            ) -> Result<()> {
+                let round_number = message.round.get() as u8;
+                let party_index = self
+                    .senders
+                    .iter()
+                    .position(|v| v == &message.sender)
+                    .ok_or(Error::SenderVerifier)?;
+                let sender = self.senders[party_index];
+                let verifier = self
+                    .verifiers
+                    .get(party_index)
+                    .ok_or(Error::SenderIdentifier(
+                        round_number,
+                        party_index,
+                    ))?;
+
+                let package = verify_package(
+                    verifier,
+                    &self.session_id,
+                    message.round,
+                    &message.sender,
+                    message.receiver,
+                    message.body,
+                )?;
+
+                let (old_id, new_id) = self.parties[party_index];
+                match (round_number, package) {
+                    (
+                        ROUND_1,
+                        ResharePackage::Round1 { commitments, share },
+                    ) => {
+                        if old_id != Some(sender) {
+                            return Err(Error::SenderVerifier);
+                        }
+                        if commitments.len()
+                            != self.new_threshold as usize
+                            || share.is_some()
+                                != self.new_id().is_some()
+                        {
+                            return Err(Error::RoundPayload(
+                                round_number,
+                            ));
+                        }
+
+                        let commitments = commitments
+                            .into_iter()
+                            .map(Self::to_element)
+                            .collect::<Result<Vec<_>>>()?;
+                        let share =
+                            share.map(Self::to_scalar).transpose()?;
+
+                        self.packages
+                            .insert(sender, (commitments, share));
+                        Ok(())
+                    }
+                    (ROUND_2, ResharePackage::Round2(echo)) => {
+                        if new_id.is_none()
+                            || self.new_id().is_none()
+                            || echo.len() != self.dealers.len()
+                        {
+                            return Err(Error::RoundPayload(
+                                round_number,
+                            ));
+                        }
+                        self.echoes.insert(party_index, echo);
+                        Ok(())
+                    }
+                    (ROUND_1, _) | (ROUND_2, _) => {
+                        Err(Error::RoundPayload(round_number))
+                    }
+                    _ => Err(Error::InvalidRound(round_number)),
+                }
+            }
+
+            fn try_finalize_round(
+                &mut self,
+            ) -> Result<Option<Self::Output>> {
+                match self.round_number {
+                    ROUND_2
+                        if self.packages.len()
+                            == self.dealers.len()
+                            && self.new_id().is_none() => {}
+                    ROUND_3
+                        if self.echoes.len()
+                            == self.receivers.len() - 1 =>
+                    {
+                        // Every new participant must have received
+                        // the same commitments
+                        let echo = self
+                            .echo
+                            .as_ref()
+                            .ok_or(Error::Round3TooEarly)?;
+                        for (index, received) in &self.echoes {
+                            if received != echo {
+                                return Err(Error::ReshareEcho(
+                                    (*index + 1) as u16,
+                                ));
+                            }
+                        }
+                    }
+                    _ => return Ok(None),
+                }
+
+                // Constant terms must sum to the group verifying key
+                let mut group_element = SuiteGroup::identity();
+                for (commitments, _) in self.packages.values() {
+                    group_element = group_element + commitments[0];
+                }
+                let group_key = VerifyingKey::deserialize(
+                    &Self::element_bytes(&group_element)?,
+                )?;
+                if group_key != self.verifying_key {
+                    return Err(Error::ReshareVerifyingKey);
+                }
+
+                // Verifying shares for the new participants
+                let mut verifying_shares = BTreeMap::new();
+                for id in &self.receivers {
+                    let x = Self::id_scalar(id)?;
+                    let mut element = SuiteGroup::identity();
+                    for (commitments, _) in self.packages.values() {
+                        element = element
+                            + Self::evaluate_commitments(
+                                commitments,
+                                &x,
+                            );
+                    }
+                    let verifying_share =
+                        VerifyingShare::deserialize(
+                            &Self::element_bytes(&element)?,
+                        )?;
+                    verifying_shares.insert(*id, verifying_share);
+                }
+
+                let public_key_package = PublicKeyPackage::new(
+                    verifying_shares.clone(),
+                    self.verifying_key,
+                );
+
+                let Some(new_id) = self.new_id() else {
+                    return Ok(Some(None));
+                };
+
+                // Verify each share against the commitments
+                let x = Self::id_scalar(&new_id)?;
+                let mut secret = ScalarField::zero();
+                for (commitments, share) in self.packages.values() {
+                    let share = share.ok_or(Error::ReshareShare)?;
+                    if SuiteGroup::generator() * share
+                        != Self::evaluate_commitments(commitments, &x)
+                    {
+                        return Err(Error::ReshareShare);
+                    }
+                    secret = secret + share;
+                }
+
+                let signing_share = SigningShare::deserialize(
+                    &Self::to_bytes(ScalarField::serialize(&secret)),
+                )?;
+                let verifying_share = *verifying_shares
+                    .get(&new_id)
+                    .ok_or(Error::ReshareShare)?;
+
+                let key_package = KeyPackage::new(
+                    new_id,
+                    signing_share,
+                    verifying_share,
+                    self.verifying_key,
+                    self.new_threshold,
+                );
+
+                Ok(Some(Some((key_package, public_key_package))))
+            }
+        }
+    };
+}
+
+pub(crate) use frost_reshare_impl;
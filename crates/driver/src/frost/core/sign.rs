@@ -12,20 +12,29 @@ macro_rules! frost_sign_impl {
         #[derive(Debug, Serialize, Deserialize)]
         pub enum SignPackage {
             Round1($r1pub),
-            Round2($r2pub),
+            /// Signature share and the index of the
+            /// preprocessed nonces used to create it.
+            Round2($r2pub, Option<u32>),
         }
 
         /// FROST signature driver.
+        ///
+        /// Round packages are signed by the participant signing
+        /// key and verified against the verifier for the sender.
         pub struct SignatureDriver {
+            session_id: SessionId,
             #[allow(dead_code)]
             party_number: NonZeroU16,
             identifiers: Vec<$id>,
+            signer: SigningKey,
+            verifiers: Vec<VerifyingKey>,
             id: Identifier,
             min_signers: u16,
             round_number: u8,
             key_share: KeyShare,
             message: Vec<u8>,
             nonces: Option<$r1priv>,
+            nonce_index: Option<u32>,
             commitments: BTreeMap<$id, $r1pub>,
             signing_package: Option<$r2priv>,
             signature_shares: BTreeMap<$id, $r2pub>,
@@ -33,9 +42,15 @@ macro_rules! frost_sign_impl {
 
         impl SignatureDriver {
             /// Create a driver.
+            ///
+            /// Verifiers must be in the same order as the
+            /// identifiers.
             pub fn new(
+                session_id: SessionId,
                 party_number: NonZeroU16,
                 identifiers: Vec<Identifier>,
+                signer: SigningKey,
+                verifiers: Vec<VerifyingKey>,
                 min_signers: u16,
                 key_share: KeyShare,
                 message: Vec<u8>,
@@ -46,25 +61,82 @@ macro_rules! frost_sign_impl {
                     .get(self_index)
                     .ok_or(Error::IndexIdentifier(party_index))?;
 
+                if verifiers.len() != identifiers.len() {
+                    return Err(Error::VerifiersLength(
+                        identifiers.len(),
+                        verifiers.len(),
+                    ));
+                }
+
                 Ok(Self {
+                    session_id,
                     party_number,
                     identifiers,
+                    signer,
+                    verifiers,
                     id,
                     min_signers,
                     round_number: ROUND_1,
                     key_share,
                     message,
                     nonces: None,
+                    nonce_index: None,
                     commitments: BTreeMap::new(),
                     signing_package: None,
                     signature_shares: BTreeMap::new(),
                 })
             }
+
+            /// Create a driver that signs with preprocessed nonces.
+            ///
+            /// The commitments were exchanged ahead of time so
+            /// signing completes after a single round.
+            ///
+            /// Every signer must use nonces at the same index
+            /// in the batch; signature shares created with
+            /// nonces at another index are rejected.
+            pub fn new_preprocessed(
+                session_id: SessionId,
+                party_number: NonZeroU16,
+                identifiers: Vec<Identifier>,
+                signer: SigningKey,
+                verifiers: Vec<VerifyingKey>,
+                min_signers: u16,
+                key_share: KeyShare,
+                message: Vec<u8>,
+                nonces: PreprocessedNonces,
+            ) -> Result<Self> {
+                let nonce_index = nonces.index();
+                let (nonces, mut commitments) = nonces.into_parts();
+                let mut driver = Self::new(
+                    session_id,
+                    party_number,
+                    identifiers,
+                    signer,
+                    verifiers,
+                    min_signers,
+                    key_share,
+                    message,
+                )?;
+
+                for id in &driver.identifiers {
+                    let commitment = commitments
+                        .remove(id)
+                        .ok_or(Error::PreprocessCommitments)?;
+                    driver.commitments.insert(*id, commitment);
+                }
+
+                driver.nonces = Some(nonces);
+                driver.nonce_index = Some(nonce_index);
+                driver.round_number = ROUND_2;
+                Ok(driver)
+            }
         }
 
         impl ProtocolDriver for SignatureDriver {
             type Error = Error;
-            type Message = RoundMessage<SignPackage, Identifier>;
+            type Message =
+                RoundMessage<SignedPackage<SignPackage>, Identifier>;
             type Output = Signature;
 
             fn round_info(&self) -> Result<RoundInfo> {
@@ -111,16 +183,26 @@ macro_rules! frost_sign_impl {
                             let receiver =
                                 NonZeroU16::new((index + 1) as u16)
                                     .unwrap();
-                            let message = RoundMessage {
-                                round: NonZeroU16::new(
-                                    self.round_number.into(),
-                                )
-                                .unwrap(),
-                                sender: self.id.clone(),
+                            let round = NonZeroU16::new(
+                                self.round_number.into(),
+                            )
+                            .unwrap();
+                            let body = sign_package(
+                                &self.signer,
+                                &self.session_id,
+                                round,
+                                &self.id,
                                 receiver,
-                                body: SignPackage::Round1(
+                                SignPackage::Round1(
                                     commitments.clone(),
                                 ),
+                            )?;
+
+                            let message = RoundMessage {
+                                round,
+                                sender: self.id.clone(),
+                                receiver,
+                                body,
                             };
 
                             messages.push(message);
@@ -166,16 +248,27 @@ macro_rules! frost_sign_impl {
                             let receiver =
                                 NonZeroU16::new((index + 1) as u16)
                                     .unwrap();
-                            let message = RoundMessage {
-                                round: NonZeroU16::new(
-                                    self.round_number.into(),
-                                )
-                                .unwrap(),
-                                sender: self.id.clone(),
+                            let round = NonZeroU16::new(
+                                self.round_number.into(),
+                            )
+                            .unwrap();
+                            let body = sign_package(
+                                &self.signer,
+                                &self.session_id,
+                                round,
+                                &self.id,
                                 receiver,
-                                body: SignPackage::Round2(
+                                SignPackage::Round2(
                                     signature_share.clone(),
+                                    self.nonce_index,
                                 ),
+                            )?;
+
+                            let message = RoundMessage {
+                                round,
+                                sender: self.id.clone(),
+                                receiver,
+                                body,
                             };
 
                             messages.push(message);
@@ -199,53 +292,55 @@ macro_rules! frost_sign_impl {
                 message: Self::Message,
             ) -> Result<()> {
                 let round_number = message.round.get() as u8;
-                match round_number {
-                    ROUND_1 => match message.body {
-                        SignPackage::Round1(commitments) => {
-                            let party_index = self
-                                .identifiers
-                                .iter()
-                                .position(|v| v == &message.sender)
-                                .ok_or(Error::SenderVerifier)?;
-                            if let Some(id) =
-                                self.identifiers.get(party_index)
-                            {
-                                self.commitments
-                                    .insert(id.clone(), commitments);
-                                Ok(())
-                            } else {
-                                Err(Error::SenderIdentifier(
-                                    round_number,
-                                    party_index,
-                                ))
-                            }
-                        }
-                        _ => Err(Error::RoundPayload(round_number)),
-                    },
-                    ROUND_2 => match message.body {
-                        SignPackage::Round2(signature_share) => {
-                            let party_index = self
-                                .identifiers
-                                .iter()
-                                .position(|v| v == &message.sender)
-                                .ok_or(Error::SenderVerifier)?;
-                            if let Some(id) =
-                                self.identifiers.get(party_index)
-                            {
-                                self.signature_shares.insert(
-                                    id.clone(),
-                                    signature_share,
-                                );
-                                Ok(())
-                            } else {
-                                Err(Error::SenderIdentifier(
-                                    round_number,
-                                    party_index,
-                                ))
-                            }
+                let party_index = self
+                    .identifiers
+                    .iter()
+                    .position(|v| v == &message.sender)
+                    .ok_or(Error::SenderVerifier)?;
+                let id = *self.identifiers.get(party_index).ok_or(
+                    Error::SenderIdentifier(
+                        round_number,
+                        party_index,
+                    ),
+                )?;
+                let verifier = self
+                    .verifiers
+                    .get(party_index)
+                    .ok_or(Error::SenderIdentifier(
+                        round_number,
+                        party_index,
+                    ))?;
+
+                let package = verify_package(
+                    verifier,
+                    &self.session_id,
+                    message.round,
+                    &message.sender,
+                    message.receiver,
+                    message.body,
+                )?;
+
+                match (round_number, package) {
+                    (ROUND_1, SignPackage::Round1(commitments)) => {
+                        self.commitments.insert(id, commitments);
+                        Ok(())
+                    }
+                    (
+                        ROUND_2,
+                        SignPackage::Round2(share, nonce_index),
+                    ) => {
+                        if nonce_index != self.nonce_index {
+                            return Err(Error::NonceIndexMismatch(
+                                self.nonce_index,
+                                nonce_index,
+                            ));
                         }
-                        _ => Err(Error::RoundPayload(round_number)),
-                    },
+                        self.signature_shares.insert(id, share);
+                        Ok(())
+                    }
+                    (ROUND_1, _) | (ROUND_2, _) => {
+                        Err(Error::RoundPayload(round_number))
+                    }
                     _ => Err(Error::InvalidRound(round_number)),
                 }
             }
@@ -262,11 +357,34 @@ macro_rules! frost_sign_impl {
                         .take()
                         .ok_or(Error::Round3TooEarly)?;
 
-                    let group_signature = aggregate(
+                    let group_signature = match aggregate(
                         &signing_package,
                         &self.signature_shares,
                         &self.key_share.1,
-                    )?;
+                    ) {
+                        Ok(signature) => signature,
+                        Err(e) => {
+                            let culprits = find_culprits(
+                                &self.key_share.1,
+                                &self.signature_shares,
+                                &signing_package,
+                                |id| {
+                                    self.identifiers
+                                        .iter()
+                                        .position(|v| v == id)
+                                        .map(|i| (i + 1) as u16)
+                                },
+                            );
+                            if culprits.is_empty() {
+                                return Err(e.into());
+                            }
+                            return Err(
+                                Error::InvalidSignatureShares(
+                                    culprits,
+                                ),
+                            );
+                        }
+                    };
 
                     Ok(Some(group_signature))
                 } else {
@@ -0,0 +1,84 @@
+//! Round packages signed by the participant signing key.
+use polysig_protocol::{PartyNumber, RoundNumber, SessionId};
+use serde::{Deserialize, Serialize};
+
+use crate::frost::Result;
+
+/// Round package signed by the participant signing key
+/// of the sender.
+///
+/// The signature covers the session identifier, round number,
+/// sender, receiver and package so a package cannot be replayed
+/// in another session, to another party or in another round.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SignedPackage<T> {
+    pub(crate) package: T,
+    pub(crate) signature: Vec<u8>,
+}
+
+/// Compute the bytes to sign for a round package.
+pub(crate) fn signing_bytes<T: Serialize, I: Serialize>(
+    session_id: &SessionId,
+    round: RoundNumber,
+    sender: &I,
+    receiver: PartyNumber,
+    package: &T,
+) -> Result<Vec<u8>> {
+    Ok(serde_json::to_vec(&(
+        session_id, round, sender, receiver, package,
+    ))
+    .map_err(polysig_protocol::Error::from)?)
+}
+
+/// Generate functions to sign and verify round packages.
+macro_rules! frost_signed_impl {
+    ($signer:ty, $verifier:ty, $signature:ty) => {
+        /// Sign a round package.
+        pub(crate) fn sign_package<T: Serialize, I: Serialize>(
+            signer: &$signer,
+            session_id: &SessionId,
+            round: RoundNumber,
+            sender: &I,
+            receiver: PartyNumber,
+            package: T,
+        ) -> Result<SignedPackage<T>> {
+            let bytes = signing_bytes(
+                session_id, round, sender, receiver, &package,
+            )?;
+            let signature: $signature = signer
+                .try_sign(&bytes)
+                .map_err(|_| Error::PackageSignature)?;
+            Ok(SignedPackage {
+                package,
+                signature: signature.to_bytes().to_vec(),
+            })
+        }
+
+        /// Verify a round package was signed by the sender.
+        pub(crate) fn verify_package<T: Serialize, I: Serialize>(
+            verifier: &$verifier,
+            session_id: &SessionId,
+            round: RoundNumber,
+            sender: &I,
+            receiver: PartyNumber,
+            signed: SignedPackage<T>,
+        ) -> Result<T> {
+            let bytes = signing_bytes(
+                session_id,
+                round,
+                sender,
+                receiver,
+                &signed.package,
+            )?;
+            let signature =
+                <$signature>::try_from(signed.signature.as_slice())
+                    .map_err(|_| Error::PackageSignature)?;
+            verifier
+                .verify(&bytes, &signature)
+                .map_err(|_| Error::PackageSignature)?;
+            Ok(signed.package)
+        }
+    };
+}
+
+pub(crate) use frost_signed_impl;
@@ -0,0 +1,22 @@
+//! Trusted dealer key import for FROST Ed25519.
+use frost_ed25519::{
+    keys::{split, IdentifierList, KeyPackage},
+    SigningKey as FrostSigningKey,
+};
+use rand::rngs::OsRng;
+
+use super::{Identifier, KeyShare, SigningKey, VerifyingKey};
+use crate::frost::{core::dealer::frost_dealer_impl, Error, Result};
+
+// FROST uses the expanded and clamped secret scalar
+// so that the verifying key is the same as the
+// Ed25519 public key.
+fn signing_key_bytes(signing_key: &SigningKey) -> Vec<u8> {
+    signing_key.to_scalar().to_bytes().to_vec()
+}
+
+fn verifying_key_bytes(verifying_key: &VerifyingKey) -> Vec<u8> {
+    verifying_key.as_bytes().to_vec()
+}
+
+frost_dealer_impl!();
@@ -0,0 +1,45 @@
+//! Non-hardened key derivation for FROST Ed25519.
+use frost_ed25519::{
+    keys::{
+        KeyPackage, PublicKeyPackage, SigningShare, VerifyingShare,
+    },
+    Ciphersuite, Ed25519Sha512, Field, Group, VerifyingKey,
+};
+use std::collections::BTreeMap;
+
+use crate::frost::{
+    core::derive::{derive_child, frost_derive_impl, ChainCode},
+    Error, Result,
+};
+
+use super::KeyShare;
+
+/// Tweak for a child index from the left half of the
+/// HMAC-SHA512 output.
+///
+/// Scalars are little-endian and a uniform 32 byte value is
+/// usually not less than the group order so only the first
+/// 28 bytes are used which keeps the tweak in range.
+fn child_tweak(bytes: [u8; 32]) -> [u8; 32] {
+    let mut tweak = [0u8; 32];
+    tweak[..28].copy_from_slice(&bytes[..28]);
+    tweak
+}
+
+frost_derive_impl!(
+    Ed25519Sha512,
+    child_tweak,
+    /// For each index `i` in the path the parent chain code
+    /// `c` and the 32 byte encoded parent verifying key `A`
+    /// give `I = HMAC-SHA512(c, A || ser32(i))` where `ser32`
+    /// is the big-endian index. The first 28 bytes of `I`
+    /// read as a little-endian integer are the tweak `t`, the
+    /// child verifying key is `A + t·B` and the last 32 bytes
+    /// of `I` are the child chain code.
+    ///
+    /// This scheme is not BIP32-Ed25519 which adds a prefix
+    /// byte, encodes the index little-endian, multiplies the
+    /// tweak by the cofactor and uses a separate HMAC for the
+    /// chain code, so child keys are not compatible with
+    /// wallets that implement BIP32-Ed25519.
+);
@@ -3,7 +3,7 @@ use frost_ed25519::{
     keys::dkg::{self, part1, part2, part3},
     Identifier,
 };
-use polysig_protocol::Parameters;
+use polysig_protocol::{Parameters, SessionId};
 use rand::rngs::OsRng;
 use serde::{Deserialize, Serialize};
 use std::{collections::BTreeMap, num::NonZeroU16};
@@ -13,10 +13,14 @@ use crate::{
     ProtocolDriver, RoundInfo, RoundMessage,
 };
 
-use super::KeyShare;
+use super::{
+    signed::{sign_package, verify_package},
+    KeyShare, SigningKey, VerifyingKey,
+};
 
 use crate::frost::{
-    core::dkg::frost_dkg_impl, ROUND_1, ROUND_2, ROUND_3,
+    core::dkg::frost_dkg_impl, SignedPackage, ROUND_1, ROUND_2,
+    ROUND_3,
 };
 
 frost_dkg_impl!(
@@ -3,10 +3,38 @@ pub use ed25519_dalek::{SigningKey, VerifyingKey};
 use frost_ed25519::keys::{KeyPackage, PublicKeyPackage};
 use polysig_protocol::pem;
 
+mod coordinator;
+mod culprits;
+mod dealer;
+mod derive;
 mod dkg;
+mod identifiers;
+mod preprocess;
+#[cfg(feature = "reconstruct")]
+mod reconstruct;
+mod refresh;
+mod repair;
+mod reshare;
 mod sign;
+mod signed;
 
+pub use coordinator::{
+    CoordinatorDriver, CoordinatorPackage, ParticipantDriver,
+};
+pub use dealer::{import_key, verify_key_share};
+pub use derive::{derive_key_share, derive_public_key_package};
 pub use dkg::DkgDriver;
+pub use identifiers::{
+    derive_identifiers, derive_signer_identifiers,
+};
+#[cfg(feature = "reconstruct")]
+pub use reconstruct::reconstruct_key;
+pub use preprocess::{
+    NonceStore, PreprocessDriver, PreprocessedNonces,
+};
+pub use refresh::RefreshDriver;
+pub use repair::RepairDriver;
+pub use reshare::ReshareDriver;
 pub use sign::SignatureDriver;
 
 /// Participant in the protocol.
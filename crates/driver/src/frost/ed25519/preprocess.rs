@@ -0,0 +1,30 @@
+//! Nonce preprocessing for FROST Ed25519.
+use frost_ed25519::{
+    round1::{self, SigningCommitments, SigningNonces},
+    Identifier,
+};
+use polysig_protocol::SessionId;
+use rand::rngs::OsRng;
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, num::NonZeroU16};
+
+use crate::{
+    frost::{Error, Result},
+    ProtocolDriver, RoundInfo, RoundMessage,
+};
+
+use super::{
+    signed::{sign_package, verify_package},
+    KeyShare, SigningKey, VerifyingKey,
+};
+use crate::frost::{
+    core::preprocess::frost_preprocess_impl, SignedPackage, ROUND_1,
+    ROUND_2,
+};
+
+frost_preprocess_impl!(
+    SigningCommitments,
+    SigningNonces,
+    Identifier,
+    round1
+);
@@ -0,0 +1,76 @@
+//! Signing key reconstruction for FROST Ed25519.
+use curve25519_dalek::Scalar;
+use frost_ed25519::{
+    keys::reconstruct, SigningKey as FrostSigningKey,
+    VerifyingKey as FrostVerifyingKey,
+};
+use sha2::{Digest, Sha512};
+
+use super::KeyShare;
+use crate::{
+    frost::{
+        core::reconstruct::frost_reconstruct_impl, Error, Result,
+    },
+    signers::eddsa::ExpandedSecretKey,
+};
+
+/// Domain separator for the hash prefix of a
+/// reconstructed key.
+const HASH_PREFIX_DOMAIN: &[u8] =
+    b"polysig-frost-ed25519-reconstruct";
+
+frost_reconstruct_impl!();
+
+/// Reconstruct the signing key from at least threshold
+/// key shares.
+///
+/// Ed25519 key shares are shares of the secret scalar
+/// rather than the seed used by
+/// [crate::signers::eddsa::EddsaSigner] so the seed
+/// cannot be recovered. The key is returned as an
+/// expanded secret key for
+/// [crate::signers::eddsa::ExpandedEddsaSigner] which
+/// creates standard Ed25519 signatures that verify
+/// against the group verifying key.
+///
+/// The hash prefix used to derive signature nonces is
+/// the first 32 bytes of SHA-512 over a domain separator
+/// and the scalar so signatures are deterministic for a
+/// key but differ from the signatures of any seed.
+///
+/// The reconstructed key defeats the purpose of threshold
+/// signing and should only be used for disaster recovery.
+pub fn reconstruct_key(
+    key_shares: &[KeyShare],
+) -> Result<ExpandedSecretKey> {
+    let signing_key = reconstruct_signing_key(key_shares)?;
+    let group_key = key_shares[0].1.verifying_key().serialize()?;
+
+    let scalar_bytes: [u8; 32] = signing_key
+        .serialize()
+        .as_slice()
+        .try_into()
+        .map_err(|_| Error::ReconstructVerifyingKey)?;
+    let scalar = Option::<Scalar>::from(
+        Scalar::from_canonical_bytes(scalar_bytes),
+    )
+    .ok_or(Error::ReconstructVerifyingKey)?;
+
+    let mut hasher = Sha512::new();
+    hasher.update(HASH_PREFIX_DOMAIN);
+    hasher.update(scalar_bytes);
+    let digest = hasher.finalize();
+    let mut hash_prefix = [0u8; 32];
+    hash_prefix.copy_from_slice(&digest[..32]);
+
+    let secret_key = ExpandedSecretKey {
+        scalar,
+        hash_prefix,
+    };
+    let verifying_key =
+        ed25519_dalek::VerifyingKey::from(&secret_key);
+    if verifying_key.as_bytes().as_slice() != group_key.as_slice() {
+        return Err(Error::ReconstructVerifyingKey);
+    }
+    Ok(secret_key)
+}
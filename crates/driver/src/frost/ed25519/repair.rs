@@ -0,0 +1,43 @@
+//! Key share repair for FROST Ed25519.
+use frost_ed25519::{
+    keys::{
+        repairable::{
+            repair_share_step_1, repair_share_step_2,
+            repair_share_step_3,
+        },
+        KeyPackage, PublicKeyPackage, SecretShare,
+        VerifiableSecretSharingCommitment, VerifyingShare,
+    },
+    Ciphersuite, Ed25519Sha512, Field, Group, Identifier,
+};
+use polysig_protocol::{Parameters, SessionId};
+use rand::rngs::OsRng;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    num::NonZeroU16,
+};
+
+use crate::{
+    frost::{Error, Result},
+    ProtocolDriver, RoundInfo, RoundMessage,
+};
+
+use super::{
+    signed::{sign_package, verify_package},
+    KeyShare, SigningKey, VerifyingKey,
+};
+
+use crate::frost::{
+    core::repair::frost_repair_impl, SignedPackage, ROUND_1, ROUND_2,
+    ROUND_3,
+};
+
+frost_repair_impl!(
+    Ed25519Sha512,
+    Identifier,
+    KeyShare,
+    repair_share_step_1,
+    repair_share_step_2,
+    repair_share_step_3
+);
@@ -5,6 +5,7 @@ use frost_ed25519::{
     round2::{self, SignatureShare},
     Identifier, Signature, SigningPackage,
 };
+use polysig_protocol::SessionId;
 use rand::rngs::OsRng;
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
@@ -15,9 +16,15 @@ use crate::{
     ProtocolDriver, RoundInfo, RoundMessage,
 };
 
-use super::KeyShare;
+use super::{
+    culprits::find_culprits,
+    preprocess::PreprocessedNonces,
+    signed::{sign_package, verify_package},
+    KeyShare, SigningKey, VerifyingKey,
+};
 use crate::frost::{
-    core::sign::frost_sign_impl, ROUND_1, ROUND_2, ROUND_3,
+    core::sign::frost_sign_impl, SignedPackage, ROUND_1, ROUND_2,
+    ROUND_3,
 };
 
 frost_sign_impl!(
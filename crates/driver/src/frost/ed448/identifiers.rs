@@ -0,0 +1,11 @@
+//! Identifiers derived from verifying keys for FROST Ed448.
+use super::{Identifier, KeyShare, VerifyingKey};
+use crate::frost::{
+    core::identifiers::frost_identifiers_impl, Error, Result,
+};
+
+fn verifying_key_bytes(verifying_key: &VerifyingKey) -> Vec<u8> {
+    verifying_key.as_bytes().to_vec()
+}
+
+frost_identifiers_impl!();
@@ -0,0 +1,56 @@
+//! Driver for the FROST Ed448 protocol.
+//!
+//! Participants authenticate round messages with Ed25519 keys.
+pub use ed25519_dalek::{SigningKey, VerifyingKey};
+use frost_ed448::keys::{KeyPackage, PublicKeyPackage};
+use polysig_protocol::pem;
+
+mod coordinator;
+mod culprits;
+mod dkg;
+mod identifiers;
+mod preprocess;
+#[cfg(feature = "reconstruct")]
+mod reconstruct;
+mod refresh;
+mod repair;
+mod reshare;
+mod sign;
+mod signed;
+
+pub use coordinator::{
+    CoordinatorDriver, CoordinatorPackage, ParticipantDriver,
+};
+pub use dkg::DkgDriver;
+pub use identifiers::{
+    derive_identifiers, derive_signer_identifiers,
+};
+#[cfg(feature = "reconstruct")]
+pub use reconstruct::reconstruct_key;
+pub use preprocess::{
+    NonceStore, PreprocessDriver, PreprocessedNonces,
+};
+pub use refresh::RefreshDriver;
+pub use repair::RepairDriver;
+pub use reshare::ReshareDriver;
+pub use sign::SignatureDriver;
+
+/// Participant in the protocol.
+pub type Participant = crate::Participant<SigningKey, VerifyingKey>;
+
+/// Options for each party.
+pub type PartyOptions = crate::PartyOptions<VerifyingKey>;
+
+/// Key share for this protocol.
+pub type KeyShare = (KeyPackage, PublicKeyPackage);
+
+/// Signature for this protocol.
+pub type Signature = frost_ed448::Signature;
+
+/// Identifier for this protocol.
+pub type Identifier = frost_ed448::Identifier;
+
+const TAG: &str = "FROST ED448 KEY SHARE";
+const PEM_VERSION: u16 = 1;
+
+super::core::key_share_pem!();
@@ -0,0 +1,27 @@
+//! Signing key reconstruction for FROST Ed448.
+use frost_ed448::{
+    keys::reconstruct, SigningKey as FrostSigningKey,
+    VerifyingKey as FrostVerifyingKey,
+};
+
+use super::KeyShare;
+use crate::frost::{
+    core::reconstruct::frost_reconstruct_impl, Error, Result,
+};
+
+frost_reconstruct_impl!();
+
+/// Reconstruct the signing key from at least threshold
+/// key shares.
+///
+/// The key is returned as a FROST signing key which
+/// can create single-party signatures that verify
+/// against the group verifying key.
+///
+/// The reconstructed key defeats the purpose of threshold
+/// signing and should only be used for disaster recovery.
+pub fn reconstruct_key(
+    key_shares: &[KeyShare],
+) -> Result<FrostSigningKey> {
+    reconstruct_signing_key(key_shares)
+}
@@ -0,0 +1,40 @@
+//! Signature generation for FROST Ed448.
+use frost_ed448::{
+    aggregate,
+    round1::{self, SigningCommitments, SigningNonces},
+    round2::{self, SignatureShare},
+    Identifier, Signature, SigningPackage,
+};
+use polysig_protocol::SessionId;
+use rand::rngs::OsRng;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::num::NonZeroU16;
+
+use crate::{
+    frost::{Error, Result},
+    ProtocolDriver, RoundInfo, RoundMessage,
+};
+
+use super::{
+    culprits::find_culprits,
+    preprocess::PreprocessedNonces,
+    signed::{sign_package, verify_package},
+    KeyShare, SigningKey, VerifyingKey,
+};
+use crate::frost::{
+    core::sign::frost_sign_impl, SignedPackage, ROUND_1, ROUND_2,
+    ROUND_3,
+};
+
+frost_sign_impl!(
+    SigningCommitments,
+    SigningNonces,
+    SignatureShare,
+    SigningPackage,
+    Identifier,
+    Signature,
+    round1,
+    round2,
+    aggregate
+);
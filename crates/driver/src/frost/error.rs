@@ -1,5 +1,25 @@
 use thiserror::Error;
 
+/// Signer that sent an invalid signature share.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Culprit {
+    /// Party number of the signer in the session.
+    ///
+    /// Zero when the identifier is not one of the
+    /// signing identifiers.
+    pub party_number: u16,
+    /// Serialized FROST identifier of the signer.
+    pub identifier: Vec<u8>,
+}
+
+fn party_numbers(culprits: &[Culprit]) -> String {
+    culprits
+        .iter()
+        .map(|c| c.party_number.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 /// Errors generated by the protocol.
 #[derive(Debug, Error)]
 pub enum Error {
@@ -23,6 +43,16 @@ pub enum Error {
     #[error("could not locate a verifier for the message sender")]
     SenderVerifier,
 
+    /// Error generated when the number of verifiers does not
+    /// match the number of identifiers.
+    #[error("expected {0} verifiers but got {1}")]
+    VerifiersLength(usize, usize),
+
+    /// Error generated when a round package signature could
+    /// not be created or is invalid for the sender.
+    #[error("invalid signature for round package")]
+    PackageSignature,
+
     /// Error generated attempting to proceed to round 2 too early.
     #[error("attempt to proceed to round 2 without round 1 data")]
     Round2TooEarly,
@@ -31,6 +61,186 @@ pub enum Error {
     #[error("attempt to proceed to round 3 without round 2 data")]
     Round3TooEarly,
 
+    /// Error generated when signature aggregation fails and
+    /// the signature shares of some signers are invalid.
+    ///
+    /// Signing should be restarted without the culprits.
+    #[error(
+        "invalid signature shares from parties {}",
+        party_numbers(.0)
+    )]
+    InvalidSignatureShares(Vec<Culprit>),
+
+    /// Error generated when preprocessing is requested for
+    /// an empty batch of nonces.
+    #[error("nonce preprocessing batch must not be empty")]
+    PreprocessEmptyBatch,
+
+    /// Error generated when the number of commitments sent
+    /// during preprocessing does not match the batch size.
+    #[error("expected batch of {0} commitments but got {1}")]
+    PreprocessBatchSize(u32, usize),
+
+    /// Error generated when preprocessed nonces do not have
+    /// commitments for all the signers.
+    #[error("preprocessed commitments are missing for a signer")]
+    PreprocessCommitments,
+
+    /// Error generated when preprocessed nonces are not in
+    /// the store, either they were never created or have
+    /// already been used.
+    #[error("nonces at index {0} are not available")]
+    NonceUnavailable(u32),
+
+    /// Error generated when a signature share was created
+    /// with preprocessed nonces at a different index in the
+    /// batch, or without preprocessed nonces.
+    #[error("expected signature share for nonce index {0:?} but got {1:?}")]
+    NonceIndexMismatch(Option<u32>, Option<u32>),
+
+    /// Error generated when coordinated signing does not have
+    /// exactly one coordinator or the coordinator driver is
+    /// created for a party that is not the coordinator.
+    #[error("coordinated signing requires exactly one coordinator")]
+    CoordinatorRole,
+
+    /// Error generated when a participant in coordinated
+    /// signing is not a signer for the key share.
+    #[error("party is not a signing participant for the key share")]
+    ParticipantRole,
+
+    /// Error generated when the signing package from the
+    /// coordinator is for a different message.
+    #[error("signing package message does not match")]
+    SigningPackageMessage,
+
+    /// Error generated when the signing package from the
+    /// coordinator does not include our commitments or
+    /// includes commitments from parties that are not signers.
+    #[error("signing package has unexpected commitments")]
+    SigningPackageCommitments,
+
+    /// Error generated when a refreshed public key package
+    /// does not match the existing group verifying key.
+    #[error(
+        "refreshed public key package has a different verifying key"
+    )]
+    RefreshVerifyingKey,
+
+    /// Error generated when the participant to repair
+    /// is not in the list of identifiers.
+    #[error("participant to repair is not a protocol identifier")]
+    RepairParticipant,
+
+    /// Error generated when a helper does not provide a key share
+    /// or the participant to repair provides a key share.
+    #[error("helpers must provide a key share and the participant to repair must not")]
+    RepairKeyShare,
+
+    /// Error generated when there are not enough helpers
+    /// to repair a key share.
+    #[error("repair requires at least {0} helpers but got {1}")]
+    RepairHelpers(u16, usize),
+
+    /// Error generated when a scalar sent to repair a key share
+    /// could not be decoded.
+    #[error("invalid scalar for key share repair")]
+    RepairScalar,
+
+    /// Error generated when the public key packages sent to repair
+    /// a key share do not match or do not include the participant.
+    #[error("public key package for key share repair is invalid")]
+    RepairPublicKeyPackage,
+
+    /// Error generated when a repaired signing share does not match
+    /// the verifying share in the public key package.
+    #[error("repaired signing share does not match verifying share")]
+    RepairVerifyingShare,
+
+    /// Error generated when an old participant does not provide
+    /// a key share or a new participant provides a key share.
+    #[error("old participants must provide a matching key share and other participants must not")]
+    ReshareKeyShare,
+
+    /// Error generated when there are not enough old participants
+    /// to reshare a key.
+    #[error("resharing requires at least {0} old participants but got {1}")]
+    ReshareDealers(u16, usize),
+
+    /// Error generated when the new threshold is invalid for
+    /// the number of new participants.
+    #[error("new threshold {0} is invalid for {1} new participants")]
+    ReshareThreshold(u16, usize),
+
+    /// Error generated when a scalar or group element sent
+    /// during resharing could not be encoded or decoded.
+    #[error("invalid scalar or element encoding for resharing")]
+    ReshareEncoding,
+
+    /// Error generated when a party in a resharing session has
+    /// neither an old nor a new identifier or the identifier it
+    /// sends messages with is used by another party.
+    #[error("resharing parties need a unique old or new identifier")]
+    ReshareParties,
+
+    /// Error generated when the commitments echoed by a new
+    /// participant do not match the commitments received from
+    /// the old participants.
+    #[error("commitments echoed by party {0} do not match")]
+    ReshareEcho(u16),
+
+    /// Error generated when a secret share from an old participant
+    /// does not match the commitments.
+    #[error("secret share does not match the commitments")]
+    ReshareShare,
+
+    /// Error generated when the reshared key does not match
+    /// the group verifying key.
+    #[error("reshared key does not match the group verifying key")]
+    ReshareVerifyingKey,
+
+    /// Error generated when an identifier derived from a
+    /// verifying key does not belong to the key share.
+    #[error("derived identifier is not a member of the key share")]
+    DerivedIdentifier,
+
+    /// Error generated when a key share created by a trusted
+    /// dealer does not match the verifying key for the
+    /// imported signing key.
+    #[error("key share does not match the imported verifying key")]
+    DealerVerifyingKey,
+
+    /// Error generated when there are not enough key shares
+    /// to reconstruct a signing key.
+    #[error(
+        "reconstruction requires at least {0} key shares but got {1}"
+    )]
+    ReconstructThreshold(u16, usize),
+
+    /// Error generated when a reconstructed signing key does not
+    /// match the group verifying key.
+    #[error(
+        "reconstructed key does not match the group verifying key"
+    )]
+    ReconstructVerifyingKey,
+
+    /// Error generated when a derivation path has a hardened
+    /// index which requires the secret key.
+    #[error(
+        "hardened index {0} is not supported for key derivation"
+    )]
+    HardenedDerivation(u32),
+
+    /// Error generated when a child index yields an invalid
+    /// key, derivation should use the next index.
+    #[error("child index {0} yields an invalid key")]
+    InvalidDerivation(u32),
+
+    /// Error generated when a scalar or group element could
+    /// not be encoded or decoded for key derivation.
+    #[error("invalid scalar or element encoding for key derivation")]
+    DeriveEncoding,
+
     /// Protocol library errors.
     #[error(transparent)]
     Protocol(#[from] polysig_protocol::Error),
@@ -44,6 +254,26 @@ pub enum Error {
     #[cfg(feature = "frost-secp256k1-tr")]
     #[error(transparent)]
     FrostSecp256k1Taproot(#[from] frost_secp256k1_tr::Error),
+
+    /// FROST library error.
+    #[cfg(feature = "frost-ristretto255")]
+    #[error(transparent)]
+    FrostRistretto255(#[from] frost_ristretto255::Error),
+
+    /// FROST library error.
+    #[cfg(feature = "frost-p256")]
+    #[error(transparent)]
+    FrostP256(#[from] frost_p256::Error),
+
+    /// FROST library error.
+    #[cfg(feature = "frost-secp256k1")]
+    #[error(transparent)]
+    FrostSecp256k1(#[from] frost_secp256k1::Error),
+
+    /// FROST library error.
+    #[cfg(feature = "frost-ed448")]
+    #[error(transparent)]
+    FrostEd448(#[from] frost_ed448::Error),
 }
 
 #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
@@ -10,7 +10,25 @@ pub mod ed25519;
 #[cfg(feature = "frost-secp256k1-tr")]
 pub mod secp256k1_tr;
 
-pub use error::Error;
+#[cfg(feature = "frost-ristretto255")]
+pub mod ristretto255;
+
+#[cfg(feature = "frost-p256")]
+pub mod p256;
+
+#[cfg(feature = "frost-secp256k1")]
+pub mod secp256k1;
+
+#[cfg(feature = "frost-ed448")]
+pub mod ed448;
+
+pub use error::{Culprit, Error};
+
+#[cfg(feature = "frost")]
+pub use self::core::{coordinator::Role, signed::SignedPackage};
+
+#[cfg(feature = "derive")]
+pub use self::core::derive::ChainCode;
 
 /// Result type for the FROST protocol.
 pub type Result<T> = std::result::Result<T, Error>;
@@ -0,0 +1,38 @@
+//! Coordinated signature generation for FROST.
+use frost_p256::{
+    aggregate,
+    keys::PublicKeyPackage,
+    round1::{self, SigningCommitments, SigningNonces},
+    round2::{self, SignatureShare},
+    Identifier, Signature, SigningPackage,
+};
+use polysig_protocol::SessionId;
+use rand::rngs::OsRng;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::num::NonZeroU16;
+
+use crate::{
+    frost::{Error, Result, Role},
+    ProtocolDriver, RoundInfo, RoundMessage,
+};
+
+use super::{
+    culprits::find_culprits,
+    signed::{sign_package, verify_package},
+    KeyShare, SigningKey, VerifyingKey,
+};
+use crate::frost::{
+    core::coordinator::frost_coordinator_impl, SignedPackage,
+    ROUND_1, ROUND_2, ROUND_3,
+};
+
+frost_coordinator_impl!(
+    SigningCommitments,
+    SigningNonces,
+    SignatureShare,
+    SigningPackage,
+    Identifier,
+    Signature,
+    PublicKeyPackage
+);
@@ -0,0 +1,15 @@
+//! Signature share verification for FROST P-256.
+use frost_p256::{
+    keys::PublicKeyPackage, round2::SignatureShare,
+    verify_signature_share, Identifier, SigningPackage,
+};
+use std::collections::BTreeMap;
+
+use crate::frost::{core::culprits::frost_culprits_impl, Culprit};
+
+frost_culprits_impl!(
+    SignatureShare,
+    SigningPackage,
+    Identifier,
+    PublicKeyPackage
+);
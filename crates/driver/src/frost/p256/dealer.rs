@@ -0,0 +1,20 @@
+//! Trusted dealer key import for FROST P-256.
+use frost_p256::{
+    keys::{split, IdentifierList, KeyPackage},
+    SigningKey as FrostSigningKey,
+};
+use rand::rngs::OsRng;
+
+use super::{Identifier, KeyShare, SigningKey, VerifyingKey};
+use crate::frost::{core::dealer::frost_dealer_impl, Error, Result};
+
+fn signing_key_bytes(signing_key: &SigningKey) -> Vec<u8> {
+    signing_key.to_bytes().to_vec()
+}
+
+// FROST serializes the verifying key as a compressed point.
+fn verifying_key_bytes(verifying_key: &VerifyingKey) -> Vec<u8> {
+    verifying_key.to_encoded_point(true).as_bytes().to_vec()
+}
+
+frost_dealer_impl!();
@@ -0,0 +1,56 @@
+//! Driver for the FROST P-256 protocol.
+use frost_p256::keys::{KeyPackage, PublicKeyPackage};
+pub use p256::ecdsa::{SigningKey, VerifyingKey};
+use polysig_protocol::pem;
+
+mod coordinator;
+mod culprits;
+mod dealer;
+mod dkg;
+mod identifiers;
+mod preprocess;
+#[cfg(feature = "reconstruct")]
+mod reconstruct;
+mod refresh;
+mod repair;
+mod reshare;
+mod sign;
+mod signed;
+
+pub use coordinator::{
+    CoordinatorDriver, CoordinatorPackage, ParticipantDriver,
+};
+pub use dealer::{import_key, verify_key_share};
+pub use dkg::DkgDriver;
+pub use identifiers::{
+    derive_identifiers, derive_signer_identifiers,
+};
+#[cfg(feature = "reconstruct")]
+pub use reconstruct::reconstruct_key;
+pub use preprocess::{
+    NonceStore, PreprocessDriver, PreprocessedNonces,
+};
+pub use refresh::RefreshDriver;
+pub use repair::RepairDriver;
+pub use reshare::ReshareDriver;
+pub use sign::SignatureDriver;
+
+/// Participant in the protocol.
+pub type Participant = crate::Participant<SigningKey, VerifyingKey>;
+
+/// Options for each party.
+pub type PartyOptions = crate::PartyOptions<VerifyingKey>;
+
+/// Key share for this protocol.
+pub type KeyShare = (KeyPackage, PublicKeyPackage);
+
+/// Signature for this protocol.
+pub type Signature = frost_p256::Signature;
+
+/// Identifier for this protocol.
+pub type Identifier = frost_p256::Identifier;
+
+const TAG: &str = "FROST P256 KEY SHARE";
+const PEM_VERSION: u16 = 1;
+
+super::core::key_share_pem!();
@@ -0,0 +1,37 @@
+//! Key share refresh for FROST Ristretto255.
+use frost_ristretto255::{
+    keys::{
+        refresh::{compute_refreshing_shares, refresh_share},
+        PublicKeyPackage, SecretShare,
+    },
+    Identifier, Ristretto255Sha512,
+};
+use polysig_protocol::{Parameters, SessionId};
+use rand::rngs::OsRng;
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeSet, num::NonZeroU16};
+
+use crate::{
+    frost::{Error, Result},
+    ProtocolDriver, RoundInfo, RoundMessage,
+};
+
+use super::{
+    signed::{sign_package, verify_package},
+    KeyShare, SigningKey, VerifyingKey,
+};
+
+use crate::frost::{
+    core::refresh::frost_refresh_impl, SignedPackage, ROUND_1,
+    ROUND_2,
+};
+
+frost_refresh_impl!(
+    Ristretto255Sha512,
+    SecretShare,
+    PublicKeyPackage,
+    Identifier,
+    KeyShare,
+    compute_refreshing_shares,
+    refresh_share
+);
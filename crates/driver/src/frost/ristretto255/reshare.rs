@@ -0,0 +1,29 @@
+//! Key resharing for FROST Ristretto255.
+use frost_ristretto255::{
+    keys::{
+        KeyPackage, PublicKeyPackage, SigningShare, VerifyingShare,
+    },
+    Ciphersuite, Field, Group, Identifier, Ristretto255Sha512,
+    VerifyingKey,
+};
+use polysig_protocol::SessionId;
+use rand::rngs::OsRng;
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, num::NonZeroU16};
+
+use crate::{
+    frost::{Error, Result},
+    ProtocolDriver, RoundInfo, RoundMessage,
+};
+
+use super::{
+    signed::{sign_package, verify_package},
+    KeyShare, SigningKey, VerifyingKey as PartyVerifyingKey,
+};
+
+use crate::frost::{
+    core::reshare::frost_reshare_impl, SignedPackage, ROUND_1,
+    ROUND_2, ROUND_3,
+};
+
+frost_reshare_impl!(Ristretto255Sha512, Identifier, KeyShare);
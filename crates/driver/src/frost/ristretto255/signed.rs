@@ -0,0 +1,12 @@
+//! Signed round packages for FROST Ristretto255.
+use ed25519_dalek::{Signature, Signer, Verifier};
+use polysig_protocol::{PartyNumber, RoundNumber, SessionId};
+use serde::Serialize;
+
+use super::{SigningKey, VerifyingKey};
+use crate::frost::{
+    core::signed::{frost_signed_impl, signing_bytes, SignedPackage},
+    Error, Result,
+};
+
+frost_signed_impl!(SigningKey, VerifyingKey, Signature);
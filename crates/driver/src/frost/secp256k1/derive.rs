@@ -0,0 +1,33 @@
+//! Non-hardened key derivation for FROST Secp256k1.
+use frost_secp256k1::{
+    keys::{
+        KeyPackage, PublicKeyPackage, SigningShare, VerifyingShare,
+    },
+    Ciphersuite, Field, Group, Secp256K1Sha256, VerifyingKey,
+};
+use std::collections::BTreeMap;
+
+use crate::frost::{
+    core::derive::{derive_child, frost_derive_impl, ChainCode},
+    Error, Result,
+};
+
+use super::KeyShare;
+
+/// Tweak for a child index from the left half of the
+/// HMAC-SHA512 output.
+///
+/// Follows BIP32 which reads the tweak as a big-endian
+/// integer and rejects values that are not less than the
+/// group order.
+fn child_tweak(bytes: [u8; 32]) -> [u8; 32] {
+    bytes
+}
+
+frost_derive_impl!(
+    Secp256K1Sha256,
+    child_tweak,
+    /// Child verifying keys and chain codes are the same as
+    /// BIP32 public derivation (`CKDpub`) from the group
+    /// verifying key and the chain code.
+);
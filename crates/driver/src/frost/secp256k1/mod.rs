@@ -0,0 +1,58 @@
+//! Driver for the FROST Secp256k1 protocol.
+use frost_secp256k1::keys::{KeyPackage, PublicKeyPackage};
+pub use k256::ecdsa::{SigningKey, VerifyingKey};
+use polysig_protocol::pem;
+
+mod coordinator;
+mod culprits;
+mod dealer;
+mod derive;
+mod dkg;
+mod identifiers;
+mod preprocess;
+#[cfg(feature = "reconstruct")]
+mod reconstruct;
+mod refresh;
+mod repair;
+mod reshare;
+mod sign;
+mod signed;
+
+pub use coordinator::{
+    CoordinatorDriver, CoordinatorPackage, ParticipantDriver,
+};
+pub use dealer::{import_key, verify_key_share};
+pub use derive::{derive_key_share, derive_public_key_package};
+pub use dkg::DkgDriver;
+pub use identifiers::{
+    derive_identifiers, derive_signer_identifiers,
+};
+#[cfg(feature = "reconstruct")]
+pub use reconstruct::reconstruct_key;
+pub use preprocess::{
+    NonceStore, PreprocessDriver, PreprocessedNonces,
+};
+pub use refresh::RefreshDriver;
+pub use repair::RepairDriver;
+pub use reshare::ReshareDriver;
+pub use sign::SignatureDriver;
+
+/// Participant in the protocol.
+pub type Participant = crate::Participant<SigningKey, VerifyingKey>;
+
+/// Options for each party.
+pub type PartyOptions = crate::PartyOptions<VerifyingKey>;
+
+/// Key share for this protocol.
+pub type KeyShare = (KeyPackage, PublicKeyPackage);
+
+/// Signature for this protocol.
+pub type Signature = frost_secp256k1::Signature;
+
+/// Identifier for this protocol.
+pub type Identifier = frost_secp256k1::Identifier;
+
+const TAG: &str = "FROST SECP256K1 KEY SHARE";
+const PEM_VERSION: u16 = 1;
+
+super::core::key_share_pem!();
@@ -0,0 +1,41 @@
+//! Signing key reconstruction for FROST Secp256k1.
+use frost_secp256k1::{
+    keys::reconstruct, SigningKey as FrostSigningKey,
+    VerifyingKey as FrostVerifyingKey,
+};
+use k256::elliptic_curve::sec1::ToEncodedPoint;
+
+use super::{KeyShare, SigningKey};
+use crate::frost::{
+    core::reconstruct::frost_reconstruct_impl, Error, Result,
+};
+
+frost_reconstruct_impl!();
+
+/// Reconstruct the signing key from at least threshold
+/// key shares.
+///
+/// The signing key can be used with
+/// [crate::signers::ecdsa::EcdsaSigner] and the verifying
+/// key matches the group verifying key.
+///
+/// The reconstructed key defeats the purpose of threshold
+/// signing and should only be used for disaster recovery.
+pub fn reconstruct_key(
+    key_shares: &[KeyShare],
+) -> Result<SigningKey> {
+    let signing_key = reconstruct_signing_key(key_shares)?;
+    let group_key = key_shares[0].1.verifying_key().serialize()?;
+    let signing_key =
+        SigningKey::from_slice(&signing_key.serialize())
+            .map_err(|_| Error::ReconstructVerifyingKey)?;
+    if signing_key
+        .verifying_key()
+        .to_encoded_point(true)
+        .as_bytes()
+        != group_key.as_slice()
+    {
+        return Err(Error::ReconstructVerifyingKey);
+    }
+    Ok(signing_key)
+}
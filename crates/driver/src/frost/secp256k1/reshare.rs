@@ -0,0 +1,29 @@
+//! Key resharing for FROST Secp256k1.
+use frost_secp256k1::{
+    keys::{
+        KeyPackage, PublicKeyPackage, SigningShare, VerifyingShare,
+    },
+    Ciphersuite, Field, Group, Identifier, Secp256K1Sha256,
+    VerifyingKey,
+};
+use polysig_protocol::SessionId;
+use rand::rngs::OsRng;
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, num::NonZeroU16};
+
+use crate::{
+    frost::{Error, Result},
+    ProtocolDriver, RoundInfo, RoundMessage,
+};
+
+use super::{
+    signed::{sign_package, verify_package},
+    KeyShare, SigningKey, VerifyingKey as PartyVerifyingKey,
+};
+
+use crate::frost::{
+    core::reshare::frost_reshare_impl, SignedPackage, ROUND_1,
+    ROUND_2, ROUND_3,
+};
+
+frost_reshare_impl!(Secp256K1Sha256, Identifier, KeyShare);
@@ -0,0 +1,25 @@
+//! Trusted dealer key import for FROST Secp256k1 Taproot.
+use frost_secp256k1_tr::{
+    keys::{split, IdentifierList, KeyPackage},
+    SigningKey as FrostSigningKey,
+};
+use rand::rngs::OsRng;
+
+use super::{Identifier, KeyShare, SigningKey, VerifyingKey};
+use crate::frost::{core::dealer::frost_dealer_impl, Error, Result};
+
+// BIP-340 signing keys are normalized so the public
+// key has an even Y coordinate.
+fn signing_key_bytes(signing_key: &SigningKey) -> Vec<u8> {
+    signing_key.to_bytes().to_vec()
+}
+
+// FROST serializes the verifying key as a compressed
+// point whereas BIP-340 uses the X coordinate only.
+fn verifying_key_bytes(verifying_key: &VerifyingKey) -> Vec<u8> {
+    let mut bytes = vec![0x02];
+    bytes.extend_from_slice(&verifying_key.to_bytes());
+    bytes
+}
+
+frost_dealer_impl!();
@@ -3,11 +3,43 @@ use frost_secp256k1_tr::keys::{KeyPackage, PublicKeyPackage};
 pub use k256::schnorr::{SigningKey, VerifyingKey};
 use polysig_protocol::pem;
 
+mod coordinator;
+mod culprits;
+mod dealer;
+mod derive;
 mod dkg;
+mod identifiers;
+mod preprocess;
+#[cfg(feature = "reconstruct")]
+mod reconstruct;
+mod refresh;
+mod repair;
+mod reshare;
 mod sign;
+mod signed;
+mod taproot;
 
+pub use coordinator::{
+    CoordinatorDriver, CoordinatorPackage, ParticipantDriver,
+};
+pub use dealer::{import_key, verify_key_share};
+pub use derive::{derive_key_share, derive_public_key_package};
 pub use dkg::DkgDriver;
+pub use identifiers::{
+    derive_identifiers, derive_signer_identifiers,
+};
+#[cfg(feature = "reconstruct")]
+pub use reconstruct::reconstruct_key;
+pub use preprocess::{
+    NonceStore, PreprocessDriver, PreprocessedNonces,
+};
+pub use refresh::RefreshDriver;
+pub use repair::RepairDriver;
+pub use reshare::ReshareDriver;
 pub use sign::SignatureDriver;
+pub use taproot::{
+    tweak_key_share, tweaked_output_key, TaprootTweak,
+};
 
 /// Participant in the protocol.
 pub type Participant = crate::Participant<SigningKey, VerifyingKey>;
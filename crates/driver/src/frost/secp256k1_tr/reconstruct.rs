@@ -0,0 +1,38 @@
+//! Signing key reconstruction for FROST Secp256k1 Taproot.
+use frost_secp256k1_tr::{
+    keys::reconstruct, SigningKey as FrostSigningKey,
+    VerifyingKey as FrostVerifyingKey,
+};
+
+use super::{KeyShare, SigningKey};
+use crate::frost::{
+    core::reconstruct::frost_reconstruct_impl, Error, Result,
+};
+
+frost_reconstruct_impl!();
+
+/// Reconstruct the signing key from at least threshold
+/// key shares.
+///
+/// The signing key can be used with
+/// [crate::signers::schnorr::SchnorrSigner] and is
+/// normalized for BIP-340 so the X coordinate of the
+/// verifying key matches the group verifying key.
+///
+/// The reconstructed key defeats the purpose of threshold
+/// signing and should only be used for disaster recovery.
+pub fn reconstruct_key(
+    key_shares: &[KeyShare],
+) -> Result<SigningKey> {
+    let signing_key = reconstruct_signing_key(key_shares)?;
+    let group_key = key_shares[0].1.verifying_key().serialize()?;
+    let signing_key =
+        SigningKey::from_bytes(&signing_key.serialize())
+            .map_err(|_| Error::ReconstructVerifyingKey)?;
+    if signing_key.verifying_key().to_bytes().as_slice()
+        != &group_key[1..]
+    {
+        return Err(Error::ReconstructVerifyingKey);
+    }
+    Ok(signing_key)
+}
@@ -5,6 +5,7 @@ use frost_secp256k1_tr::{
     round2::{self, SignatureShare},
     Identifier, Signature, SigningPackage,
 };
+use polysig_protocol::SessionId;
 use rand::rngs::OsRng;
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
@@ -15,9 +16,16 @@ use crate::{
     ProtocolDriver, RoundInfo, RoundMessage,
 };
 
-use super::KeyShare;
+use super::{
+    culprits::find_culprits,
+    preprocess::PreprocessedNonces,
+    signed::{sign_package, verify_package},
+    taproot::{tweak_key_share, TaprootTweak},
+    KeyShare, SigningKey, VerifyingKey,
+};
 use crate::frost::{
-    core::sign::frost_sign_impl, ROUND_1, ROUND_2, ROUND_3,
+    core::sign::frost_sign_impl, SignedPackage, ROUND_1, ROUND_2,
+    ROUND_3,
 };
 
 frost_sign_impl!(
@@ -31,3 +39,33 @@ frost_sign_impl!(
     round2,
     aggregate
 );
+
+impl SignatureDriver {
+    /// Create a driver that signs for a Taproot output key.
+    ///
+    /// The key share is tweaked before signing so the
+    /// signature verifies against the output key.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_tweaked(
+        session_id: SessionId,
+        party_number: NonZeroU16,
+        identifiers: Vec<Identifier>,
+        signer: SigningKey,
+        verifiers: Vec<VerifyingKey>,
+        min_signers: u16,
+        key_share: KeyShare,
+        message: Vec<u8>,
+        tweak: TaprootTweak,
+    ) -> Result<Self> {
+        Self::new(
+            session_id,
+            party_number,
+            identifiers,
+            signer,
+            verifiers,
+            min_signers,
+            tweak_key_share(key_share, &tweak),
+            message,
+        )
+    }
+}
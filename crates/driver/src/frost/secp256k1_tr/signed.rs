@@ -0,0 +1,15 @@
+//! Signed round packages for FROST Secp256k1 Taproot.
+use k256::schnorr::{
+    signature::{Signer, Verifier},
+    Signature,
+};
+use polysig_protocol::{PartyNumber, RoundNumber, SessionId};
+use serde::Serialize;
+
+use super::{SigningKey, VerifyingKey};
+use crate::frost::{
+    core::signed::{frost_signed_impl, signing_bytes, SignedPackage},
+    Error, Result,
+};
+
+frost_signed_impl!(SigningKey, VerifyingKey, Signature);
@@ -0,0 +1,83 @@
+//! BIP-341 Taproot tweak for FROST Secp256k1 Taproot.
+use frost_secp256k1_tr::keys::{PublicKeyPackage, Tweak};
+use serde::{Deserialize, Serialize};
+
+use super::KeyShare;
+use crate::frost::Result;
+
+/// Taproot tweak applied to a key share before signing.
+///
+/// Signing without a tweak produces a signature for the
+/// internal key which is what a script path spend needs
+/// when a leaf script commits to the group key.
+///
+/// An explicit tweak scalar is deliberately not supported.
+/// BIP-341 derives the tweak from the internal key and the
+/// merkle root so every key path spendable output is covered
+/// by these variants, and the `Tweak` implementation for
+/// FROST Secp256k1 Taproot only accepts a merkle root. An
+/// arbitrary scalar would also let the initiator request
+/// signatures for a key that no party can check against
+/// a script tree commitment.
+#[derive(
+    Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize,
+)]
+pub enum TaprootTweak {
+    /// Key path spend for an output without a script
+    /// tree (BIP-86).
+    KeyPath,
+    /// Key path spend for an output that commits to a
+    /// script tree with the given merkle root.
+    MerkleRoot([u8; 32]),
+}
+
+impl TaprootTweak {
+    /// Merkle root committed to by the output key.
+    ///
+    /// An output without a script tree commits to
+    /// an empty merkle root.
+    pub fn merkle_root(&self) -> &[u8] {
+        match self {
+            Self::KeyPath => &[],
+            Self::MerkleRoot(root) => root.as_slice(),
+        }
+    }
+}
+
+impl From<Option<[u8; 32]>> for TaprootTweak {
+    fn from(value: Option<[u8; 32]>) -> Self {
+        match value {
+            Some(root) => Self::MerkleRoot(root),
+            None => Self::KeyPath,
+        }
+    }
+}
+
+/// Tweak a key share so signatures verify against
+/// the Taproot output key.
+pub fn tweak_key_share(
+    key_share: KeyShare,
+    tweak: &TaprootTweak,
+) -> KeyShare {
+    let (key_package, public_key_package) = key_share;
+    (
+        key_package.tweak(Some(tweak.merkle_root())),
+        public_key_package.tweak(Some(tweak.merkle_root())),
+    )
+}
+
+/// Compute the x-only Taproot output key for a
+/// public key package.
+pub fn tweaked_output_key(
+    public_key_package: &PublicKeyPackage,
+    tweak: &TaprootTweak,
+) -> Result<[u8; 32]> {
+    let tweaked =
+        public_key_package.clone().tweak(Some(tweak.merkle_root()));
+    // FROST serializes the verifying key as a compressed
+    // point whereas BIP-340 uses the X coordinate only.
+    let bytes = tweaked.verifying_key().serialize()?;
+    let mut output_key = [0u8; 32];
+    output_key.copy_from_slice(&bytes[bytes.len() - 32..]);
+    Ok(output_key)
+}
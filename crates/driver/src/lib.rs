@@ -10,9 +10,24 @@ mod error;
 #[cfg(feature = "frost")]
 pub mod frost;
 
+#[cfg(any(feature = "ecdsa", feature = "cggmp"))]
+pub mod eip712;
+
 #[cfg(any(feature = "ecdsa", feature = "cggmp"))]
 pub mod recoverable_signature;
 
+#[cfg(any(feature = "ecdsa", feature = "cggmp"))]
+pub mod transaction;
+
+#[cfg(feature = "solana")]
+pub mod solana;
+
+#[cfg(feature = "bitcoin")]
+pub mod bitcoin;
+
+#[cfg(feature = "addresses")]
+pub mod addresses;
+
 #[cfg(any(feature = "cggmp", feature = "frost"))]
 mod protocol;
 
@@ -28,15 +43,33 @@ pub use frost_ed25519;
 #[cfg(feature = "frost-secp256k1-tr")]
 pub use frost_secp256k1_tr;
 
+#[cfg(feature = "frost-ristretto255")]
+pub use frost_ristretto255;
+
+#[cfg(feature = "frost-p256")]
+pub use {frost_p256, p256};
+
+#[cfg(feature = "frost-secp256k1")]
+pub use frost_secp256k1;
+
+#[cfg(feature = "frost-ed448")]
+pub use frost_ed448;
+
 #[cfg(any(
     feature = "cggmp",
     feature = "ecdsa",
     feature = "schnorr",
-    feature = "frost-secp256k1-tr"
+    feature = "frost-secp256k1-tr",
+    feature = "frost-secp256k1"
 ))]
 pub use k256;
 
-#[cfg(any(feature = "eddsa", feature = "frost-ed25519"))]
+#[cfg(any(
+    feature = "eddsa",
+    feature = "frost-ed25519",
+    feature = "frost-ristretto255",
+    feature = "frost-ed448"
+))]
 pub use ed25519_dalek;
 
 pub use error::Error;
@@ -96,10 +96,15 @@ pub trait ProtocolDriver {
 
 /// Trait for round messages.
 pub trait Round: Send + Sync {
+    /// Identity of the party that sent a message.
+    type Sender: PartialEq + Send + Sync;
+
     /// Round number.
-    #[allow(dead_code)]
     fn round_number(&self) -> RoundNumber;
 
+    /// Sender of a message.
+    fn sender(&self) -> &Self::Sender;
+
     /// Receiver for a message.
     fn receiver(&self) -> &PartyNumber;
 }
@@ -131,12 +136,18 @@ where
 impl<Body, Verifier> Round for RoundMessage<Body, Verifier>
 where
     Body: Send + Sync,
-    Verifier: Serialize + Send + Sync + DeserializeOwned,
+    Verifier: Serialize + Send + Sync + DeserializeOwned + PartialEq,
 {
+    type Sender = Verifier;
+
     fn round_number(&self) -> RoundNumber {
         self.round
     }
 
+    fn sender(&self) -> &Self::Sender {
+        &self.sender
+    }
+
     fn receiver(&self) -> &PartyNumber {
         &self.receiver
     }
@@ -1,5 +1,8 @@
 //! Generate ECDSA signatures compatible with Ethereum.
-use crate::{recoverable_signature::RecoverableSignature, Result};
+use crate::{
+    eip712::TypedData, recoverable_signature::RecoverableSignature,
+    Result,
+};
 use k256::ecdsa::{
     signature::{hazmat::PrehashVerifier, Signer, Verifier},
     RecoveryId, SigningKey, VerifyingKey,
@@ -97,6 +100,14 @@ impl<'a> EcdsaSigner<'a> {
         Ok(self.signing_key.sign_digest_recoverable(digest)?)
     }
 
+    /// Sign EIP-712 typed structured data.
+    pub fn sign_typed_data(
+        &self,
+        typed_data: &TypedData,
+    ) -> Result<(Signature, RecoveryId)> {
+        self.sign_prehash_recoverable(&typed_data.signing_hash()?)
+    }
+
     /// Recover the public key from a signature and recovery identifier.
     pub fn recover(
         message: &[u8],
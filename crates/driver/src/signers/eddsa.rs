@@ -7,6 +7,9 @@ use std::borrow::Cow;
 
 pub use ed25519_dalek::Signature;
 
+#[cfg(feature = "reconstruct")]
+pub use ed25519_dalek::hazmat::ExpandedSecretKey;
+
 /// Create a signer for EdDSA signatures.
 pub struct EddsaSigner<'a> {
     signing_key: Cow<'a, SigningKey>,
@@ -59,6 +62,58 @@ impl<'a> EddsaSigner<'a> {
     }
 }
 
+/// Create a signer for EdDSA signatures from an expanded
+/// secret key.
+///
+/// Signing keys reconstructed from threshold key shares are
+/// secret scalars rather than the seed used by
+/// [EddsaSigner] so they are signed with this signer
+/// instead. Signatures are standard Ed25519 signatures
+/// that verify against the verifying key for the scalar.
+#[cfg(feature = "reconstruct")]
+pub struct ExpandedEddsaSigner {
+    secret_key: ExpandedSecretKey,
+    verifying_key: VerifyingKey,
+}
+
+#[cfg(feature = "reconstruct")]
+impl ExpandedEddsaSigner {
+    /// Create a new signer.
+    pub fn new(secret_key: ExpandedSecretKey) -> Self {
+        let verifying_key = VerifyingKey::from(&secret_key);
+        Self {
+            secret_key,
+            verifying_key,
+        }
+    }
+
+    /// Sign a message.
+    pub fn sign<B: AsRef<[u8]>>(&self, message: B) -> Signature {
+        ed25519_dalek::hazmat::raw_sign::<sha2::Sha512>(
+            &self.secret_key,
+            message.as_ref(),
+            &self.verifying_key,
+        )
+    }
+
+    /// Verifying key for this signer.
+    pub fn verifying_key(&self) -> &VerifyingKey {
+        &self.verifying_key
+    }
+
+    /// Verify a message.
+    pub fn verify<B: AsRef<[u8]>>(
+        &self,
+        message: B,
+        signature: &Signature,
+    ) -> Result<()> {
+        let verifier = DalekVerifier {
+            verifying_key: self.verifying_key(),
+        };
+        verifier.verify(message, signature)
+    }
+}
+
 struct DalekSigner<'a, S>
 where
     S: Signer<ed25519::Signature>,
@@ -56,6 +56,24 @@ impl<'a> SchnorrSigner<'a> {
         Ok(self.signing_key.sign_raw(msg_digest, aux_rand)?)
     }
 
+    /// Tweak the signing key for a Taproot key path spend
+    /// (BIP-341).
+    ///
+    /// Signatures from the tweaked signer verify against the
+    /// output key. Without a merkle root the output key
+    /// commits to an unspendable script path (BIP-86).
+    #[cfg(feature = "bitcoin")]
+    pub fn taproot_tweak(
+        &self,
+        merkle_root: Option<&[u8; 32]>,
+    ) -> Result<SchnorrSigner<'static>> {
+        let signing_key = crate::bitcoin::tweak_signing_key(
+            &self.signing_key,
+            merkle_root,
+        )?;
+        Ok(SchnorrSigner::new(Cow::Owned(signing_key)))
+    }
+
     /// Verifying key for this signer.
     pub fn verifying_key(&self) -> &VerifyingKey {
         self.signing_key.verifying_key()
@@ -0,0 +1,569 @@
+//! Build, sign and encode Solana transactions.
+//!
+//! Messages are compiled from instructions into the legacy
+//! or version 0 format and the serialized message is signed
+//! using `EddsaSigner` or FROST Ed25519.
+//!
+//! Off-chain messages can be signed using the format from
+//! [offchain_message].
+use crate::{Error, Result};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use std::{collections::BTreeMap, fmt, str::FromStr};
+
+/// Prefix for off-chain messages.
+const SIGNING_DOMAIN: &[u8; 16] = b"\xffsolana offchain";
+
+/// Length of the off-chain message header.
+const OFFCHAIN_HEADER_LEN: usize = SIGNING_DOMAIN.len() + 4;
+
+/// Maximum length of an off-chain message that fits in
+/// a packet.
+const OFFCHAIN_MAX_LEN_LEDGER: usize = 1232 - OFFCHAIN_HEADER_LEN;
+
+/// Maximum length of an off-chain message.
+const OFFCHAIN_MAX_LEN: usize =
+    u16::MAX as usize - OFFCHAIN_HEADER_LEN;
+
+/// Prefix for versioned messages.
+const VERSION_PREFIX: u8 = 0x80;
+
+/// Public key for an account encoded as base58.
+#[derive(
+    Debug, Clone, Copy, Default, Eq, PartialEq, Ord, PartialOrd,
+)]
+pub struct Pubkey(pub [u8; 32]);
+
+impl fmt::Display for Pubkey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", bs58::encode(self.0).into_string())
+    }
+}
+
+impl FromStr for Pubkey {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(Self(decode_base58(s)?))
+    }
+}
+
+impl From<[u8; 32]> for Pubkey {
+    fn from(value: [u8; 32]) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&ed25519_dalek::VerifyingKey> for Pubkey {
+    fn from(value: &ed25519_dalek::VerifyingKey) -> Self {
+        Self(value.to_bytes())
+    }
+}
+
+/// Hash of a block encoded as base58.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct Hash(pub [u8; 32]);
+
+impl fmt::Display for Hash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", bs58::encode(self.0).into_string())
+    }
+}
+
+impl FromStr for Hash {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(Self(decode_base58(s)?))
+    }
+}
+
+impl From<[u8; 32]> for Hash {
+    fn from(value: [u8; 32]) -> Self {
+        Self(value)
+    }
+}
+
+/// Signature for a transaction.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Signature(pub [u8; 64]);
+
+impl Default for Signature {
+    fn default() -> Self {
+        Self([0; 64])
+    }
+}
+
+impl fmt::Display for Signature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", bs58::encode(self.0).into_string())
+    }
+}
+
+impl From<ed25519_dalek::Signature> for Signature {
+    fn from(value: ed25519_dalek::Signature) -> Self {
+        Self(value.to_bytes())
+    }
+}
+
+#[cfg(feature = "frost-ed25519")]
+impl TryFrom<frost_ed25519::Signature> for Signature {
+    type Error = Error;
+
+    fn try_from(value: frost_ed25519::Signature) -> Result<Self> {
+        let bytes =
+            value.serialize().map_err(crate::frost::Error::from)?;
+        Ok(Self(
+            bytes.try_into().map_err(|_| Error::SolanaSignature)?,
+        ))
+    }
+}
+
+/// Account used by an instruction.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct AccountMeta {
+    /// Public key of the account.
+    pub pubkey: Pubkey,
+    /// Whether the account must sign the transaction.
+    pub is_signer: bool,
+    /// Whether the instruction may modify the account.
+    pub is_writable: bool,
+}
+
+impl AccountMeta {
+    /// Create a writable account.
+    pub fn new(pubkey: Pubkey, is_signer: bool) -> Self {
+        Self {
+            pubkey,
+            is_signer,
+            is_writable: true,
+        }
+    }
+
+    /// Create a read-only account.
+    pub fn new_readonly(pubkey: Pubkey, is_signer: bool) -> Self {
+        Self {
+            pubkey,
+            is_signer,
+            is_writable: false,
+        }
+    }
+}
+
+/// Instruction to invoke a program.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Instruction {
+    /// Program to invoke.
+    pub program_id: Pubkey,
+    /// Accounts used by the program.
+    pub accounts: Vec<AccountMeta>,
+    /// Input data for the program.
+    pub data: Vec<u8>,
+}
+
+/// Number of signed and read-only accounts in a message.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct MessageHeader {
+    /// Number of signatures required for the message.
+    pub num_required_signatures: u8,
+    /// Number of signed accounts that are read-only.
+    pub num_readonly_signed_accounts: u8,
+    /// Number of unsigned accounts that are read-only.
+    pub num_readonly_unsigned_accounts: u8,
+}
+
+/// Instruction with accounts referenced by index.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct CompiledInstruction {
+    /// Index of the program account.
+    pub program_id_index: u8,
+    /// Indices of the accounts used by the program.
+    pub accounts: Vec<u8>,
+    /// Input data for the program.
+    pub data: Vec<u8>,
+}
+
+/// Accounts loaded from an address lookup table.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct MessageAddressTableLookup {
+    /// Address of the lookup table.
+    pub account_key: Pubkey,
+    /// Indices of writable accounts in the table.
+    pub writable_indexes: Vec<u8>,
+    /// Indices of read-only accounts in the table.
+    pub readonly_indexes: Vec<u8>,
+}
+
+/// Legacy transaction message.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct LegacyMessage {
+    /// Message header.
+    pub header: MessageHeader,
+    /// Account keys, signers first.
+    pub account_keys: Vec<Pubkey>,
+    /// Recent block hash.
+    pub recent_blockhash: Hash,
+    /// Compiled instructions.
+    pub instructions: Vec<CompiledInstruction>,
+}
+
+/// Version 0 transaction message.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct MessageV0 {
+    /// Message header.
+    pub header: MessageHeader,
+    /// Static account keys, signers first.
+    pub account_keys: Vec<Pubkey>,
+    /// Recent block hash.
+    pub recent_blockhash: Hash,
+    /// Compiled instructions.
+    ///
+    /// Accounts loaded from lookup tables are indexed after
+    /// the static account keys.
+    pub instructions: Vec<CompiledInstruction>,
+    /// Address lookup tables.
+    pub address_table_lookups: Vec<MessageAddressTableLookup>,
+}
+
+/// Transaction message.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Message {
+    /// Legacy message.
+    Legacy(LegacyMessage),
+    /// Version 0 message.
+    V0(MessageV0),
+}
+
+impl Message {
+    /// Compile instructions into a legacy message.
+    ///
+    /// The payer is the first signer.
+    pub fn legacy(
+        payer: &Pubkey,
+        instructions: &[Instruction],
+        recent_blockhash: Hash,
+    ) -> Result<Self> {
+        let (header, account_keys, instructions) =
+            compile(payer, instructions)?;
+        Ok(Self::Legacy(LegacyMessage {
+            header,
+            account_keys,
+            recent_blockhash,
+            instructions,
+        }))
+    }
+
+    /// Compile instructions into a version 0 message
+    /// without address lookup tables.
+    ///
+    /// The payer is the first signer.
+    pub fn v0(
+        payer: &Pubkey,
+        instructions: &[Instruction],
+        recent_blockhash: Hash,
+    ) -> Result<Self> {
+        let (header, account_keys, instructions) =
+            compile(payer, instructions)?;
+        Ok(Self::V0(MessageV0 {
+            header,
+            account_keys,
+            recent_blockhash,
+            instructions,
+            address_table_lookups: vec![],
+        }))
+    }
+
+    /// Message header.
+    pub fn header(&self) -> &MessageHeader {
+        match self {
+            Self::Legacy(message) => &message.header,
+            Self::V0(message) => &message.header,
+        }
+    }
+
+    /// Static account keys.
+    pub fn account_keys(&self) -> &[Pubkey] {
+        match self {
+            Self::Legacy(message) => &message.account_keys,
+            Self::V0(message) => &message.account_keys,
+        }
+    }
+
+    /// Public keys of the accounts that must sign.
+    pub fn signers(&self) -> &[Pubkey] {
+        let keys = self.account_keys();
+        let count = self.header().num_required_signatures as usize;
+        &keys[..count.min(keys.len())]
+    }
+
+    /// Serialize the message to the bytes that are signed.
+    pub fn serialize(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        let (header, account_keys, recent_blockhash, instructions) =
+            match self {
+                Self::Legacy(message) => (
+                    &message.header,
+                    &message.account_keys,
+                    &message.recent_blockhash,
+                    &message.instructions,
+                ),
+                Self::V0(message) => {
+                    out.push(VERSION_PREFIX);
+                    (
+                        &message.header,
+                        &message.account_keys,
+                        &message.recent_blockhash,
+                        &message.instructions,
+                    )
+                }
+            };
+
+        out.push(header.num_required_signatures);
+        out.push(header.num_readonly_signed_accounts);
+        out.push(header.num_readonly_unsigned_accounts);
+
+        encode_length(&mut out, account_keys.len());
+        for key in account_keys {
+            out.extend_from_slice(&key.0);
+        }
+
+        out.extend_from_slice(&recent_blockhash.0);
+
+        encode_length(&mut out, instructions.len());
+        for instruction in instructions {
+            out.push(instruction.program_id_index);
+            encode_bytes(&mut out, &instruction.accounts);
+            encode_bytes(&mut out, &instruction.data);
+        }
+
+        if let Self::V0(message) = self {
+            encode_length(
+                &mut out,
+                message.address_table_lookups.len(),
+            );
+            for lookup in &message.address_table_lookups {
+                out.extend_from_slice(&lookup.account_key.0);
+                encode_bytes(&mut out, &lookup.writable_indexes);
+                encode_bytes(&mut out, &lookup.readonly_indexes);
+            }
+        }
+
+        out
+    }
+}
+
+/// Transaction with signatures for a message.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Transaction {
+    /// Signatures in the order of the message signers.
+    pub signatures: Vec<Signature>,
+    /// Message to sign.
+    pub message: Message,
+}
+
+impl Transaction {
+    /// Create an unsigned transaction.
+    pub fn new(message: Message) -> Self {
+        let signatures = vec![
+            Signature::default();
+            message.header().num_required_signatures
+                as usize
+        ];
+        Self {
+            signatures,
+            message,
+        }
+    }
+
+    /// Bytes to sign.
+    pub fn message_bytes(&self) -> Vec<u8> {
+        self.message.serialize()
+    }
+
+    /// Add the signature for a signer.
+    ///
+    /// The signature may be created by `EddsaSigner` or
+    /// converted from a FROST Ed25519 signature.
+    pub fn add_signature(
+        &mut self,
+        pubkey: &Pubkey,
+        signature: impl Into<Signature>,
+    ) -> Result<()> {
+        let index = self
+            .message
+            .signers()
+            .iter()
+            .position(|key| key == pubkey)
+            .ok_or_else(|| Error::SolanaSigner(pubkey.to_string()))?;
+        self.signatures[index] = signature.into();
+        Ok(())
+    }
+
+    /// Sign the transaction using a single party signer.
+    pub fn sign(
+        &mut self,
+        signer: &crate::signers::eddsa::EddsaSigner<'_>,
+    ) -> Result<()> {
+        let pubkey: Pubkey = signer.verifying_key().into();
+        let signature = signer.sign(self.message_bytes());
+        self.add_signature(&pubkey, signature)
+    }
+
+    /// Determine if all the signers have signed.
+    pub fn is_signed(&self) -> bool {
+        self.signatures.iter().all(|s| s != &Signature::default())
+    }
+
+    /// Serialize the transaction to the wire format.
+    pub fn serialize(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        encode_length(&mut out, self.signatures.len());
+        for signature in &self.signatures {
+            out.extend_from_slice(&signature.0);
+        }
+        out.extend_from_slice(&self.message.serialize());
+        out
+    }
+
+    /// Serialize the transaction encoded as base58.
+    pub fn to_base58(&self) -> String {
+        bs58::encode(self.serialize()).into_string()
+    }
+
+    /// Serialize the transaction encoded as base64.
+    pub fn to_base64(&self) -> String {
+        STANDARD.encode(self.serialize())
+    }
+}
+
+/// Create the bytes to sign for an off-chain message.
+///
+/// Uses version 0 of the off-chain message format, the
+/// message format is chosen from the length and content
+/// of the message.
+pub fn offchain_message(message: &[u8]) -> Result<Vec<u8>> {
+    if message.is_empty() {
+        return Err(Error::SolanaOffchainMessage);
+    }
+
+    let format = if message.len() <= OFFCHAIN_MAX_LEN_LEDGER {
+        if message.iter().all(|b| (0x20..=0x7e).contains(b)) {
+            0u8
+        } else if std::str::from_utf8(message).is_ok() {
+            1
+        } else {
+            return Err(Error::SolanaOffchainMessage);
+        }
+    } else if message.len() <= OFFCHAIN_MAX_LEN
+        && std::str::from_utf8(message).is_ok()
+    {
+        2
+    } else {
+        return Err(Error::SolanaOffchainMessage);
+    };
+
+    let mut out =
+        Vec::with_capacity(OFFCHAIN_HEADER_LEN + message.len());
+    out.extend_from_slice(SIGNING_DOMAIN);
+    out.push(0);
+    out.push(format);
+    out.extend_from_slice(&(message.len() as u16).to_le_bytes());
+    out.extend_from_slice(message);
+    Ok(out)
+}
+
+/// Compile instructions into the message header, account
+/// keys and compiled instructions.
+///
+/// Accounts are ordered by writable signers, read-only
+/// signers, writable accounts and read-only accounts with
+/// the payer first and the other keys sorted within
+/// each group.
+fn compile(
+    payer: &Pubkey,
+    instructions: &[Instruction],
+) -> Result<(MessageHeader, Vec<Pubkey>, Vec<CompiledInstruction>)> {
+    // Signer and writable flags for each account
+    let mut accounts: BTreeMap<Pubkey, (bool, bool)> =
+        BTreeMap::new();
+    for instruction in instructions {
+        accounts.entry(instruction.program_id).or_default();
+        for meta in &instruction.accounts {
+            let flags = accounts.entry(meta.pubkey).or_default();
+            flags.0 |= meta.is_signer;
+            flags.1 |= meta.is_writable;
+        }
+    }
+    accounts.remove(payer);
+
+    let group = |signer: bool, writable: bool| {
+        accounts
+            .iter()
+            .filter(move |(_, flags)| **flags == (signer, writable))
+            .map(|(key, _)| *key)
+            .collect::<Vec<_>>()
+    };
+
+    let mut account_keys = vec![*payer];
+    account_keys.extend(group(true, true));
+    let readonly_signed = group(true, false);
+    account_keys.extend(&readonly_signed);
+    let num_required_signatures = account_keys.len();
+    account_keys.extend(group(false, true));
+    let readonly_unsigned = group(false, false);
+    account_keys.extend(&readonly_unsigned);
+
+    if account_keys.len() > u8::MAX as usize + 1 {
+        return Err(Error::SolanaAccounts(account_keys.len()));
+    }
+
+    let index = |pubkey: &Pubkey| {
+        account_keys.iter().position(|key| key == pubkey).unwrap()
+            as u8
+    };
+    let compiled = instructions
+        .iter()
+        .map(|instruction| CompiledInstruction {
+            program_id_index: index(&instruction.program_id),
+            accounts: instruction
+                .accounts
+                .iter()
+                .map(|meta| index(&meta.pubkey))
+                .collect(),
+            data: instruction.data.clone(),
+        })
+        .collect();
+
+    let header = MessageHeader {
+        num_required_signatures: num_required_signatures as u8,
+        num_readonly_signed_accounts: readonly_signed.len() as u8,
+        num_readonly_unsigned_accounts: readonly_unsigned.len() as u8,
+    };
+    Ok((header, account_keys, compiled))
+}
+
+/// Encode a length using the compact-u16 format.
+fn encode_length(out: &mut Vec<u8>, length: usize) {
+    let mut value = length as u16;
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            out.push(byte);
+            break;
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+fn encode_bytes(out: &mut Vec<u8>, bytes: &[u8]) {
+    encode_length(out, bytes.len());
+    out.extend_from_slice(bytes);
+}
+
+fn decode_base58(s: &str) -> Result<[u8; 32]> {
+    bs58::decode(s)
+        .into_vec()
+        .ok()
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| Error::SolanaBase58(s.to_owned()))
+}
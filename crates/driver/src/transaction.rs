@@ -0,0 +1,375 @@
+//! Encode and sign Ethereum transactions.
+//!
+//! Supports legacy transactions with optional [EIP-155]
+//! replay protection and the [EIP-2930], [EIP-1559] and
+//! [EIP-4844] typed transaction envelopes.
+//!
+//! Sign the signing hash using `EcdsaSigner` or CGGMP and
+//! pass the recoverable signature to
+//! [Transaction::encode_signed] to create the raw transaction.
+//!
+//! [EIP-155]: https://eips.ethereum.org/EIPS/eip-155
+//! [EIP-2930]: https://eips.ethereum.org/EIPS/eip-2930
+//! [EIP-1559]: https://eips.ethereum.org/EIPS/eip-1559
+//! [EIP-4844]: https://eips.ethereum.org/EIPS/eip-4844
+use crate::{recoverable_signature::RecoverableSignature, Result};
+use k256::ecdsa::{RecoveryId, Signature};
+use sha3::{Digest, Keccak256};
+
+/// Ethereum address.
+pub type Address = [u8; 20];
+
+/// Address and storage keys accessed by a transaction.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct AccessListItem {
+    /// Accessed address.
+    pub address: Address,
+    /// Accessed storage keys.
+    pub storage_keys: Vec<[u8; 32]>,
+}
+
+/// Legacy transaction.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct LegacyTransaction {
+    /// Chain identifier for EIP-155 replay protection.
+    pub chain_id: Option<u64>,
+    /// Nonce.
+    pub nonce: u64,
+    /// Gas price in wei.
+    pub gas_price: u128,
+    /// Gas limit.
+    pub gas_limit: u64,
+    /// Recipient or `None` to create a contract.
+    pub to: Option<Address>,
+    /// Value in wei.
+    pub value: u128,
+    /// Input data.
+    pub data: Vec<u8>,
+}
+
+/// Transaction with an access list (EIP-2930).
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Eip2930Transaction {
+    /// Chain identifier.
+    pub chain_id: u64,
+    /// Nonce.
+    pub nonce: u64,
+    /// Gas price in wei.
+    pub gas_price: u128,
+    /// Gas limit.
+    pub gas_limit: u64,
+    /// Recipient or `None` to create a contract.
+    pub to: Option<Address>,
+    /// Value in wei.
+    pub value: u128,
+    /// Input data.
+    pub data: Vec<u8>,
+    /// Access list.
+    pub access_list: Vec<AccessListItem>,
+}
+
+/// Transaction with a priority fee (EIP-1559).
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Eip1559Transaction {
+    /// Chain identifier.
+    pub chain_id: u64,
+    /// Nonce.
+    pub nonce: u64,
+    /// Maximum priority fee per gas in wei.
+    pub max_priority_fee_per_gas: u128,
+    /// Maximum fee per gas in wei.
+    pub max_fee_per_gas: u128,
+    /// Gas limit.
+    pub gas_limit: u64,
+    /// Recipient or `None` to create a contract.
+    pub to: Option<Address>,
+    /// Value in wei.
+    pub value: u128,
+    /// Input data.
+    pub data: Vec<u8>,
+    /// Access list.
+    pub access_list: Vec<AccessListItem>,
+}
+
+/// Blob transaction (EIP-4844).
+///
+/// The signed encoding does not include the blobs,
+/// commitments and proofs which are sent alongside the
+/// transaction when it is broadcast.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Eip4844Transaction {
+    /// Chain identifier.
+    pub chain_id: u64,
+    /// Nonce.
+    pub nonce: u64,
+    /// Maximum priority fee per gas in wei.
+    pub max_priority_fee_per_gas: u128,
+    /// Maximum fee per gas in wei.
+    pub max_fee_per_gas: u128,
+    /// Gas limit.
+    pub gas_limit: u64,
+    /// Recipient.
+    pub to: Address,
+    /// Value in wei.
+    pub value: u128,
+    /// Input data.
+    pub data: Vec<u8>,
+    /// Access list.
+    pub access_list: Vec<AccessListItem>,
+    /// Maximum fee per blob gas in wei.
+    pub max_fee_per_blob_gas: u128,
+    /// Versioned hashes of the blobs.
+    pub blob_versioned_hashes: Vec<[u8; 32]>,
+}
+
+/// Ethereum transaction.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Transaction {
+    /// Legacy transaction.
+    Legacy(LegacyTransaction),
+    /// Transaction with an access list.
+    Eip2930(Eip2930Transaction),
+    /// Transaction with a priority fee.
+    Eip1559(Eip1559Transaction),
+    /// Blob transaction.
+    Eip4844(Eip4844Transaction),
+}
+
+/// Signed transaction.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SignedTransaction {
+    /// Raw transaction bytes to broadcast.
+    pub raw: Vec<u8>,
+    /// Transaction hash.
+    pub hash: [u8; 32],
+}
+
+impl Transaction {
+    /// Transaction type or `None` for legacy transactions.
+    pub fn transaction_type(&self) -> Option<u8> {
+        match self {
+            Self::Legacy(_) => None,
+            Self::Eip2930(_) => Some(0x01),
+            Self::Eip1559(_) => Some(0x02),
+            Self::Eip4844(_) => Some(0x03),
+        }
+    }
+
+    /// Compute the hash to be signed.
+    ///
+    /// The hash can be signed with
+    /// `EcdsaSigner::sign_prehash_recoverable()` and is the
+    /// prehashed message for CGGMP signing.
+    pub fn signing_hash(&self) -> [u8; 32] {
+        let mut fields = self.fields();
+        if let Self::Legacy(LegacyTransaction {
+            chain_id: Some(chain_id),
+            ..
+        }) = self
+        {
+            encode_uint(&mut fields, *chain_id as u128);
+            encode_uint(&mut fields, 0);
+            encode_uint(&mut fields, 0);
+        }
+        keccak256(&self.envelope(fields))
+    }
+
+    /// Encode the signed transaction.
+    ///
+    /// Signatures with a high `s` value are normalized.
+    pub fn encode_signed(
+        &self,
+        signature: &RecoverableSignature,
+    ) -> Result<SignedTransaction> {
+        let (signature, recovery_id): (Signature, RecoveryId) =
+            signature.try_into()?;
+        let (signature, recovery_id) = match signature.normalize_s() {
+            Some(signature) => (
+                signature,
+                RecoveryId::new(
+                    !recovery_id.is_y_odd(),
+                    recovery_id.is_x_reduced(),
+                ),
+            ),
+            None => (signature, recovery_id),
+        };
+        let parity = recovery_id.is_y_odd() as u128;
+        let v = match self {
+            Self::Legacy(LegacyTransaction {
+                chain_id: Some(chain_id),
+                ..
+            }) => *chain_id as u128 * 2 + 35 + parity,
+            Self::Legacy(_) => 27 + parity,
+            _ => parity,
+        };
+
+        let (r, s) = signature.split_bytes();
+        let mut fields = self.fields();
+        encode_uint(&mut fields, v);
+        encode_bytes(&mut fields, trim_leading_zeros(&r));
+        encode_bytes(&mut fields, trim_leading_zeros(&s));
+
+        let raw = self.envelope(fields);
+        let hash = keccak256(&raw);
+        Ok(SignedTransaction { raw, hash })
+    }
+
+    /// Encode the list of transaction fields without the
+    /// signature values.
+    fn fields(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        match self {
+            Self::Legacy(tx) => {
+                encode_uint(&mut out, tx.nonce as u128);
+                encode_uint(&mut out, tx.gas_price);
+                encode_uint(&mut out, tx.gas_limit as u128);
+                encode_to(&mut out, tx.to.as_ref());
+                encode_uint(&mut out, tx.value);
+                encode_bytes(&mut out, &tx.data);
+            }
+            Self::Eip2930(tx) => {
+                encode_uint(&mut out, tx.chain_id as u128);
+                encode_uint(&mut out, tx.nonce as u128);
+                encode_uint(&mut out, tx.gas_price);
+                encode_uint(&mut out, tx.gas_limit as u128);
+                encode_to(&mut out, tx.to.as_ref());
+                encode_uint(&mut out, tx.value);
+                encode_bytes(&mut out, &tx.data);
+                encode_access_list(&mut out, &tx.access_list);
+            }
+            Self::Eip1559(tx) => {
+                encode_uint(&mut out, tx.chain_id as u128);
+                encode_uint(&mut out, tx.nonce as u128);
+                encode_uint(&mut out, tx.max_priority_fee_per_gas);
+                encode_uint(&mut out, tx.max_fee_per_gas);
+                encode_uint(&mut out, tx.gas_limit as u128);
+                encode_to(&mut out, tx.to.as_ref());
+                encode_uint(&mut out, tx.value);
+                encode_bytes(&mut out, &tx.data);
+                encode_access_list(&mut out, &tx.access_list);
+            }
+            Self::Eip4844(tx) => {
+                encode_uint(&mut out, tx.chain_id as u128);
+                encode_uint(&mut out, tx.nonce as u128);
+                encode_uint(&mut out, tx.max_priority_fee_per_gas);
+                encode_uint(&mut out, tx.max_fee_per_gas);
+                encode_uint(&mut out, tx.gas_limit as u128);
+                encode_to(&mut out, Some(&tx.to));
+                encode_uint(&mut out, tx.value);
+                encode_bytes(&mut out, &tx.data);
+                encode_access_list(&mut out, &tx.access_list);
+                encode_uint(&mut out, tx.max_fee_per_blob_gas);
+                let mut hashes = Vec::new();
+                for hash in &tx.blob_versioned_hashes {
+                    encode_bytes(&mut hashes, hash);
+                }
+                encode_list(&mut out, &hashes);
+            }
+        }
+        out
+    }
+
+    /// Wrap the encoded fields in a list prefixed with the
+    /// transaction type.
+    fn envelope(&self, fields: Vec<u8>) -> Vec<u8> {
+        let mut out = Vec::with_capacity(fields.len() + 10);
+        if let Some(kind) = self.transaction_type() {
+            out.push(kind);
+        }
+        encode_list(&mut out, &fields);
+        out
+    }
+}
+
+impl From<LegacyTransaction> for Transaction {
+    fn from(value: LegacyTransaction) -> Self {
+        Self::Legacy(value)
+    }
+}
+
+impl From<Eip2930Transaction> for Transaction {
+    fn from(value: Eip2930Transaction) -> Self {
+        Self::Eip2930(value)
+    }
+}
+
+impl From<Eip1559Transaction> for Transaction {
+    fn from(value: Eip1559Transaction) -> Self {
+        Self::Eip1559(value)
+    }
+}
+
+impl From<Eip4844Transaction> for Transaction {
+    fn from(value: Eip4844Transaction) -> Self {
+        Self::Eip4844(value)
+    }
+}
+
+fn encode_to(out: &mut Vec<u8>, to: Option<&Address>) {
+    match to {
+        Some(address) => encode_bytes(out, address),
+        None => encode_bytes(out, &[]),
+    }
+}
+
+fn encode_access_list(
+    out: &mut Vec<u8>,
+    access_list: &[AccessListItem],
+) {
+    let mut items = Vec::new();
+    for item in access_list {
+        let mut keys = Vec::new();
+        for key in &item.storage_keys {
+            encode_bytes(&mut keys, key);
+        }
+        let mut fields = Vec::new();
+        encode_bytes(&mut fields, &item.address);
+        encode_list(&mut fields, &keys);
+        encode_list(&mut items, &fields);
+    }
+    encode_list(out, &items);
+}
+
+/// RLP encode an integer as big-endian bytes without
+/// leading zeros.
+fn encode_uint(out: &mut Vec<u8>, value: u128) {
+    let bytes = value.to_be_bytes();
+    encode_bytes(out, trim_leading_zeros(&bytes));
+}
+
+/// RLP encode a byte string.
+fn encode_bytes(out: &mut Vec<u8>, bytes: &[u8]) {
+    if bytes.len() == 1 && bytes[0] < 0x80 {
+        out.push(bytes[0]);
+    } else {
+        encode_length(out, bytes.len(), 0x80);
+        out.extend_from_slice(bytes);
+    }
+}
+
+/// RLP encode a list from the encoded items.
+fn encode_list(out: &mut Vec<u8>, items: &[u8]) {
+    encode_length(out, items.len(), 0xc0);
+    out.extend_from_slice(items);
+}
+
+fn encode_length(out: &mut Vec<u8>, length: usize, offset: u8) {
+    if length < 56 {
+        out.push(offset + length as u8);
+    } else {
+        let bytes = (length as u64).to_be_bytes();
+        let bytes = trim_leading_zeros(&bytes);
+        out.push(offset + 55 + bytes.len() as u8);
+        out.extend_from_slice(bytes);
+    }
+}
+
+fn trim_leading_zeros(bytes: &[u8]) -> &[u8] {
+    let start =
+        bytes.iter().position(|b| *b != 0).unwrap_or(bytes.len());
+    &bytes[start..]
+}
+
+fn keccak256(bytes: &[u8]) -> [u8; 32] {
+    Keccak256::digest(bytes).into()
+}
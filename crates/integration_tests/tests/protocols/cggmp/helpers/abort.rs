@@ -0,0 +1,82 @@
+use anyhow::Result;
+use polysig_driver::{
+    cggmp::{Error, Fault, KeyInitDriver},
+    synedrion::{sessions::RemoteErrorEnum, SessionId, TestParams},
+    ProtocolDriver, Round,
+};
+use rand::{rngs::OsRng, Rng};
+use serde_json::Value;
+
+use super::make_signers;
+
+/// Flip the first character of every base64 payload so the
+/// message no longer matches the signature of the sender.
+fn corrupt_payload(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                match value {
+                    Value::String(payload) if key == "payload" => {
+                        let first = if payload.starts_with('A') {
+                            "B"
+                        } else {
+                            "A"
+                        };
+                        payload.replace_range(0..1, first);
+                    }
+                    _ => corrupt_payload(value),
+                }
+            }
+        }
+        Value::Array(values) => {
+            values.iter_mut().for_each(corrupt_payload)
+        }
+        _ => {}
+    }
+}
+
+pub fn run_corrupted_message_abort() -> Result<()> {
+    let n = 3;
+    let session_id: [u8; 32] = OsRng.gen();
+    let session_id = SessionId::from_seed(&session_id);
+
+    let (signers, verifiers) = make_signers(n);
+    let mut drivers = Vec::new();
+    for signer in signers {
+        drivers.push(KeyInitDriver::<TestParams>::new(
+            session_id,
+            signer,
+            verifiers.clone(),
+        )?);
+    }
+
+    let mut messages = Vec::new();
+    for driver in drivers.iter_mut() {
+        messages.append(&mut driver.proceed()?);
+    }
+
+    // Corrupt a message sent by the first party
+    let message = messages
+        .into_iter()
+        .find(|message| message.sender() == &verifiers[0])
+        .unwrap();
+    let receiver = message.receiver().get() as usize - 1;
+    let mut value = serde_json::to_value(&message)?;
+    corrupt_payload(&mut value);
+    let corrupted = serde_json::from_value(value)?;
+
+    let result = drivers[receiver].handle_incoming(corrupted);
+    match result {
+        Err(Error::Abort {
+            party,
+            round,
+            fault: Fault::Remote(RemoteErrorEnum::InvalidSignature(_)),
+        }) => {
+            assert_eq!(verifiers[0], party);
+            assert_eq!(1, round);
+        }
+        other => panic!("expected abort, got {:#?}", other),
+    }
+
+    Ok(())
+}
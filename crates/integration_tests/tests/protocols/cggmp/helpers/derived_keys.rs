@@ -17,7 +17,7 @@ pub async fn run_dkg_derived_2_2(
         *share = derived_key;
     }
 
-    sign_t_2(t, n, server, key_shares, signers).await?;
+    sign_t_2(t, n, server, key_shares, signers, None).await?;
 
     Ok(())
 }
@@ -1,15 +1,18 @@
 use anyhow::Result;
 use polysig_driver::{
-    cggmp::{Participant, PartyOptions},
+    cggmp::{
+        import_key, verify_key_share, EncodedAuxInfo, Participant,
+        PartyOptions,
+    },
     k256::ecdsa::{
         self, signature::hazmat::PrehashVerifier, SigningKey,
         VerifyingKey,
     },
-    synedrion::{SessionId, TestParams, ThresholdKeyShare},
+    synedrion::{AuxInfo, SessionId, TestParams, ThresholdKeyShare},
 };
 
 use polysig_client::{
-    cggmp::{dkg, sign},
+    cggmp::{aux_gen, dkg, sign},
     ServerOptions, SessionOptions,
 };
 use polysig_protocol::{Keypair, Parameters};
@@ -19,6 +22,7 @@ use std::collections::BTreeSet;
 use super::{make_signers, make_signing_message};
 
 type KeyShare = ThresholdKeyShare<TestParams, VerifyingKey>;
+type SignerAuxInfo = AuxInfo<TestParams, VerifyingKey>;
 
 pub async fn run_dkg_sign_2_2(
     server: &str,
@@ -29,7 +33,7 @@ pub async fn run_dkg_sign_2_2(
 
     let (server, key_shares, signers) =
         run_dkg(t, n, server, server_public_key).await?;
-    sign_t_2(t, n, server, key_shares, signers).await?;
+    sign_t_2(t, n, server, key_shares, signers, None).await?;
 
     Ok(())
 }
@@ -43,7 +47,88 @@ pub async fn run_dkg_sign_2_3(
 
     let (server, key_shares, signers) =
         run_dkg(t, n, server, server_public_key).await?;
-    sign_t_2(t, n, server, key_shares, signers).await?;
+    sign_t_2(t, n, server, key_shares, signers, None).await?;
+
+    Ok(())
+}
+
+pub async fn run_import_sign_2_3(
+    server: &str,
+    server_public_key: Vec<u8>,
+) -> Result<()> {
+    let t = 2;
+    let n = 3;
+
+    let server = ServerOptions {
+        server_url: server.to_owned(),
+        server_public_key,
+        pattern: None,
+    };
+
+    // Existing single-party key
+    let signing_key = SigningKey::random(&mut OsRng);
+    let verifying_key = signing_key.verifying_key().clone();
+
+    let (signers, verifiers) = make_signers(n as usize);
+    let encoded = import_key::<TestParams>(
+        &signing_key,
+        &verifiers,
+        t as usize,
+    )?;
+    assert_eq!(n as usize, encoded.len());
+
+    let mut key_shares = Vec::new();
+    for encoded in &encoded {
+        let key_share: KeyShare = encoded.try_into()?;
+        verify_key_share(&key_share, &verifying_key)?;
+        key_shares.push(key_share);
+    }
+
+    sign_t_2(t, n, server, key_shares, signers, None).await?;
+
+    Ok(())
+}
+
+pub async fn run_dkg_stored_aux_info_sign_2_3(
+    server: &str,
+    server_public_key: Vec<u8>,
+) -> Result<()> {
+    let t = 2;
+    let n = 3;
+
+    let (server, key_shares, signers) =
+        run_dkg(t, n, server, server_public_key).await?;
+
+    // Generate aux info once for the signing parties
+    let aux_infos = aux_gen_t_2(t, n, &server, &signers).await?;
+
+    // Check the aux info can be stored and restored
+    let mut stored_aux_infos = Vec::new();
+    for aux_info in &aux_infos {
+        let encoded: EncodedAuxInfo = aux_info.try_into()?;
+        let decoded: SignerAuxInfo = (&encoded).try_into()?;
+        stored_aux_infos.push(decoded);
+    }
+
+    // Sign multiple times with the same aux info
+    sign_t_2(
+        t,
+        n,
+        server.clone(),
+        key_shares.clone(),
+        signers.clone(),
+        Some(stored_aux_infos.clone()),
+    )
+    .await?;
+    sign_t_2(
+        t,
+        n,
+        server,
+        key_shares,
+        signers,
+        Some(stored_aux_infos),
+    )
+    .await?;
 
     Ok(())
 }
@@ -126,12 +211,89 @@ pub(super) async fn run_dkg(
     Ok((server, key_shares, signers))
 }
 
+/// Generate aux info for the first and last signers
+/// which are the parties selected by `sign_t_2`.
+async fn aux_gen_t_2(
+    t: u16,
+    n: u16,
+    server: &ServerOptions,
+    signers: &[SigningKey],
+) -> Result<Vec<SignerAuxInfo>> {
+    let params = Parameters {
+        parties: n,
+        threshold: t,
+    };
+
+    let rng = &mut OsRng;
+    let aux_session_id: [u8; 32] = rng.gen();
+    let aux_session_id = SessionId::from_seed(&aux_session_id);
+
+    let selected_signers = vec![
+        signers.first().unwrap().clone(),
+        signers.last().unwrap().clone(),
+    ];
+    let selected_verifiers = selected_signers
+        .iter()
+        .map(|s| s.verifying_key().clone())
+        .collect::<Vec<_>>();
+
+    let keypairs = vec![Keypair::generate()?, Keypair::generate()?];
+    let public_keys = keypairs
+        .iter()
+        .map(|k| k.public_key().to_vec())
+        .collect::<Vec<_>>();
+
+    let mut tasks = Vec::new();
+    for (index, (keypair, signer)) in keypairs
+        .into_iter()
+        .zip(selected_signers.into_iter())
+        .enumerate()
+    {
+        let opts = SessionOptions {
+            keypair,
+            parameters: params.clone(),
+            server: server.clone(),
+        };
+        let participants =
+            public_keys.iter().cloned().collect::<Vec<_>>();
+        let is_initiator = index == 0;
+        let public_key = participants.get(index).unwrap().to_vec();
+
+        let party = PartyOptions::new(
+            public_key,
+            participants,
+            is_initiator,
+            selected_verifiers.clone(),
+        )?;
+
+        let verifier = signer.verifying_key().clone();
+        let participant = Participant::new(signer, verifier, party)?;
+        tasks.push(tokio::task::spawn(async move {
+            let aux_info = aux_gen::<TestParams>(
+                opts,
+                participant,
+                aux_session_id.clone(),
+            )
+            .await?;
+            Ok::<_, anyhow::Error>(aux_info)
+        }));
+    }
+
+    let mut aux_infos = Vec::new();
+    let results = futures::future::try_join_all(tasks).await?;
+    for result in results {
+        aux_infos.push(result?);
+    }
+    Ok(aux_infos)
+}
+
 pub(super) async fn sign_t_2(
     t: u16,
     n: u16,
     server: ServerOptions,
     mut key_shares: Vec<KeyShare>,
     signers: Vec<SigningKey>,
+    aux_infos: Option<Vec<SignerAuxInfo>>,
 ) -> Result<()> {
     let params = Parameters {
         parties: n,
@@ -184,6 +346,8 @@ pub(super) async fn sign_t_2(
         },
     ];
 
+    let mut aux_infos = aux_infos.map(|a| a.into_iter());
+
     let mut tasks = Vec::new();
     for (index, ((opts, key_share), signer)) in session_options
         .into_iter()
@@ -191,6 +355,7 @@ pub(super) async fn sign_t_2(
         .zip(selected_signers.into_iter())
         .enumerate()
     {
+        let aux_info = aux_infos.as_mut().and_then(|a| a.next());
         let participants =
             public_keys.iter().cloned().collect::<Vec<_>>();
         let is_initiator = index == 0;
@@ -216,6 +381,7 @@ pub(super) async fn sign_t_2(
                 participant,
                 sign_session_id.clone(),
                 &key_share,
+                aux_info.as_ref(),
                 &message,
             )
             .await?;
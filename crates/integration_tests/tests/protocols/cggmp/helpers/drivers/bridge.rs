@@ -0,0 +1,176 @@
+use super::super::{
+    execute_drivers, make_client_sessions, make_signers,
+};
+use anyhow::Result;
+use futures::StreamExt;
+use polysig_client::{
+    cggmp::KeyGenDriver, wait_for_close, Driver, Error,
+    NetworkTransport,
+};
+use polysig_driver::{
+    synedrion::{SessionId, TestParams},
+    Error as DriverError,
+};
+use polysig_protocol::{
+    hex, serde_json::Value, Event, JsonMessage, Keypair,
+};
+use rand::{rngs::OsRng, Rng};
+
+/// Build a message event as it would be delivered by a peer.
+fn json_event(
+    peer_key: &[u8],
+    message: &Value,
+    session_id: polysig_protocol::SessionId,
+) -> Result<Event> {
+    Ok(Event::JsonMessage {
+        peer_key: peer_key.to_vec(),
+        message: JsonMessage::from(JsonMessage::serialize(message)?),
+        session_id: Some(session_id),
+    })
+}
+
+/// Inject spoofed, misaddressed and replayed messages
+/// into a client driver during key generation and expect
+/// each one to be rejected.
+pub async fn run_bridge_verify_message(
+    server: &str,
+    server_public_key: Vec<u8>,
+) -> Result<()> {
+    let n = 3;
+    let rng = &mut OsRng;
+    let session_id: [u8; 32] = rng.gen();
+    let session_id = SessionId::from_seed(&session_id);
+
+    let (mut signers, verifiers) = make_signers(n);
+    let mut results =
+        make_client_sessions(server, &server_public_key, n).await?;
+
+    // Last party receives the injected messages
+    let (transport, session, mut stream) = results.remove(n - 1);
+    let first_key = session.all_participants[0].clone();
+    let second_key = session.all_participants[1].clone();
+    let unknown_key = Keypair::generate()?.public_key().to_vec();
+    let client_session_id = session.session_id;
+    let mut driver = KeyGenDriver::<TestParams>::new(
+        transport,
+        session,
+        session_id,
+        signers.remove(n - 1),
+        verifiers.clone(),
+    )?;
+
+    let mut streams = Vec::new();
+    let mut drivers = Vec::new();
+    for result in results {
+        let (transport, session, stream) = result;
+        streams.push(stream);
+        drivers.push(KeyGenDriver::<TestParams>::new(
+            transport,
+            session,
+            session_id,
+            signers.remove(0),
+            verifiers.clone(),
+        )?);
+    }
+
+    driver.execute().await?;
+    let others =
+        tokio::task::spawn(execute_drivers(streams, drivers));
+
+    let mut replay: Option<Value> = None;
+    while let Some(event) = stream.next().await {
+        let event = event?;
+        if let Event::JsonMessage {
+            peer_key, message, ..
+        } = &event
+        {
+            if replay.is_none() && peer_key == &first_key {
+                let message: Value = message.deserialize()?;
+
+                // Peer outside of the session
+                let result = driver
+                    .handle_event(json_event(
+                        &unknown_key,
+                        &message,
+                        client_session_id,
+                    )?)
+                    .await;
+                assert!(matches!(
+                    result,
+                    Err(Error::Driver(DriverError::UnknownPeer(key)))
+                        if key == hex::encode(&unknown_key)
+                ));
+
+                // Message from the first party delivered by
+                // the second party
+                let result = driver
+                    .handle_event(json_event(
+                        &second_key,
+                        &message,
+                        client_session_id,
+                    )?)
+                    .await;
+                assert!(matches!(
+                    result,
+                    Err(Error::Driver(DriverError::SenderMismatch(
+                        2
+                    )))
+                ));
+
+                // Message addressed to the second party
+                let mut misaddressed = message.clone();
+                misaddressed["receiver"] = 2.into();
+                let result = driver
+                    .handle_event(json_event(
+                        &first_key,
+                        &misaddressed,
+                        client_session_id,
+                    )?)
+                    .await;
+                assert!(matches!(
+                    result,
+                    Err(Error::Driver(
+                        DriverError::ReceiverMismatch(2, 3)
+                    ))
+                ));
+
+                replay = Some(message);
+            }
+        }
+
+        if driver.handle_event(event).await?.is_some() {
+            break;
+        }
+    }
+
+    // Replay the first round message after the protocol
+    // has moved on to later rounds
+    let replay = replay.expect("message from the first party");
+    assert_eq!(Some(1), replay["round"].as_u64());
+    let result = driver
+        .handle_event(json_event(
+            &first_key,
+            &replay,
+            client_session_id,
+        )?)
+        .await;
+    assert!(matches!(
+        result,
+        Err(Error::Driver(DriverError::StaleRound(1, round)))
+            if round > 1
+    ));
+
+    let results = others.await??;
+    assert_eq!(n - 1, results.len());
+
+    // Close the client sockets
+    let transport = driver.into_transport();
+    transport.close().await?;
+    wait_for_close(&mut stream).await?;
+    for (_, transport, mut stream) in results {
+        transport.close().await?;
+        wait_for_close(&mut stream).await?;
+    }
+
+    Ok(())
+}
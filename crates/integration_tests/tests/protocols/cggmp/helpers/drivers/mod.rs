@@ -1,7 +1,9 @@
 mod aux_info;
+mod bridge;
 mod keygen;
 mod threshold_sign;
 
 pub use aux_info::run_aux_info;
+pub use bridge::run_bridge_verify_message;
 pub use keygen::run_keygen;
 pub use threshold_sign::run_threshold_sign;
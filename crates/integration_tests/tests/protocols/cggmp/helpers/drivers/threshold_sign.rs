@@ -341,7 +341,7 @@ async fn make_key_resharing(
     let mut out_clients = Vec::new();
     for result in results {
         let (output, transport, stream) = result;
-        session_output.push(output);
+        session_output.push(output.unwrap());
         out_clients.push((transport, sessions.remove(0), stream));
     }
 
@@ -14,14 +14,20 @@ use rand::rngs::OsRng;
 use sha3::{Digest, Keccak256};
 use std::pin::Pin;
 
+mod abort;
 mod derived_keys;
 mod dkg_sign;
 mod drivers;
+#[cfg(feature = "reconstruct")]
+mod reconstruct;
 mod reshare;
 
+pub use abort::*;
 pub use derived_keys::*;
 pub use dkg_sign::*;
 pub use drivers::*;
+#[cfg(feature = "reconstruct")]
+pub use reconstruct::*;
 pub use reshare::*;
 
 pub fn make_signing_message() -> Result<PrehashedMessage> {
@@ -0,0 +1,110 @@
+use anyhow::Result;
+use polysig_driver::{
+    cggmp::{import_key, reconstruct_key, Error},
+    k256::ecdsa::{SigningKey, VerifyingKey},
+    synedrion::{TestParams, ThresholdKeyShare},
+};
+use rand::rngs::OsRng;
+
+use super::make_signers;
+
+type KeyShare = ThresholdKeyShare<TestParams, VerifyingKey>;
+
+pub fn run_import_reconstruct_2_3() -> Result<()> {
+    let t = 2;
+    let n = 3;
+
+    let signing_key = SigningKey::random(&mut OsRng);
+    let (_, verifiers) = make_signers(n);
+    let encoded =
+        import_key::<TestParams>(&signing_key, &verifiers, t)?;
+
+    let mut key_shares = Vec::new();
+    for encoded in &encoded {
+        let key_share: KeyShare = encoded.try_into()?;
+        key_shares.push(key_share);
+    }
+
+    // Any threshold subset recovers the imported key
+    for key_shares in [
+        &key_shares[0..2],
+        &key_shares[1..3],
+        &[key_shares[0].clone(), key_shares[2].clone()][..],
+        &key_shares[..],
+    ] {
+        let reconstructed = reconstruct_key(key_shares)?;
+        assert_eq!(signing_key.to_bytes(), reconstructed.to_bytes());
+    }
+
+    // Refuse with fewer than threshold key shares
+    assert!(matches!(
+        reconstruct_key(&key_shares[0..1]),
+        Err(Error::ReconstructThreshold(2, 1))
+    ));
+
+    // Refuse duplicate key shares
+    assert!(matches!(
+        reconstruct_key(&[
+            key_shares[0].clone(),
+            key_shares[0].clone()
+        ]),
+        Err(Error::ReconstructKeyShare)
+    ));
+
+    Ok(())
+}
+
+/// Reconstruction reads the serialized key shares of
+/// synedrion 0.2.0 so fail loudly if the format changes.
+pub fn run_reconstruct_key_share_format() -> Result<()> {
+    let t = 2;
+    let n = 3;
+
+    let signing_key = SigningKey::random(&mut OsRng);
+    let (_, verifiers) = make_signers(n);
+    let encoded =
+        import_key::<TestParams>(&signing_key, &verifiers, t)?;
+    let key_share: KeyShare = (&encoded[0]).try_into()?;
+
+    let value = serde_json::to_value(&key_share)?;
+    let mut fields = value
+        .as_object()
+        .expect("threshold key share is an object")
+        .keys()
+        .map(|k| k.as_str())
+        .collect::<Vec<_>>();
+    fields.sort();
+    assert_eq!(
+        vec![
+            "owner",
+            "phantom",
+            "public_shares",
+            "secret_share",
+            "share_ids",
+            "threshold",
+        ],
+        fields,
+    );
+
+    let owners = verifiers[0..2].iter().copied().collect();
+    let value =
+        serde_json::to_value(key_share.to_key_share(&owners))?;
+    let object = value.as_object().expect("key share is an object");
+    let mut fields =
+        object.keys().map(|k| k.as_str()).collect::<Vec<_>>();
+    fields.sort();
+    assert_eq!(
+        vec!["owner", "phantom", "public_shares", "secret_share"],
+        fields,
+    );
+
+    let secret_share = object["secret_share"]
+        .as_str()
+        .expect("secret share is a string");
+    let secret_share = secret_share
+        .strip_prefix("0x")
+        .expect("secret share is 0x-prefixed");
+    assert_eq!(32, hex::decode(secret_share)?.len());
+
+    Ok(())
+}
@@ -25,7 +25,7 @@ pub async fn run_dkg_reshare_2_2_to_3_4(
 ) -> Result<()> {
     let n = 2;
     let t = 2;
-    let key_shares =
+    let (key_shares, _) =
         run_dkg(t, n, server, &server_public_key).await?;
 
     assert_eq!(n as usize, key_shares.len());
@@ -58,12 +58,85 @@ pub async fn run_dkg_reshare_2_2_to_3_4(
     Ok(())
 }
 
+pub async fn run_dkg_reshare_members_2_3_to_3_4(
+    server: &str,
+    server_public_key: Vec<u8>,
+) -> Result<()> {
+    let n = 3;
+    let t = 2;
+    let (key_shares, signers) =
+        run_dkg(t, n, server, &server_public_key).await?;
+
+    assert_eq!(n as usize, key_shares.len());
+
+    let account_verifying_key =
+        key_shares.first().unwrap().verifying_key().to_owned();
+
+    // First party hands off its share and leaves, the second
+    // party remains a holder and three new parties join
+    let (new_signers, _) = make_signers(3);
+    let mut session_signers = signers[0..2].to_vec();
+    session_signers.extend(new_signers.into_iter());
+
+    let session_verifiers = session_signers
+        .iter()
+        .map(|s| s.verifying_key().clone())
+        .collect::<Vec<_>>();
+    let old_holders = session_verifiers[0..2].to_vec();
+    let new_holders = session_verifiers[1..].to_vec();
+
+    let mut old_key_shares = key_shares[0..2]
+        .iter()
+        .cloned()
+        .map(Some)
+        .collect::<Vec<_>>();
+    old_key_shares.extend([None, None, None]);
+
+    let new_t = 3;
+    let new_n = 4;
+
+    let results = run_reshare_holders(
+        server,
+        &server_public_key,
+        account_verifying_key,
+        session_signers.clone(),
+        old_key_shares,
+        old_holders,
+        t as usize,
+        new_holders,
+        new_t,
+    )
+    .await?;
+
+    // Departing holder does not receive a key share
+    assert!(results[0].is_none());
+
+    let new_key_shares =
+        results.into_iter().flatten().collect::<Vec<_>>();
+    assert_eq!(new_n, new_key_shares.len());
+    for key_share in &new_key_shares {
+        assert_eq!(account_verifying_key, key_share.verifying_key());
+    }
+
+    run_sign(
+        new_t as u16,
+        new_n as u16,
+        server,
+        &server_public_key,
+        session_signers[1..].to_vec(),
+        new_key_shares,
+    )
+    .await?;
+
+    Ok(())
+}
+
 async fn run_dkg(
     t: u16,
     n: u16,
     server: &str,
     server_public_key: &[u8],
-) -> Result<Vec<KeyShare>> {
+) -> Result<(Vec<KeyShare>, Vec<SigningKey>)> {
     let params = Parameters {
         parties: n,
         threshold: t,
@@ -133,7 +206,7 @@ async fn run_dkg(
         key_shares.push(result?.into());
     }
 
-    Ok(key_shares)
+    Ok((key_shares, signers))
 }
 
 async fn run_reshare(
@@ -149,12 +222,54 @@ async fn run_reshare(
     let account_verifying_key =
         old_keys.first().unwrap().verifying_key().to_owned();
 
+    let (signers, verifiers) = make_signers(new_n as usize);
+
+    let key_shares = (0..new_n)
+        .map(|index| old_keys.get(index).cloned())
+        .collect::<Vec<_>>();
+
+    let results = run_reshare_holders(
+        server,
+        server_public_key,
+        account_verifying_key,
+        signers.clone(),
+        key_shares,
+        verifiers[..old_t].to_vec(),
+        old_t,
+        verifiers.clone(),
+        new_t,
+    )
+    .await?;
+
+    let key_shares = results
+        .into_iter()
+        .map(|key_share| key_share.unwrap())
+        .collect::<Vec<_>>();
+
+    Ok((key_shares, signers))
+}
+
+async fn run_reshare_holders(
+    server: &str,
+    server_public_key: &[u8],
+    account_verifying_key: VerifyingKey,
+    signers: Vec<SigningKey>,
+    key_shares: Vec<Option<KeyShare>>,
+    old_holders: Vec<VerifyingKey>,
+    old_t: usize,
+    new_holders: Vec<VerifyingKey>,
+    new_t: usize,
+) -> Result<Vec<Option<KeyShare>>> {
+    let n = signers.len();
     let params = Parameters {
-        parties: new_n as u16,
+        parties: n as u16,
         threshold: new_t as u16,
     };
 
-    let (signers, verifiers) = make_signers(new_n as usize);
+    let verifiers = signers
+        .iter()
+        .map(|s| s.verifying_key().clone())
+        .collect::<Vec<_>>();
     let server = ServerOptions {
         server_url: server.to_owned(),
         server_public_key: server_public_key.to_vec(),
@@ -169,7 +284,7 @@ async fn run_reshare(
     let mut public_keys = Vec::new();
     let mut keypairs = Vec::new();
 
-    for _ in 0..new_n {
+    for _ in 0..n {
         let keypair = Keypair::generate()?;
         keypairs.push(keypair.clone());
         public_keys.push(keypair.public_key().to_vec());
@@ -183,9 +298,10 @@ async fn run_reshare(
 
     let mut tasks = Vec::new();
 
-    for (index, (opts, signer)) in session_options
+    for (index, ((opts, signer), key_share)) in session_options
         .into_iter()
-        .zip(signers.clone().into_iter())
+        .zip(signers.into_iter())
+        .zip(key_shares.into_iter())
         .enumerate()
     {
         let participants =
@@ -200,9 +316,9 @@ async fn run_reshare(
             verifiers.clone(),
         )?;
 
-        let key_share = old_keys.get(index).cloned();
-
         let verifier = signer.verifying_key().clone();
+        let old_holders = old_holders.clone();
+        let new_holders = new_holders.clone();
         tasks.push(tokio::task::spawn(async move {
             let key_share = reshare(
                 opts,
@@ -210,7 +326,9 @@ async fn run_reshare(
                 keygen_session_id.clone(),
                 account_verifying_key.clone(),
                 key_share,
+                &old_holders,
                 old_t,
+                &new_holders,
                 new_t,
             )
             .await?;
@@ -225,7 +343,7 @@ async fn run_reshare(
         key_shares.push(result?);
     }
 
-    Ok((key_shares, signers))
+    Ok(key_shares)
 }
 
 async fn run_sign(
@@ -351,6 +469,7 @@ async fn run_sign(
                 participant,
                 sign_session_id.clone(),
                 &key_share,
+                None,
                 &message,
             )
             .await?;
@@ -34,6 +34,23 @@ async fn cggmp_driver_aux_info() -> Result<()> {
     Ok(())
 }
 
+/// CGGMP key generation rejects spoofed, misaddressed and
+/// replayed messages delivered to the client driver.
+#[tokio::test]
+async fn cggmp_bridge_verify_message() -> Result<()> {
+    // crate::test_utils::init_tracing();
+
+    let (rx, _handle) = spawn_server()?;
+    let addr = rx.await?;
+    let server = format!("ws://{}", addr);
+
+    let server_public_key = server_public_key().await?;
+    helpers::run_bridge_verify_message(&server, server_public_key)
+        .await?;
+
+    Ok(())
+}
+
 /// CGGMP threshold sign.
 ///
 /// This test re-uses the AuxInfo created during DKG for the signing
@@ -68,6 +85,26 @@ async fn cggmp_dkg_sign_2_3() -> Result<()> {
     Ok(())
 }
 
+/// CGGMP DKG followed by generating aux info once
+/// and signing multiple times with the stored aux info.
+#[tokio::test]
+async fn cggmp_dkg_stored_aux_info_sign_2_3() -> Result<()> {
+    // crate::test_utils::init_tracing();
+
+    let (rx, _handle) = spawn_server()?;
+    let addr = rx.await?;
+    let server = format!("ws://{}", addr);
+
+    let server_public_key = server_public_key().await?;
+    helpers::run_dkg_stored_aux_info_sign_2_3(
+        &server,
+        server_public_key,
+    )
+    .await?;
+
+    Ok(())
+}
+
 /// CGGMP DKG followed by signing (2-of-2).
 ///
 /// Note that this follows a different code path to the 2-of-3
@@ -86,6 +123,36 @@ async fn cggmp_dkg_sign_2_2() -> Result<()> {
     Ok(())
 }
 
+/// CGGMP trusted dealer key import followed by signing (2-of-3).
+#[tokio::test]
+async fn cggmp_import_sign_2_3() -> Result<()> {
+    // crate::test_utils::init_tracing();
+
+    let (rx, _handle) = spawn_server()?;
+    let addr = rx.await?;
+    let server = format!("ws://{}", addr);
+
+    let server_public_key = server_public_key().await?;
+    helpers::run_import_sign_2_3(&server, server_public_key).await?;
+
+    Ok(())
+}
+
+/// CGGMP trusted dealer key import followed by reconstructing
+/// the signing key.
+#[cfg(feature = "reconstruct")]
+#[test]
+fn cggmp_import_reconstruct_2_3() -> Result<()> {
+    helpers::run_import_reconstruct_2_3()
+}
+
+/// CGGMP key share serialization expected by reconstruction.
+#[cfg(feature = "reconstruct")]
+#[test]
+fn cggmp_reconstruct_key_share_format() -> Result<()> {
+    helpers::run_reconstruct_key_share_format()
+}
+
 /// CGGMP DKG followed by resharing and signing.
 #[tokio::test]
 async fn cggmp_dkg_reshare_2_2_to_3_4() -> Result<()> {
@@ -102,6 +169,26 @@ async fn cggmp_dkg_reshare_2_2_to_3_4() -> Result<()> {
     Ok(())
 }
 
+/// CGGMP DKG followed by resharing to a new set of parties
+/// where a holder leaves and new parties join, then signing.
+#[tokio::test]
+async fn cggmp_dkg_reshare_members_2_3_to_3_4() -> Result<()> {
+    // crate::test_utils::init_tracing();
+
+    let (rx, _handle) = spawn_server()?;
+    let addr = rx.await?;
+    let server = format!("ws://{}", addr);
+
+    let server_public_key = server_public_key().await?;
+    helpers::run_dkg_reshare_members_2_3_to_3_4(
+        &server,
+        server_public_key,
+    )
+    .await?;
+
+    Ok(())
+}
+
 /// CGGMP DKG followed by signing (2-of-2) using
 /// derived child keys (BIP32).
 #[tokio::test]
@@ -117,3 +204,10 @@ async fn cggmp_dkg_derived_2_2() -> Result<()> {
 
     Ok(())
 }
+
+/// CGGMP abort identifies the party that sent a corrupted
+/// round message and the round it was detected in.
+#[test]
+fn cggmp_corrupted_message_abort() -> Result<()> {
+    helpers::run_corrupted_message_abort()
+}
@@ -0,0 +1,135 @@
+macro_rules! frost_coordinator_sign {
+    () => {
+        pub async fn run_dkg_coordinator_sign_2_3(
+            server: &str,
+            server_public_key: Vec<u8>,
+        ) -> Result<()> {
+            let n = 3;
+            let t = 2;
+            let identifiers: Vec<Identifier> =
+                (1..=n).map(|i| i.try_into().unwrap()).collect();
+
+            let (server, key_shares, signers) = run_dkg(
+                t,
+                n,
+                server,
+                server_public_key,
+                identifiers,
+            )
+            .await?;
+
+            // Coordinator does not hold a key share
+            let (mut coordinator, _) = make_signers(1);
+            let coordinator = coordinator.remove(0);
+            let public_key_package = key_shares[0].1.clone();
+
+            let indices = [0, 2];
+            let mut roles = vec![Role::Coordinator];
+            let mut session_signers = vec![coordinator];
+            let mut session_key_shares = vec![None];
+            for index in indices {
+                roles.push(Role::Participant(
+                    key_shares[index].0.identifier().clone(),
+                ));
+                session_signers.push(signers[index].clone());
+                session_key_shares
+                    .push(Some(key_shares[index].clone()));
+            }
+            let verifiers = session_signers
+                .iter()
+                .map(|s| s.verifying_key().clone())
+                .collect::<Vec<_>>();
+
+            let params = Parameters {
+                parties: n,
+                threshold: t,
+            };
+            let message = make_signing_message();
+
+            let mut keypairs = Vec::new();
+            for _ in 0..roles.len() {
+                keypairs.push(Keypair::generate()?);
+            }
+            let public_keys = keypairs
+                .iter()
+                .map(|k| k.public_key().to_vec())
+                .collect::<Vec<_>>();
+
+            let mut tasks = Vec::new();
+            for (index, ((keypair, signer), key_share)) in keypairs
+                .into_iter()
+                .zip(session_signers.into_iter())
+                .zip(session_key_shares.into_iter())
+                .enumerate()
+            {
+                let opts = SessionOptions {
+                    keypair,
+                    parameters: params.clone(),
+                    server: server.clone(),
+                };
+                let participants = public_keys.clone();
+                let public_key =
+                    participants.get(index).unwrap().to_vec();
+                // Coordinator must be the session initiator
+                let party = PartyOptions::new(
+                    public_key,
+                    participants,
+                    index == 0,
+                    verifiers.clone(),
+                )?;
+
+                let verifier = signer.verifying_key().clone();
+                let participant =
+                    Participant::new(signer, verifier, party)?;
+                let roles = roles.clone();
+                let msg = message.clone();
+                let public_key_package = public_key_package.clone();
+                tasks.push(tokio::task::spawn(async move {
+                    if let Some(key_share) = key_share {
+                        sign_coordinated(
+                            opts,
+                            participant,
+                            roles,
+                            key_share,
+                            msg,
+                        )
+                        .await?;
+                        Ok::<_, anyhow::Error>(None)
+                    } else {
+                        let signature = coordinate(
+                            opts,
+                            participant,
+                            roles,
+                            public_key_package,
+                            msg,
+                        )
+                        .await?;
+                        Ok::<_, anyhow::Error>(Some(signature))
+                    }
+                }));
+            }
+
+            let results =
+                futures::future::try_join_all(tasks).await?;
+            let mut signatures = Vec::new();
+            for result in results {
+                if let Some(signature) = result? {
+                    signatures.push(signature);
+                }
+            }
+
+            // Only the coordinator assembles the signature
+            assert_eq!(1, signatures.len());
+            for key_share in &key_shares {
+                key_share
+                    .1
+                    .verifying_key()
+                    .verify(&message, &signatures[0])?;
+            }
+
+            Ok(())
+        }
+    };
+}
+
+pub(crate) use frost_coordinator_sign;
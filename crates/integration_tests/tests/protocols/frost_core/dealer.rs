@@ -0,0 +1,58 @@
+macro_rules! frost_import_sign {
+    () => {
+        pub async fn run_import_sign_2_3(
+            server: &str,
+            server_public_key: Vec<u8>,
+        ) -> Result<()> {
+            let n = 3;
+            let t = 2;
+            let identifiers: Vec<Identifier> =
+                (1..=n).map(|i| i.try_into().unwrap()).collect();
+
+            let server = ServerOptions {
+                server_url: server.to_owned(),
+                server_public_key,
+                pattern: None,
+            };
+
+            // Existing single-party key
+            let signing_key = Signer::random();
+            let verifying_key = signing_key.verifying_key().clone();
+
+            let encoded = import_key(&signing_key, &identifiers, t)?;
+            assert_eq!(n as usize, encoded.len());
+
+            let mut key_shares = Vec::new();
+            for (id, encoded) in
+                identifiers.iter().zip(encoded.iter())
+            {
+                let key_share: KeyShare = encoded.try_into()?;
+                assert_eq!(id, key_share.0.identifier());
+                verify_key_share(&key_share, &verifying_key)?;
+                key_shares.push(key_share);
+            }
+
+            // Key share for a different key must not verify
+            let other_key = Signer::random();
+            assert!(verify_key_share(
+                &key_shares[0],
+                &other_key.verifying_key()
+            )
+            .is_err());
+
+            let (signers, _) = super::make_signers(n as usize);
+            let selected = SelectedSigners::new(
+                t,
+                &[0, 2],
+                signers,
+                key_shares.clone(),
+            )?;
+
+            check_sign(t, n, server, key_shares, selected).await?;
+
+            Ok(())
+        }
+    };
+}
+
+pub(crate) use frost_import_sign;
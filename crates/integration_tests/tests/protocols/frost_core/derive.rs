@@ -0,0 +1,76 @@
+macro_rules! frost_derive_sign {
+    () => {
+        pub async fn run_dkg_derive_sign_2_3(
+            server: &str,
+            server_public_key: Vec<u8>,
+        ) -> Result<()> {
+            let n = 3;
+            let t = 2;
+            let identifiers: Vec<Identifier> =
+                (1..=n).map(|i| i.try_into().unwrap()).collect();
+
+            let (server, key_shares, signers) = run_dkg(
+                t,
+                n,
+                server,
+                server_public_key,
+                identifiers,
+            )
+            .await?;
+
+            let chain_code: ChainCode = [7; 32];
+            let path = [0, 1, 42];
+
+            // Watch-only derivation from the public key package
+            let public_key_package = derive_public_key_package(
+                &key_shares[0].1,
+                &chain_code,
+                &path,
+            )?;
+            assert_ne!(
+                key_shares[0].1.verifying_key(),
+                public_key_package.verifying_key()
+            );
+
+            let mut derived_key_shares = Vec::new();
+            for key_share in &key_shares {
+                let derived =
+                    derive_key_share(key_share, &chain_code, &path)?;
+                assert_eq!(
+                    public_key_package.verifying_key(),
+                    derived.0.verifying_key()
+                );
+                assert_eq!(&public_key_package, &derived.1);
+                assert_eq!(
+                    public_key_package
+                        .verifying_shares()
+                        .get(derived.0.identifier()),
+                    Some(derived.0.verifying_share())
+                );
+                derived_key_shares.push(derived);
+            }
+
+            // Hardened derivation needs the secret key
+            assert!(derive_public_key_package(
+                &key_shares[0].1,
+                &chain_code,
+                &[1 << 31],
+            )
+            .is_err());
+
+            let selected = SelectedSigners::new(
+                t,
+                &[0, 2],
+                signers,
+                derived_key_shares.clone(),
+            )?;
+
+            check_sign(t, n, server, derived_key_shares, selected)
+                .await?;
+
+            Ok(())
+        }
+    };
+}
+
+pub(crate) use frost_derive_sign;
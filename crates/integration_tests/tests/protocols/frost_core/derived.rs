@@ -0,0 +1,192 @@
+macro_rules! frost_dkg_derived_sign {
+    () => {
+        pub async fn run_dkg_derived_sign_2_3(
+            server: &str,
+            server_public_key: Vec<u8>,
+        ) -> Result<()> {
+            let n = 3;
+            let t = 2;
+
+            let (server, key_shares, signers) =
+                run_dkg_derived(t, n, server, server_public_key)
+                    .await?;
+
+            // Any subset of signers can sign without
+            // knowing the key share identifiers
+            sign_derived_subset(
+                t,
+                n,
+                server,
+                &[1, 2],
+                signers,
+                key_shares,
+            )
+            .await
+        }
+
+        async fn run_dkg_derived(
+            t: u16,
+            n: u16,
+            server: &str,
+            server_public_key: Vec<u8>,
+        ) -> Result<(ServerOptions, Vec<KeyShare>, Vec<SigningKey>)> {
+            let params = Parameters {
+                parties: n,
+                threshold: t,
+            };
+
+            let (signers, verifiers) = make_signers(n as usize);
+            let server = ServerOptions {
+                server_url: server.to_owned(),
+                server_public_key,
+                pattern: None,
+            };
+
+            let mut keypairs = Vec::new();
+            for _ in 0..n {
+                keypairs.push(Keypair::generate()?);
+            }
+            let public_keys = keypairs
+                .iter()
+                .map(|k| k.public_key().to_vec())
+                .collect::<Vec<_>>();
+
+            let mut tasks = Vec::new();
+            for (index, (keypair, signer)) in keypairs
+                .into_iter()
+                .zip(signers.clone().into_iter())
+                .enumerate()
+            {
+                let opts = SessionOptions {
+                    keypair,
+                    parameters: params.clone(),
+                    server: server.clone(),
+                };
+                let participants = public_keys.clone();
+                let public_key =
+                    participants.get(index).unwrap().to_vec();
+                let party = PartyOptions::new(
+                    public_key,
+                    participants,
+                    index == 0,
+                    verifiers.clone(),
+                )?;
+
+                let verifier = signer.verifying_key().clone();
+                tasks.push(tokio::task::spawn(async move {
+                    let key_share = dkg_derived(
+                        opts,
+                        Participant::new(signer, verifier, party)?,
+                    )
+                    .await?;
+                    Ok::<_, anyhow::Error>(key_share)
+                }));
+            }
+
+            let mut key_shares = Vec::new();
+            let results =
+                futures::future::try_join_all(tasks).await?;
+            for result in results {
+                key_shares.push(result?);
+            }
+
+            // Identifiers are derived from the verifying keys
+            let identifiers = derive_identifiers(&verifiers)?;
+            for (key_share, id) in
+                key_shares.iter().zip(identifiers.iter())
+            {
+                assert_eq!(id, key_share.0.identifier());
+            }
+
+            Ok((server, key_shares, signers))
+        }
+
+        async fn sign_derived_subset(
+            t: u16,
+            n: u16,
+            server: ServerOptions,
+            indices: &[usize],
+            signers: Vec<SigningKey>,
+            key_shares: Vec<KeyShare>,
+        ) -> Result<()> {
+            let params = Parameters {
+                parties: n,
+                threshold: t,
+            };
+            let message = make_signing_message();
+
+            let selected_signers = indices
+                .iter()
+                .map(|i| signers[*i].clone())
+                .collect::<Vec<_>>();
+            let selected_verifiers = selected_signers
+                .iter()
+                .map(|s| s.verifying_key().clone())
+                .collect::<Vec<_>>();
+
+            let mut keypairs = Vec::new();
+            for _ in 0..t {
+                keypairs.push(Keypair::generate()?);
+            }
+            let public_keys = keypairs
+                .iter()
+                .map(|k| k.public_key().to_vec())
+                .collect::<Vec<_>>();
+
+            let mut tasks = Vec::new();
+            for (index, (keypair, signer)) in keypairs
+                .into_iter()
+                .zip(selected_signers.into_iter())
+                .enumerate()
+            {
+                let opts = SessionOptions {
+                    keypair,
+                    parameters: params.clone(),
+                    server: server.clone(),
+                };
+                let participants = public_keys.clone();
+                let public_key =
+                    participants.get(index).unwrap().to_vec();
+                let party = PartyOptions::new(
+                    public_key,
+                    participants,
+                    index == 0,
+                    selected_verifiers.clone(),
+                )?;
+
+                let verifier = signer.verifying_key().clone();
+                let participant =
+                    Participant::new(signer, verifier, party)?;
+                let key_share = key_shares[indices[index]].clone();
+                let msg = message.clone();
+                tasks.push(tokio::task::spawn(async move {
+                    let signature = sign_derived(
+                        opts,
+                        participant,
+                        key_share,
+                        msg,
+                    )
+                    .await?;
+                    Ok::<_, anyhow::Error>(signature)
+                }));
+            }
+
+            let results =
+                futures::future::try_join_all(tasks).await?;
+            assert_eq!(t as usize, results.len());
+            for result in results {
+                let signature = result?;
+                for key_share in &key_shares {
+                    key_share
+                        .1
+                        .verifying_key()
+                        .verify(&message, &signature)?;
+                }
+            }
+
+            Ok(())
+        }
+    };
+}
+
+pub(crate) use frost_dkg_derived_sign;
@@ -1,4 +1,14 @@
+pub(crate) mod coordinator;
+pub(crate) mod dealer;
+pub(crate) mod derive;
+pub(crate) mod derived;
 pub(crate) mod dkg;
+pub(crate) mod preprocess;
+#[cfg(feature = "reconstruct")]
+pub(crate) mod reconstruct;
+pub(crate) mod refresh;
+pub(crate) mod repair;
+pub(crate) mod reshare;
 pub(crate) mod sign;
 
 pub fn make_signing_message() -> Vec<u8> {
@@ -0,0 +1,229 @@
+macro_rules! frost_preprocess_sign {
+    () => {
+        pub async fn run_dkg_preprocess_sign_2_3(
+            server: &str,
+            server_public_key: Vec<u8>,
+        ) -> Result<()> {
+            let n = 3;
+            let t = 2;
+            let batch_size = 2;
+            let identifiers: Vec<Identifier> =
+                (1..=n).map(|i| i.try_into().unwrap()).collect();
+
+            let (server, key_shares, signers) = run_dkg(
+                t,
+                n,
+                server,
+                server_public_key,
+                identifiers.clone(),
+            )
+            .await?;
+
+            let mut stores = run_preprocess(
+                t,
+                n,
+                &server,
+                identifiers,
+                &signers,
+                &key_shares,
+                batch_size,
+            )
+            .await?;
+
+            for store in &stores {
+                assert_eq!(batch_size as usize, store.len());
+            }
+
+            // Each batch index signs a different message
+            for index in 0..batch_size {
+                sign_preprocessed_subset(
+                    t,
+                    n,
+                    &server,
+                    &[0, 2],
+                    &signers,
+                    &key_shares,
+                    &mut stores,
+                    index,
+                )
+                .await?;
+            }
+
+            // Nonces can only be used once
+            for index in 0..batch_size {
+                assert!(stores[0].take(index).is_err());
+                assert!(stores[2].take(index).is_err());
+            }
+
+            Ok(())
+        }
+
+        async fn run_preprocess(
+            t: u16,
+            n: u16,
+            server: &ServerOptions,
+            identifiers: Vec<Identifier>,
+            signers: &[SigningKey],
+            key_shares: &[KeyShare],
+            batch_size: u32,
+        ) -> Result<Vec<NonceStore>> {
+            let params = Parameters {
+                parties: n,
+                threshold: t,
+            };
+            let verifiers = signers
+                .iter()
+                .map(|s| s.verifying_key().clone())
+                .collect::<Vec<_>>();
+
+            let mut keypairs = Vec::new();
+            for _ in 0..n {
+                keypairs.push(Keypair::generate()?);
+            }
+            let public_keys = keypairs
+                .iter()
+                .map(|k| k.public_key().to_vec())
+                .collect::<Vec<_>>();
+
+            let mut tasks = Vec::new();
+            for (index, ((keypair, signer), key_share)) in keypairs
+                .into_iter()
+                .zip(signers.iter().cloned())
+                .zip(key_shares.iter().cloned())
+                .enumerate()
+            {
+                let opts = SessionOptions {
+                    keypair,
+                    parameters: params.clone(),
+                    server: server.clone(),
+                };
+                let participants = public_keys.clone();
+                let public_key =
+                    participants.get(index).unwrap().to_vec();
+                let party = PartyOptions::new(
+                    public_key,
+                    participants,
+                    index == 0,
+                    verifiers.clone(),
+                )?;
+
+                let verifier = signer.verifying_key().clone();
+                let participant =
+                    Participant::new(signer, verifier, party)?;
+                let ids = identifiers.clone();
+                tasks.push(tokio::task::spawn(async move {
+                    let store = preprocess(
+                        opts,
+                        participant,
+                        ids,
+                        key_share,
+                        batch_size,
+                    )
+                    .await?;
+                    Ok::<_, anyhow::Error>(store)
+                }));
+            }
+
+            let mut stores = Vec::new();
+            let results =
+                futures::future::try_join_all(tasks).await?;
+            for result in results {
+                stores.push(result?);
+            }
+            Ok(stores)
+        }
+
+        async fn sign_preprocessed_subset(
+            t: u16,
+            n: u16,
+            server: &ServerOptions,
+            indices: &[usize],
+            signers: &[SigningKey],
+            key_shares: &[KeyShare],
+            stores: &mut [NonceStore],
+            nonce_index: u32,
+        ) -> Result<()> {
+            let params = Parameters {
+                parties: n,
+                threshold: t,
+            };
+            let mut message = make_signing_message();
+            message.extend_from_slice(&nonce_index.to_be_bytes());
+
+            let selected_verifiers = indices
+                .iter()
+                .map(|i| signers[*i].verifying_key().clone())
+                .collect::<Vec<_>>();
+            let identifiers = indices
+                .iter()
+                .map(|i| key_shares[*i].0.identifier().clone())
+                .collect::<Vec<_>>();
+
+            let mut keypairs = Vec::new();
+            for _ in 0..t {
+                keypairs.push(Keypair::generate()?);
+            }
+            let public_keys = keypairs
+                .iter()
+                .map(|k| k.public_key().to_vec())
+                .collect::<Vec<_>>();
+
+            let mut tasks = Vec::new();
+            for (index, keypair) in keypairs.into_iter().enumerate() {
+                let opts = SessionOptions {
+                    keypair,
+                    parameters: params.clone(),
+                    server: server.clone(),
+                };
+                let participants = public_keys.clone();
+                let public_key =
+                    participants.get(index).unwrap().to_vec();
+                let party = PartyOptions::new(
+                    public_key,
+                    participants,
+                    index == 0,
+                    selected_verifiers.clone(),
+                )?;
+
+                let signer = signers[indices[index]].clone();
+                let verifier = signer.verifying_key().clone();
+                let participant =
+                    Participant::new(signer, verifier, party)?;
+                let key_share = key_shares[indices[index]].clone();
+                let nonces =
+                    stores[indices[index]].take(nonce_index)?;
+                let ids = identifiers.clone();
+                let msg = message.clone();
+                tasks.push(tokio::task::spawn(async move {
+                    let signature = sign_preprocessed(
+                        opts,
+                        participant,
+                        ids,
+                        key_share,
+                        nonces,
+                        msg,
+                    )
+                    .await?;
+                    Ok::<_, anyhow::Error>(signature)
+                }));
+            }
+
+            let results =
+                futures::future::try_join_all(tasks).await?;
+            assert_eq!(t as usize, results.len());
+            for result in results {
+                let signature = result?;
+                for key_share in key_shares {
+                    key_share
+                        .1
+                        .verifying_key()
+                        .verify(&message, &signature)?;
+                }
+            }
+
+            Ok(())
+        }
+    };
+}
+
+pub(crate) use frost_preprocess_sign;
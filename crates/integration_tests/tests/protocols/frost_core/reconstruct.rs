@@ -0,0 +1,38 @@
+macro_rules! frost_dkg_reconstruct {
+    () => {
+        pub async fn run_dkg_reconstruct_2_3(
+            server: &str,
+            server_public_key: Vec<u8>,
+        ) -> Result<()> {
+            let n = 3;
+            let t = 2;
+            let identifiers: Vec<Identifier> =
+                (1..=n).map(|i| i.try_into().unwrap()).collect();
+
+            let (_, key_shares, _) = run_dkg(
+                t,
+                n,
+                server,
+                server_public_key,
+                identifiers,
+            )
+            .await?;
+
+            // Reconstructed key is verified against the
+            // group verifying key
+            reconstruct_key(&key_shares[0..2])?;
+            reconstruct_key(&key_shares[1..3])?;
+            reconstruct_key(&key_shares)?;
+
+            // Refuse with fewer than threshold key shares
+            assert!(matches!(
+                reconstruct_key(&key_shares[0..1]),
+                Err(Error::ReconstructThreshold(2, 1))
+            ));
+
+            Ok(())
+        }
+    };
+}
+
+pub(crate) use frost_dkg_reconstruct;
@@ -0,0 +1,143 @@
+macro_rules! frost_dkg_refresh_sign {
+    () => {
+        pub async fn run_dkg_refresh_sign_2_3(
+            server: &str,
+            server_public_key: Vec<u8>,
+        ) -> Result<()> {
+            let n = 3;
+            let t = 2;
+            let identifiers: Vec<Identifier> =
+                (1..=n).map(|i| i.try_into().unwrap()).collect();
+
+            let (server, key_shares, signers) = run_dkg(
+                t,
+                n,
+                server,
+                server_public_key,
+                identifiers.clone(),
+            )
+            .await?;
+
+            let refreshed_key_shares = run_refresh(
+                t,
+                n,
+                server.clone(),
+                identifiers,
+                key_shares.clone(),
+                signers.clone(),
+            )
+            .await?;
+
+            assert_eq!(n as usize, refreshed_key_shares.len());
+            for (key_share, refreshed) in
+                key_shares.iter().zip(refreshed_key_shares.iter())
+            {
+                // Group verifying key must not change
+                assert_eq!(
+                    key_share.1.verifying_key(),
+                    refreshed.1.verifying_key()
+                );
+                assert_eq!(
+                    key_share.0.identifier(),
+                    refreshed.0.identifier()
+                );
+                // Secret share must have changed
+                assert_ne!(
+                    key_share.0.signing_share(),
+                    refreshed.0.signing_share()
+                );
+            }
+
+            let selected = SelectedSigners::new(
+                t,
+                &[0, 2],
+                signers,
+                refreshed_key_shares.clone(),
+            )?;
+
+            check_sign(t, n, server, refreshed_key_shares, selected)
+                .await?;
+
+            Ok(())
+        }
+
+        async fn run_refresh(
+            t: u16,
+            n: u16,
+            server: ServerOptions,
+            identifiers: Vec<Identifier>,
+            key_shares: Vec<KeyShare>,
+            signers: Vec<SigningKey>,
+        ) -> Result<Vec<KeyShare>> {
+            let params = Parameters {
+                parties: n,
+                threshold: t,
+            };
+
+            let verifiers = signers
+                .iter()
+                .map(|s| s.verifying_key().clone())
+                .collect::<Vec<_>>();
+
+            let mut session_options = Vec::new();
+            let mut public_keys = Vec::new();
+
+            for _ in 0..n {
+                let keypair = Keypair::generate()?;
+                public_keys.push(keypair.public_key().to_vec());
+
+                session_options.push(SessionOptions {
+                    keypair,
+                    parameters: params.clone(),
+                    server: server.clone(),
+                });
+            }
+
+            let mut tasks = Vec::new();
+            for (index, ((opts, signer), key_share)) in
+                session_options
+                    .into_iter()
+                    .zip(signers.into_iter())
+                    .zip(key_shares.into_iter())
+                    .enumerate()
+            {
+                let participants =
+                    public_keys.iter().cloned().collect::<Vec<_>>();
+                let is_initiator = index == 0;
+                let public_key =
+                    participants.get(index).unwrap().to_vec();
+
+                let party = PartyOptions::new(
+                    public_key,
+                    participants,
+                    is_initiator,
+                    verifiers.clone(),
+                )?;
+
+                let verifier = signer.verifying_key().clone();
+                let ids = identifiers.clone();
+                tasks.push(tokio::task::spawn(async move {
+                    let key_share = refresh(
+                        opts,
+                        Participant::new(signer, verifier, party)?,
+                        ids,
+                        key_share,
+                    )
+                    .await?;
+                    Ok::<_, anyhow::Error>(key_share)
+                }));
+            }
+
+            let mut key_shares = Vec::new();
+            let results =
+                futures::future::try_join_all(tasks).await?;
+            for result in results {
+                key_shares.push(result?);
+            }
+
+            Ok(key_shares)
+        }
+    };
+}
+
+pub(crate) use frost_dkg_refresh_sign;
@@ -0,0 +1,159 @@
+macro_rules! frost_dkg_repair_sign {
+    () => {
+        pub async fn run_dkg_repair_sign_2_3(
+            server: &str,
+            server_public_key: Vec<u8>,
+        ) -> Result<()> {
+            let n = 3;
+            let t = 2;
+            let identifiers: Vec<Identifier> =
+                (1..=n).map(|i| i.try_into().unwrap()).collect();
+
+            let (server, key_shares, signers) = run_dkg(
+                t,
+                n,
+                server,
+                server_public_key,
+                identifiers.clone(),
+            )
+            .await?;
+
+            // Second participant has lost their key share
+            let lost_index = 1;
+            let lost = identifiers[lost_index];
+            let mut partial_key_shares = key_shares
+                .iter()
+                .cloned()
+                .map(Some)
+                .collect::<Vec<_>>();
+            partial_key_shares[lost_index] = None;
+
+            let repaired_key_shares = run_repair(
+                t,
+                n,
+                server.clone(),
+                identifiers,
+                lost,
+                partial_key_shares,
+                signers.clone(),
+            )
+            .await?;
+
+            assert_eq!(n as usize, repaired_key_shares.len());
+
+            // Repaired key share must be usable with the PEM encoding
+            let repaired = &repaired_key_shares[lost_index];
+            let encoded: polysig_driver::KeyShare =
+                repaired.try_into()?;
+            let repaired: KeyShare = (&encoded).try_into()?;
+
+            let original = &key_shares[lost_index];
+            assert_eq!(
+                original.0.signing_share(),
+                repaired.0.signing_share()
+            );
+            assert_eq!(
+                original.0.verifying_share(),
+                repaired.0.verifying_share()
+            );
+            assert_eq!(
+                original.1.verifying_key(),
+                repaired.1.verifying_key()
+            );
+
+            // Sign using the repaired key share
+            let selected = SelectedSigners::new(
+                t,
+                &[0, lost_index],
+                signers,
+                repaired_key_shares.clone(),
+            )?;
+
+            check_sign(t, n, server, repaired_key_shares, selected)
+                .await?;
+
+            Ok(())
+        }
+
+        async fn run_repair(
+            t: u16,
+            n: u16,
+            server: ServerOptions,
+            identifiers: Vec<Identifier>,
+            lost: Identifier,
+            key_shares: Vec<Option<KeyShare>>,
+            signers: Vec<SigningKey>,
+        ) -> Result<Vec<KeyShare>> {
+            let params = Parameters {
+                parties: n,
+                threshold: t,
+            };
+
+            let verifiers = signers
+                .iter()
+                .map(|s| s.verifying_key().clone())
+                .collect::<Vec<_>>();
+
+            let mut session_options = Vec::new();
+            let mut public_keys = Vec::new();
+
+            for _ in 0..n {
+                let keypair = Keypair::generate()?;
+                public_keys.push(keypair.public_key().to_vec());
+
+                session_options.push(SessionOptions {
+                    keypair,
+                    parameters: params.clone(),
+                    server: server.clone(),
+                });
+            }
+
+            let mut tasks = Vec::new();
+            for (index, ((opts, signer), key_share)) in
+                session_options
+                    .into_iter()
+                    .zip(signers.into_iter())
+                    .zip(key_shares.into_iter())
+                    .enumerate()
+            {
+                let participants =
+                    public_keys.iter().cloned().collect::<Vec<_>>();
+                let is_initiator = index == 0;
+                let public_key =
+                    participants.get(index).unwrap().to_vec();
+
+                let party = PartyOptions::new(
+                    public_key,
+                    participants,
+                    is_initiator,
+                    verifiers.clone(),
+                )?;
+
+                let verifier = signer.verifying_key().clone();
+                let ids = identifiers.clone();
+                tasks.push(tokio::task::spawn(async move {
+                    let key_share = repair(
+                        opts,
+                        Participant::new(signer, verifier, party)?,
+                        ids,
+                        lost,
+                        key_share,
+                    )
+                    .await?;
+                    Ok::<_, anyhow::Error>(key_share)
+                }));
+            }
+
+            let mut key_shares = Vec::new();
+            let results =
+                futures::future::try_join_all(tasks).await?;
+            for result in results {
+                key_shares.push(result?);
+            }
+
+            Ok(key_shares)
+        }
+    };
+}
+
+pub(crate) use frost_dkg_repair_sign;
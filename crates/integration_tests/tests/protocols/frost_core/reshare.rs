@@ -0,0 +1,181 @@
+macro_rules! frost_dkg_reshare_sign {
+    () => {
+        pub async fn run_dkg_reshare_sign_2_3_to_3_4(
+            server: &str,
+            server_public_key: Vec<u8>,
+        ) -> Result<()> {
+            let n = 3;
+            let t = 2;
+            let identifiers: Vec<Identifier> =
+                (1..=n).map(|i| i.try_into().unwrap()).collect();
+
+            let (server, key_shares, signers) = run_dkg(
+                t,
+                n,
+                server,
+                server_public_key,
+                identifiers.clone(),
+            )
+            .await?;
+
+            let verifying_key =
+                key_shares.first().unwrap().1.verifying_key().clone();
+
+            // First two participants keep their identifiers, the
+            // third participant leaves and two new participants
+            // join with a new threshold
+            let new_n: u16 = 4;
+            let new_t: u16 = 3;
+            let new_ids: Vec<Identifier> = [4u16, 5]
+                .into_iter()
+                .map(|i| i.try_into().unwrap())
+                .collect();
+            let parties = vec![
+                (Some(identifiers[0]), Some(identifiers[0])),
+                (Some(identifiers[1]), Some(identifiers[1])),
+                (Some(identifiers[2]), None),
+                (None, Some(new_ids[0])),
+                (None, Some(new_ids[1])),
+            ];
+
+            let (new_signers, _) = super::make_signers(2);
+            let mut session_signers = signers.clone();
+            session_signers.extend(new_signers.iter().cloned());
+
+            let mut old_key_shares = key_shares
+                .iter()
+                .cloned()
+                .map(Some)
+                .collect::<Vec<_>>();
+            old_key_shares.push(None);
+            old_key_shares.push(None);
+
+            let results = run_reshare(
+                server.clone(),
+                parties,
+                t,
+                new_t,
+                verifying_key,
+                old_key_shares,
+                session_signers,
+            )
+            .await?;
+
+            // Participant that left does not receive a key share
+            assert!(results[2].is_none());
+
+            let new_key_shares = results
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>();
+            assert_eq!(new_n as usize, new_key_shares.len());
+
+            for key_share in &new_key_shares {
+                assert_eq!(
+                    &verifying_key,
+                    key_share.1.verifying_key()
+                );
+                assert_eq!(new_t, *key_share.0.min_signers());
+            }
+
+            // Sign with the new threshold using a new participant
+            let mut new_signers_all = signers[0..2].to_vec();
+            new_signers_all.extend(new_signers.into_iter());
+            let selected = SelectedSigners::new(
+                new_t,
+                &[0, 2, 3],
+                new_signers_all,
+                new_key_shares.clone(),
+            )?;
+
+            check_sign(new_t, new_n, server, new_key_shares, selected)
+                .await?;
+
+            Ok(())
+        }
+
+        async fn run_reshare(
+            server: ServerOptions,
+            parties: Vec<(Option<Identifier>, Option<Identifier>)>,
+            old_threshold: u16,
+            new_threshold: u16,
+            verifying_key: GroupVerifyingKey,
+            key_shares: Vec<Option<KeyShare>>,
+            signers: Vec<SigningKey>,
+        ) -> Result<Vec<Option<KeyShare>>> {
+            let n = parties.len() as u16;
+            let params = Parameters {
+                parties: n,
+                threshold: new_threshold,
+            };
+
+            let verifiers = signers
+                .iter()
+                .map(|s| s.verifying_key().clone())
+                .collect::<Vec<_>>();
+
+            let mut session_options = Vec::new();
+            let mut public_keys = Vec::new();
+
+            for _ in 0..n {
+                let keypair = Keypair::generate()?;
+                public_keys.push(keypair.public_key().to_vec());
+
+                session_options.push(SessionOptions {
+                    keypair,
+                    parameters: params.clone(),
+                    server: server.clone(),
+                });
+            }
+
+            let mut tasks = Vec::new();
+            for (index, ((opts, signer), key_share)) in
+                session_options
+                    .into_iter()
+                    .zip(signers.into_iter())
+                    .zip(key_shares.into_iter())
+                    .enumerate()
+            {
+                let participants =
+                    public_keys.iter().cloned().collect::<Vec<_>>();
+                let is_initiator = index == 0;
+                let public_key =
+                    participants.get(index).unwrap().to_vec();
+
+                let party = PartyOptions::new(
+                    public_key,
+                    participants,
+                    is_initiator,
+                    verifiers.clone(),
+                )?;
+
+                let verifier = signer.verifying_key().clone();
+                let parties = parties.clone();
+                tasks.push(tokio::task::spawn(async move {
+                    let key_share = reshare(
+                        opts,
+                        Participant::new(signer, verifier, party)?,
+                        parties,
+                        old_threshold,
+                        new_threshold,
+                        verifying_key,
+                        key_share,
+                    )
+                    .await?;
+                    Ok::<_, anyhow::Error>(key_share)
+                }));
+            }
+
+            let mut key_shares = Vec::new();
+            let results =
+                futures::future::try_join_all(tasks).await?;
+            for result in results {
+                key_shares.push(result?);
+            }
+
+            Ok(key_shares)
+        }
+    };
+}
+
+pub(crate) use frost_dkg_reshare_sign;
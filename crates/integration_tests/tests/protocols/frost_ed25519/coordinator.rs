@@ -0,0 +1,17 @@
+use super::{dkg::run_dkg, make_signers};
+use anyhow::Result;
+use polysig_client::{
+    frost::ed25519::{coordinate, sign_coordinated},
+    SessionOptions,
+};
+use polysig_driver::frost::ed25519::{
+    Identifier, Participant, PartyOptions,
+};
+use polysig_driver::frost::Role;
+use polysig_protocol::{Keypair, Parameters};
+
+use crate::protocols::frost_core::{
+    coordinator::frost_coordinator_sign, make_signing_message,
+};
+
+frost_coordinator_sign!();
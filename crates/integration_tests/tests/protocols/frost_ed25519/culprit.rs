@@ -0,0 +1,101 @@
+use anyhow::Result;
+use ed25519_dalek::SigningKey;
+use polysig_driver::{
+    frost::{
+        ed25519::{import_key, KeyShare, SignatureDriver},
+        Culprit, Error,
+    },
+    frost_ed25519::{keys::KeyPackage, Identifier},
+    ProtocolDriver, Round,
+};
+use polysig_protocol::SessionId;
+use rand::rngs::OsRng;
+use std::num::NonZeroU16;
+
+use super::make_signers;
+
+pub fn import_shares(
+    identifiers: &[Identifier],
+    min_signers: u16,
+) -> Result<Vec<KeyShare>> {
+    let signing_key = SigningKey::generate(&mut OsRng);
+    let mut key_shares = Vec::new();
+    for key_share in
+        import_key(&signing_key, identifiers, min_signers)?
+    {
+        let key_share: KeyShare = (&key_share).try_into()?;
+        key_shares.push(key_share);
+    }
+    Ok(key_shares)
+}
+
+/// Signer with a corrupted signing share is reported as
+/// the culprit when the signature shares are aggregated.
+pub fn run_sign_invalid_share() -> Result<()> {
+    let t = 2;
+    let identifiers: Vec<Identifier> =
+        (1..=3u16).map(|i| i.try_into().unwrap()).collect();
+    let mut key_shares = import_shares(&identifiers, t)?;
+
+    // Signing share from an unrelated key for the third
+    // identifier so the signature share does not verify
+    let other_shares = import_shares(&identifiers, t)?;
+    let (key_package, public_key_package) = key_shares.remove(2);
+    let corrupted = KeyPackage::new(
+        *key_package.identifier(),
+        *other_shares[2].0.signing_share(),
+        *key_package.verifying_share(),
+        *key_package.verifying_key(),
+        *key_package.min_signers(),
+    );
+
+    // Third identifier is the second signer in the session
+    let signing_ids = vec![identifiers[0], identifiers[2]];
+    let signing_shares =
+        vec![key_shares.remove(0), (corrupted, public_key_package)];
+    let (signers, verifiers) = make_signers(t as usize);
+    let session_id = SessionId::new_v4();
+    let message = b"message to sign".to_vec();
+
+    let mut drivers = Vec::new();
+    for (index, (signer, key_share)) in
+        signers.into_iter().zip(signing_shares).enumerate()
+    {
+        drivers.push(SignatureDriver::new(
+            session_id,
+            NonZeroU16::new(index as u16 + 1).unwrap(),
+            signing_ids.clone(),
+            signer,
+            verifiers.clone(),
+            t,
+            key_share,
+            message.clone(),
+        )?);
+    }
+
+    // Commitments followed by signature shares
+    for _ in 0..2 {
+        let mut messages = Vec::new();
+        for driver in drivers.iter_mut() {
+            messages.extend(driver.proceed()?);
+        }
+        for message in messages {
+            let receiver = message.receiver().get() as usize - 1;
+            drivers[receiver].handle_incoming(message)?;
+        }
+    }
+
+    let result = drivers[0].try_finalize_round();
+    let Err(Error::InvalidSignatureShares(culprits)) = result else {
+        panic!("expected invalid signature shares error");
+    };
+    assert_eq!(
+        vec![Culprit {
+            party_number: 2,
+            identifier: identifiers[2].serialize(),
+        }],
+        culprits,
+    );
+
+    Ok(())
+}
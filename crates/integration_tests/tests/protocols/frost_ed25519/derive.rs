@@ -0,0 +1,64 @@
+use super::{
+    dkg::run_dkg,
+    sign::{check_sign, SelectedSigners},
+};
+use anyhow::Result;
+use ed25519_dalek::SigningKey;
+use polysig_driver::frost::{
+    ed25519::{
+        derive_key_share, derive_public_key_package, import_key,
+        Identifier, KeyShare,
+    },
+    ChainCode,
+};
+
+use crate::protocols::frost_core::derive::frost_derive_sign;
+
+frost_derive_sign!();
+
+/// Check child verifying keys for a fixed key, chain code
+/// and path.
+pub fn run_derive_test_vector() -> Result<()> {
+    let identifiers: Vec<Identifier> =
+        (1..=3u16).map(|i| i.try_into().unwrap()).collect();
+    let signing_key = SigningKey::from_bytes(&[0x11; 32]);
+    let key_share: KeyShare =
+        import_key(&signing_key, &identifiers, 2)?
+            .first()
+            .unwrap()
+            .try_into()?;
+    assert_eq!(
+        hex::decode(
+            "d04ab232742bb4ab3a1368bd4615e4e6d0224ab71a016baf8520a332c9778737",
+        )?,
+        key_share.1.verifying_key().serialize()?,
+    );
+
+    let chain_code: ChainCode = [7; 32];
+    let expected = [
+        (
+            vec![0],
+            "5bc8fb17a4ad4a56cd5b811b9e73dfb02457ccf8fad5abd778225a8033cf7cd1",
+        ),
+        (
+            vec![0, 1, 42],
+            "1a945532e0c3b41168854a3711fc9f424f4afd297b3b314078b909f38264a6df",
+        ),
+    ];
+    for (path, verifying_key) in expected {
+        let public_key_package = derive_public_key_package(
+            &key_share.1,
+            &chain_code,
+            &path,
+        )?;
+        assert_eq!(
+            hex::decode(verifying_key)?,
+            public_key_package.verifying_key().serialize()?,
+        );
+
+        let derived =
+            derive_key_share(&key_share, &chain_code, &path)?;
+        assert_eq!(&public_key_package, &derived.1);
+    }
+    Ok(())
+}
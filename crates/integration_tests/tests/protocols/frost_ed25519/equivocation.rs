@@ -0,0 +1,149 @@
+use anyhow::Result;
+use ed25519_dalek::SigningKey;
+use polysig_driver::{
+    frost::{
+        ed25519::{import_key, KeyShare, ReshareDriver},
+        Error,
+    },
+    frost_ed25519::Identifier,
+    ProtocolDriver, Round,
+};
+use polysig_protocol::SessionId;
+use rand::rngs::OsRng;
+use std::num::NonZeroU16;
+
+use super::make_signers;
+
+fn party(number: u16) -> NonZeroU16 {
+    NonZeroU16::new(number).unwrap()
+}
+
+fn deliver(
+    drivers: &mut [ReshareDriver],
+    messages: Vec<<ReshareDriver as ProtocolDriver>::Message>,
+) -> Result<()> {
+    for message in messages {
+        let index = message.receiver().get() as usize - 1;
+        drivers[index].handle_incoming(message)?;
+    }
+    Ok(())
+}
+
+/// Old participant sends different commitments to the new
+/// participants; the echo round must detect it.
+pub fn run_reshare_equivocation() -> Result<()> {
+    let ids: Vec<Identifier> =
+        (1..=4u16).map(|i| i.try_into().unwrap()).collect();
+    let (signers, verifiers) = make_signers(3);
+    let session_id = SessionId::new_v4();
+
+    let signing_key = SigningKey::generate(&mut OsRng);
+    let mut key_shares = Vec::new();
+    for key_share in import_key(&signing_key, &ids[0..3], 2)? {
+        let key_share: KeyShare = (&key_share).try_into()?;
+        key_shares.push(key_share);
+    }
+    let verifying_key = *key_shares[0].1.verifying_key();
+
+    // Two old participants keep their identifiers and a
+    // new participant joins
+    let parties = vec![
+        (Some(ids[0]), Some(ids[0])),
+        (Some(ids[1]), Some(ids[1])),
+        (None, Some(ids[3])),
+    ];
+    let new_driver = |index: usize| {
+        ReshareDriver::new(
+            session_id,
+            party(index as u16 + 1),
+            parties.clone(),
+            signers[index].clone(),
+            verifiers.clone(),
+            2,
+            2,
+            verifying_key,
+            key_shares.get(index).cloned().filter(|_| index < 2),
+        )
+    };
+
+    // Old threshold must match the key shares
+    assert!(matches!(
+        ReshareDriver::new(
+            session_id,
+            party(1),
+            parties.clone(),
+            signers[0].clone(),
+            verifiers.clone(),
+            3,
+            2,
+            verifying_key,
+            Some(key_shares[0].clone()),
+        ),
+        Err(Error::ReshareKeyShare)
+    ));
+
+    // New participant must not assume a default old threshold
+    assert!(matches!(
+        ReshareDriver::new(
+            session_id,
+            party(3),
+            parties.clone(),
+            signers[2].clone(),
+            verifiers.clone(),
+            3,
+            2,
+            verifying_key,
+            None,
+        ),
+        Err(Error::ReshareDealers(3, 2))
+    ));
+
+    let mut drivers =
+        (0..3).map(new_driver).collect::<Result<Vec<_>, _>>()?;
+
+    // First old participant sends commitments to a different
+    // polynomial to the new participant
+    let mut equivocating = new_driver(0)?;
+    let other = equivocating
+        .proceed()?
+        .into_iter()
+        .find(|m| m.receiver() == &party(3))
+        .unwrap();
+
+    let mut messages = Vec::new();
+    for (index, driver) in drivers.iter_mut().enumerate() {
+        for message in driver.proceed()? {
+            if index == 0 && message.receiver() == &party(3) {
+                continue;
+            }
+            messages.push(message);
+        }
+    }
+    messages.push(other);
+    deliver(&mut drivers, messages)?;
+
+    // Shares are consistent with the commitments each
+    // party received so the echo round is required
+    let mut messages = Vec::new();
+    for driver in drivers.iter_mut() {
+        assert!(driver.round_info()?.can_finalize);
+        assert!(driver.try_finalize_round()?.is_none());
+        messages.append(&mut driver.proceed()?);
+    }
+    deliver(&mut drivers, messages)?;
+
+    assert!(matches!(
+        drivers[0].try_finalize_round(),
+        Err(Error::ReshareEcho(3))
+    ));
+    assert!(matches!(
+        drivers[1].try_finalize_round(),
+        Err(Error::ReshareEcho(3))
+    ));
+    assert!(matches!(
+        drivers[2].try_finalize_round(),
+        Err(Error::ReshareEcho(1))
+    ));
+
+    Ok(())
+}
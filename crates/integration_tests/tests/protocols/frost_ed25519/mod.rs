@@ -4,8 +4,22 @@ use ed25519_dalek::{SigningKey, VerifyingKey};
 use polysig_driver::frost_ed25519::Identifier;
 use rand::rngs::OsRng;
 
+mod coordinator;
+mod culprit;
+mod dealer;
+mod derive;
+mod derived;
 mod dkg;
+mod equivocation;
+mod nonce_index;
+mod preprocess;
+#[cfg(feature = "reconstruct")]
+mod reconstruct;
+mod refresh;
+mod repair;
+mod reshare;
 mod sign;
+mod signed;
 
 pub fn make_signers(
     num_parties: usize,
@@ -62,6 +76,219 @@ async fn frost_ed25519_dkg_sign_2_3() -> Result<()> {
     Ok(())
 }
 
+/// FROST DKG followed by key share refresh and signing (2-of-3).
+#[tokio::test]
+async fn frost_ed25519_dkg_refresh_sign_2_3() -> Result<()> {
+    // crate::test_utils::init_tracing();
+
+    let (rx, _handle) = spawn_server()?;
+    let addr = rx.await?;
+    let server = format!("ws://{}", addr);
+
+    let server_public_key = server_public_key().await?;
+    refresh::run_dkg_refresh_sign_2_3(&server, server_public_key)
+        .await?;
+
+    Ok(())
+}
+
+/// FROST DKG followed by repairing a lost key share
+/// and signing with the repaired key share (2-of-3).
+#[tokio::test]
+async fn frost_ed25519_dkg_repair_sign_2_3() -> Result<()> {
+    // crate::test_utils::init_tracing();
+
+    let (rx, _handle) = spawn_server()?;
+    let addr = rx.await?;
+    let server = format!("ws://{}", addr);
+
+    let server_public_key = server_public_key().await?;
+    repair::run_dkg_repair_sign_2_3(&server, server_public_key)
+        .await?;
+
+    Ok(())
+}
+
+/// FROST DKG followed by resharing to a new participant set
+/// and threshold then signing (2-of-3 to 3-of-4).
+#[tokio::test]
+async fn frost_ed25519_dkg_reshare_sign_2_3_to_3_4() -> Result<()> {
+    // crate::test_utils::init_tracing();
+
+    let (rx, _handle) = spawn_server()?;
+    let addr = rx.await?;
+    let server = format!("ws://{}", addr);
+
+    let server_public_key = server_public_key().await?;
+    reshare::run_dkg_reshare_sign_2_3_to_3_4(
+        &server,
+        server_public_key,
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// FROST trusted dealer key import followed by signing (2-of-3).
+#[tokio::test]
+async fn frost_ed25519_import_sign_2_3() -> Result<()> {
+    // crate::test_utils::init_tracing();
+
+    let (rx, _handle) = spawn_server()?;
+    let addr = rx.await?;
+    let server = format!("ws://{}", addr);
+
+    let server_public_key = server_public_key().await?;
+    dealer::run_import_sign_2_3(&server, server_public_key).await?;
+
+    Ok(())
+}
+
+/// FROST DKG with derived identifiers followed by signing
+/// with a subset of the participants (2-of-3).
+#[tokio::test]
+async fn frost_ed25519_dkg_derived_sign_2_3() -> Result<()> {
+    // crate::test_utils::init_tracing();
+
+    let (rx, _handle) = spawn_server()?;
+    let addr = rx.await?;
+    let server = format!("ws://{}", addr);
+
+    let server_public_key = server_public_key().await?;
+    derived::run_dkg_derived_sign_2_3(&server, server_public_key)
+        .await?;
+
+    Ok(())
+}
+
+/// FROST non-hardened derivation matches the test vector
+/// for the documented scheme.
+#[test]
+fn frost_ed25519_derive_test_vector() -> Result<()> {
+    derive::run_derive_test_vector()
+}
+
+/// FROST DKG followed by non-hardened derivation of child
+/// key shares and signing with the child key shares (2-of-3).
+#[tokio::test]
+async fn frost_ed25519_dkg_derive_sign_2_3() -> Result<()> {
+    // crate::test_utils::init_tracing();
+
+    let (rx, _handle) = spawn_server()?;
+    let addr = rx.await?;
+    let server = format!("ws://{}", addr);
+
+    let server_public_key = server_public_key().await?;
+    derive::run_dkg_derive_sign_2_3(&server, server_public_key)
+        .await?;
+
+    Ok(())
+}
+
+/// FROST DKG followed by nonce preprocessing and signing
+/// with the preprocessed nonces (2-of-3).
+#[tokio::test]
+async fn frost_ed25519_dkg_preprocess_sign_2_3() -> Result<()> {
+    // crate::test_utils::init_tracing();
+
+    let (rx, _handle) = spawn_server()?;
+    let addr = rx.await?;
+    let server = format!("ws://{}", addr);
+
+    let server_public_key = server_public_key().await?;
+    preprocess::run_dkg_preprocess_sign_2_3(
+        &server,
+        server_public_key,
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// FROST signing with preprocessed nonces rejects signature
+/// shares for nonces at a different index.
+#[test]
+fn frost_ed25519_preprocessed_nonce_index_mismatch() -> Result<()> {
+    nonce_index::run_preprocessed_nonce_index_mismatch()
+}
+
+/// FROST DKG followed by coordinated signing where a
+/// coordinator without a key share aggregates the
+/// signature (2-of-3).
+#[tokio::test]
+async fn frost_ed25519_dkg_coordinator_sign_2_3() -> Result<()> {
+    // crate::test_utils::init_tracing();
+
+    let (rx, _handle) = spawn_server()?;
+    let addr = rx.await?;
+    let server = format!("ws://{}", addr);
+
+    let server_public_key = server_public_key().await?;
+    coordinator::run_dkg_coordinator_sign_2_3(
+        &server,
+        server_public_key,
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// FROST DKG followed by reconstructing the signing key.
+#[cfg(feature = "reconstruct")]
+#[tokio::test]
+async fn frost_ed25519_dkg_reconstruct_2_3() -> Result<()> {
+    // crate::test_utils::init_tracing();
+
+    let (rx, _handle) = spawn_server()?;
+    let addr = rx.await?;
+    let server = format!("ws://{}", addr);
+
+    let server_public_key = server_public_key().await?;
+    reconstruct::run_dkg_reconstruct_2_3(&server, server_public_key)
+        .await?;
+
+    Ok(())
+}
+
+/// FROST DKG followed by signing with the reconstructed key.
+#[cfg(feature = "reconstruct")]
+#[tokio::test]
+async fn frost_ed25519_dkg_reconstruct_sign_2_3() -> Result<()> {
+    // crate::test_utils::init_tracing();
+
+    let (rx, _handle) = spawn_server()?;
+    let addr = rx.await?;
+    let server = format!("ws://{}", addr);
+
+    let server_public_key = server_public_key().await?;
+    reconstruct::run_dkg_reconstruct_sign_2_3(
+        &server,
+        server_public_key,
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// FROST round packages are bound to the session.
+#[test]
+fn frost_ed25519_dkg_replay_session() -> Result<()> {
+    signed::run_dkg_replay_session()
+}
+
+/// FROST refresh packages are signed and bound to the session.
+#[test]
+fn frost_ed25519_refresh_replay_session() -> Result<()> {
+    signed::run_refresh_replay_session()
+}
+
+/// FROST signing reports the signer that sent an invalid
+/// signature share.
+#[test]
+fn frost_ed25519_sign_invalid_share() -> Result<()> {
+    culprit::run_sign_invalid_share()
+}
+
 /// FROST DKG followed by signing (3-of-5).
 #[tokio::test]
 async fn frost_ed25519_dkg_sign_3_5() -> Result<()> {
@@ -91,3 +318,10 @@ async fn frost_ed25519_dkg_sign_5_9() -> Result<()> {
 
     Ok(())
 }
+
+/// FROST resharing detects an old participant that sends
+/// different commitments to different parties.
+#[test]
+fn frost_ed25519_reshare_equivocation() -> Result<()> {
+    equivocation::run_reshare_equivocation()
+}
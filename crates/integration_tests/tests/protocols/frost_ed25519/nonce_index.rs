@@ -0,0 +1,95 @@
+use anyhow::Result;
+use polysig_driver::{
+    frost::{
+        ed25519::{PreprocessDriver, SignatureDriver},
+        Error,
+    },
+    frost_ed25519::Identifier,
+    ProtocolDriver, Round,
+};
+use polysig_protocol::SessionId;
+use std::num::NonZeroU16;
+
+use super::{culprit::import_shares, make_signers};
+
+/// Signature shares created with preprocessed nonces at a
+/// different index in the batch are rejected.
+pub fn run_preprocessed_nonce_index_mismatch() -> Result<()> {
+    let t = 2;
+    let identifiers: Vec<Identifier> =
+        (1..=2u16).map(|i| i.try_into().unwrap()).collect();
+    let key_shares = import_shares(&identifiers, t)?;
+    let (signers, verifiers) = make_signers(t as usize);
+
+    let session_id = SessionId::new_v4();
+    let mut drivers = Vec::new();
+    for (index, (signer, key_share)) in
+        signers.iter().zip(key_shares.iter()).enumerate()
+    {
+        drivers.push(PreprocessDriver::new(
+            session_id,
+            NonZeroU16::new(index as u16 + 1).unwrap(),
+            identifiers.clone(),
+            signer.clone(),
+            verifiers.clone(),
+            key_share.clone(),
+            2,
+        )?);
+    }
+
+    let mut messages = Vec::new();
+    for driver in drivers.iter_mut() {
+        messages.extend(driver.proceed()?);
+    }
+    for message in messages {
+        let receiver = message.receiver().get() as usize - 1;
+        drivers[receiver].handle_incoming(message)?;
+    }
+
+    let mut stores = Vec::new();
+    for driver in drivers.iter_mut() {
+        stores.push(driver.try_finalize_round()?.unwrap());
+    }
+
+    // Second signer uses the nonces at the wrong index
+    let session_id = SessionId::new_v4();
+    let message = b"message to sign".to_vec();
+    let mut drivers = Vec::new();
+    for (index, ((signer, key_share), store)) in signers
+        .into_iter()
+        .zip(key_shares)
+        .zip(stores.iter_mut())
+        .enumerate()
+    {
+        let nonces = store.take(index as u32)?;
+        drivers.push(SignatureDriver::new_preprocessed(
+            session_id,
+            NonZeroU16::new(index as u16 + 1).unwrap(),
+            identifiers.clone(),
+            signer,
+            verifiers.clone(),
+            t,
+            key_share,
+            message.clone(),
+            nonces,
+        )?);
+    }
+
+    let mut messages = Vec::new();
+    for driver in drivers.iter_mut() {
+        messages.extend(driver.proceed()?);
+    }
+    for message in messages {
+        let receiver = message.receiver().get() as usize - 1;
+        let expected =
+            (Some(receiver as u32), Some(1 - receiver as u32));
+        let result = drivers[receiver].handle_incoming(message);
+        let Err(Error::NonceIndexMismatch(index, received)) = result
+        else {
+            panic!("expected nonce index mismatch error");
+        };
+        assert_eq!(expected, (index, received));
+    }
+
+    Ok(())
+}
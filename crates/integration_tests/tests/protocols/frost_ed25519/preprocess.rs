@@ -0,0 +1,17 @@
+use super::dkg::run_dkg;
+use anyhow::Result;
+use ed25519_dalek::SigningKey;
+use polysig_client::{
+    frost::ed25519::{preprocess, sign_preprocessed},
+    ServerOptions, SessionOptions,
+};
+use polysig_driver::frost::ed25519::{
+    Identifier, KeyShare, NonceStore, Participant, PartyOptions,
+};
+use polysig_protocol::{Keypair, Parameters};
+
+use crate::protocols::frost_core::{
+    make_signing_message, preprocess::frost_preprocess_sign,
+};
+
+frost_preprocess_sign!();
@@ -0,0 +1,49 @@
+use super::dkg::run_dkg;
+use anyhow::Result;
+use ed25519_dalek::{Verifier, VerifyingKey};
+use polysig_driver::{
+    frost::{
+        ed25519::{reconstruct_key, Identifier},
+        Error,
+    },
+    signers::eddsa::ExpandedEddsaSigner,
+};
+
+use crate::protocols::frost_core::reconstruct::frost_dkg_reconstruct;
+
+frost_dkg_reconstruct!();
+
+pub async fn run_dkg_reconstruct_sign_2_3(
+    server: &str,
+    server_public_key: Vec<u8>,
+) -> Result<()> {
+    let n = 3;
+    let t = 2;
+    let identifiers: Vec<Identifier> =
+        (1..=n).map(|i| i.try_into().unwrap()).collect();
+
+    let (_, key_shares, _) =
+        run_dkg(t, n, server, server_public_key, identifiers).await?;
+
+    let group_key = key_shares[0].1.verifying_key().serialize()?;
+    let verifying_key =
+        VerifyingKey::from_bytes(group_key.as_slice().try_into()?)?;
+
+    let secret_key = reconstruct_key(&key_shares[1..3])?;
+    let signer = ExpandedEddsaSigner::new(secret_key);
+    assert_eq!(&verifying_key, signer.verifying_key());
+
+    // Standard Ed25519 signature for the group key
+    let message = b"reconstructed key signature";
+    let signature = signer.sign(message);
+    signer.verify(message, &signature)?;
+    verifying_key.verify(message, &signature)?;
+    verifying_key.verify_strict(message, &signature)?;
+
+    // Same key from a different subset of key shares
+    let other = reconstruct_key(&key_shares[0..2])?;
+    let other = ExpandedEddsaSigner::new(other);
+    assert_eq!(signature, other.sign(message));
+
+    Ok(())
+}
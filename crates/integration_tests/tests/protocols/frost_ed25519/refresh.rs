@@ -0,0 +1,17 @@
+use super::{
+    dkg::run_dkg,
+    sign::{check_sign, SelectedSigners},
+};
+use anyhow::Result;
+use ed25519_dalek::SigningKey;
+use polysig_client::{
+    frost::ed25519::refresh, ServerOptions, SessionOptions,
+};
+use polysig_driver::frost::ed25519::{
+    Identifier, KeyShare, Participant, PartyOptions,
+};
+use polysig_protocol::{Keypair, Parameters};
+
+use crate::protocols::frost_core::refresh::frost_dkg_refresh_sign;
+
+frost_dkg_refresh_sign!();
@@ -0,0 +1,20 @@
+use super::{
+    dkg::run_dkg,
+    sign::{check_sign, SelectedSigners},
+};
+use anyhow::Result;
+use ed25519_dalek::SigningKey;
+use polysig_client::{
+    frost::ed25519::reshare, ServerOptions, SessionOptions,
+};
+use polysig_driver::{
+    frost::ed25519::{
+        Identifier, KeyShare, Participant, PartyOptions,
+    },
+    frost_ed25519::VerifyingKey as GroupVerifyingKey,
+};
+use polysig_protocol::{Keypair, Parameters};
+
+use crate::protocols::frost_core::reshare::frost_dkg_reshare_sign;
+
+frost_dkg_reshare_sign!();
@@ -0,0 +1,145 @@
+use anyhow::Result;
+use ed25519_dalek::SigningKey;
+use polysig_driver::{
+    frost::{
+        ed25519::{import_key, DkgDriver, KeyShare, RefreshDriver},
+        Error,
+    },
+    frost_ed25519::Identifier,
+    ProtocolDriver,
+};
+use polysig_protocol::{Parameters, SessionId};
+use rand::rngs::OsRng;
+use std::num::NonZeroU16;
+
+use super::make_signers;
+
+fn party(number: u16) -> NonZeroU16 {
+    NonZeroU16::new(number).unwrap()
+}
+
+/// Round packages signed for one session are rejected
+/// by a receiver in another session.
+pub fn run_dkg_replay_session() -> Result<()> {
+    let params = Parameters {
+        parties: 2,
+        threshold: 2,
+    };
+    let identifiers: Vec<Identifier> =
+        (1..=2u16).map(|i| i.try_into().unwrap()).collect();
+    let (signers, verifiers) = make_signers(2);
+    let session_id = SessionId::new_v4();
+
+    let mut sender = DkgDriver::new(
+        session_id,
+        party(1),
+        params,
+        identifiers.clone(),
+        signers[0].clone(),
+        verifiers.clone(),
+    )?;
+    let message = sender.proceed()?.remove(0);
+    let replayed =
+        serde_json::from_slice(&serde_json::to_vec(&message)?)?;
+
+    let mut receiver = DkgDriver::new(
+        SessionId::new_v4(),
+        party(2),
+        params,
+        identifiers.clone(),
+        signers[1].clone(),
+        verifiers.clone(),
+    )?;
+    assert!(matches!(
+        receiver.handle_incoming(replayed),
+        Err(Error::PackageSignature)
+    ));
+
+    let mut receiver = DkgDriver::new(
+        session_id,
+        party(2),
+        params,
+        identifiers,
+        signers[1].clone(),
+        verifiers,
+    )?;
+    receiver.handle_incoming(message)?;
+
+    Ok(())
+}
+
+/// Refresh packages are signed by the dealer for the session.
+pub fn run_refresh_replay_session() -> Result<()> {
+    let params = Parameters {
+        parties: 2,
+        threshold: 2,
+    };
+    let identifiers: Vec<Identifier> =
+        (1..=2u16).map(|i| i.try_into().unwrap()).collect();
+    let (signers, verifiers) = make_signers(2);
+    let session_id = SessionId::new_v4();
+
+    let signing_key = SigningKey::generate(&mut OsRng);
+    let mut key_shares = Vec::new();
+    for key_share in import_key(&signing_key, &identifiers, 2)? {
+        let key_share: KeyShare = (&key_share).try_into()?;
+        key_shares.push(key_share);
+    }
+
+    let mut dealer = RefreshDriver::new(
+        session_id,
+        party(1),
+        params,
+        identifiers.clone(),
+        signers[0].clone(),
+        verifiers.clone(),
+        key_shares.remove(0),
+    )?;
+    let message = dealer.proceed()?.remove(0);
+    let replayed =
+        serde_json::from_slice(&serde_json::to_vec(&message)?)?;
+
+    let mut receiver = RefreshDriver::new(
+        SessionId::new_v4(),
+        party(2),
+        params,
+        identifiers.clone(),
+        signers[1].clone(),
+        verifiers.clone(),
+        key_shares[0].clone(),
+    )?;
+    assert!(matches!(
+        receiver.handle_incoming(replayed),
+        Err(Error::PackageSignature)
+    ));
+
+    // Package signed by another party is rejected
+    let mut receiver = RefreshDriver::new(
+        session_id,
+        party(2),
+        params,
+        identifiers.clone(),
+        signers[1].clone(),
+        verifiers.iter().rev().cloned().collect(),
+        key_shares[0].clone(),
+    )?;
+    let replayed =
+        serde_json::from_slice(&serde_json::to_vec(&message)?)?;
+    assert!(matches!(
+        receiver.handle_incoming(replayed),
+        Err(Error::PackageSignature)
+    ));
+
+    let mut receiver = RefreshDriver::new(
+        session_id,
+        party(2),
+        params,
+        identifiers,
+        signers[1].clone(),
+        verifiers,
+        key_shares.remove(0),
+    )?;
+    receiver.handle_incoming(message)?;
+
+    Ok(())
+}
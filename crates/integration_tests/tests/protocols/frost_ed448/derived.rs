@@ -0,0 +1,17 @@
+use anyhow::Result;
+use ed25519_dalek::SigningKey;
+use polysig_client::{
+    frost::ed448::{dkg_derived, sign_derived},
+    ServerOptions, SessionOptions,
+};
+use polysig_driver::frost::ed448::{
+    derive_identifiers, KeyShare, Participant, PartyOptions,
+};
+use polysig_protocol::{Keypair, Parameters};
+
+use super::make_signers;
+use crate::protocols::frost_core::{
+    derived::frost_dkg_derived_sign, make_signing_message,
+};
+
+frost_dkg_derived_sign!();
@@ -0,0 +1,19 @@
+use super::dkg::run_dkg;
+use anyhow::Result;
+use polysig_client::{
+    frost::ed448::sign, ServerOptions, SessionOptions,
+};
+use polysig_driver::{
+    frost::ed448::{KeyShare, Participant, PartyOptions},
+    frost_ed448::{keys, Identifier},
+};
+
+use ed25519_dalek::{SigningKey, VerifyingKey};
+use polysig_protocol::{Keypair, Parameters};
+use std::collections::BTreeMap;
+
+use crate::protocols::frost_core::{
+    make_signing_message, sign::frost_dkg_sign,
+};
+
+frost_dkg_sign!();
@@ -0,0 +1,14 @@
+use anyhow::Result;
+use polysig_client::{
+    frost::p256::dkg, ServerOptions, SessionOptions,
+};
+use polysig_driver::{
+    frost::p256::{Identifier, KeyShare, Participant, PartyOptions},
+    p256::ecdsa::SigningKey,
+};
+use polysig_protocol::{Keypair, Parameters};
+
+use super::make_signers;
+use crate::protocols::frost_core::dkg::frost_dkg;
+
+frost_dkg!();
@@ -0,0 +1,19 @@
+use anyhow::Result;
+use polysig_client::{
+    frost::secp256k1::{dkg_derived, sign_derived},
+    ServerOptions, SessionOptions,
+};
+use polysig_driver::{
+    frost::secp256k1::{
+        derive_identifiers, KeyShare, Participant, PartyOptions,
+    },
+    k256::ecdsa::SigningKey,
+};
+use polysig_protocol::{Keypair, Parameters};
+
+use super::make_signers;
+use crate::protocols::frost_core::{
+    derived::frost_dkg_derived_sign, make_signing_message,
+};
+
+frost_dkg_derived_sign!();
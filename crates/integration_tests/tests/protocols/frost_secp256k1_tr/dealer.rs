@@ -0,0 +1,13 @@
+use super::sign::{check_sign, SelectedSigners};
+use anyhow::Result;
+use polysig_client::ServerOptions;
+use polysig_driver::{
+    frost::secp256k1_tr::{
+        import_key, verify_key_share, Identifier, KeyShare,
+    },
+    signers::schnorr::SchnorrSigner as Signer,
+};
+
+use crate::protocols::frost_core::dealer::frost_import_sign;
+
+frost_import_sign!();
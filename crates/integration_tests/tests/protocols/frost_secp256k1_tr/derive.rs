@@ -0,0 +1,15 @@
+use super::{
+    dkg::run_dkg,
+    sign::{check_sign, SelectedSigners},
+};
+use anyhow::Result;
+use polysig_driver::frost::{
+    secp256k1_tr::{
+        derive_key_share, derive_public_key_package, Identifier,
+    },
+    ChainCode,
+};
+
+use crate::protocols::frost_core::derive::frost_derive_sign;
+
+frost_derive_sign!();
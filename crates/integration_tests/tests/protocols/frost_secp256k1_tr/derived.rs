@@ -0,0 +1,19 @@
+use anyhow::Result;
+use polysig_client::{
+    frost::secp256k1_tr::{dkg_derived, sign_derived},
+    ServerOptions, SessionOptions,
+};
+use polysig_driver::{
+    frost::secp256k1_tr::{
+        derive_identifiers, KeyShare, Participant, PartyOptions,
+    },
+    k256::schnorr::SigningKey,
+};
+use polysig_protocol::{Keypair, Parameters};
+
+use super::make_signers;
+use crate::protocols::frost_core::{
+    derived::frost_dkg_derived_sign, make_signing_message,
+};
+
+frost_dkg_derived_sign!();
@@ -1,11 +1,23 @@
 use crate::test_utils::{server_public_key, spawn_server};
 use anyhow::Result;
+use polysig_driver::frost::secp256k1_tr::TaprootTweak;
 use polysig_driver::frost_secp256k1_tr::Identifier;
 use polysig_driver::k256::schnorr::{SigningKey, VerifyingKey};
 use rand::rngs::OsRng;
 
+mod coordinator;
+mod dealer;
+mod derive;
+mod derived;
 mod dkg;
+mod preprocess;
+#[cfg(feature = "reconstruct")]
+mod reconstruct;
+mod refresh;
+mod repair;
+mod reshare;
 mod sign;
+mod taproot;
 
 pub fn make_signers(
     num_parties: usize,
@@ -62,6 +74,249 @@ async fn frost_secp256k1_tr_dkg_sign_2_3() -> Result<()> {
     Ok(())
 }
 
+/// FROST DKG followed by key share refresh and signing (2-of-3).
+#[tokio::test]
+async fn frost_secp256k1_tr_dkg_refresh_sign_2_3() -> Result<()> {
+    // crate::test_utils::init_tracing();
+
+    let (rx, _handle) = spawn_server()?;
+    let addr = rx.await?;
+    let server = format!("ws://{}", addr);
+
+    let server_public_key = server_public_key().await?;
+    refresh::run_dkg_refresh_sign_2_3(&server, server_public_key)
+        .await?;
+
+    Ok(())
+}
+
+/// FROST DKG followed by repairing a lost key share
+/// and signing with the repaired key share (2-of-3).
+#[tokio::test]
+async fn frost_secp256k1_tr_dkg_repair_sign_2_3() -> Result<()> {
+    // crate::test_utils::init_tracing();
+
+    let (rx, _handle) = spawn_server()?;
+    let addr = rx.await?;
+    let server = format!("ws://{}", addr);
+
+    let server_public_key = server_public_key().await?;
+    repair::run_dkg_repair_sign_2_3(&server, server_public_key)
+        .await?;
+
+    Ok(())
+}
+
+/// FROST DKG followed by resharing to a new participant set
+/// and threshold then signing (2-of-3 to 3-of-4).
+#[tokio::test]
+async fn frost_secp256k1_tr_dkg_reshare_sign_2_3_to_3_4() -> Result<()>
+{
+    // crate::test_utils::init_tracing();
+
+    let (rx, _handle) = spawn_server()?;
+    let addr = rx.await?;
+    let server = format!("ws://{}", addr);
+
+    let server_public_key = server_public_key().await?;
+    reshare::run_dkg_reshare_sign_2_3_to_3_4(
+        &server,
+        server_public_key,
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// FROST trusted dealer key import followed by signing (2-of-3).
+#[tokio::test]
+async fn frost_secp256k1_tr_import_sign_2_3() -> Result<()> {
+    // crate::test_utils::init_tracing();
+
+    let (rx, _handle) = spawn_server()?;
+    let addr = rx.await?;
+    let server = format!("ws://{}", addr);
+
+    let server_public_key = server_public_key().await?;
+    dealer::run_import_sign_2_3(&server, server_public_key).await?;
+
+    Ok(())
+}
+
+/// FROST DKG with derived identifiers followed by signing
+/// with a subset of the participants (2-of-3).
+#[tokio::test]
+async fn frost_secp256k1_tr_dkg_derived_sign_2_3() -> Result<()> {
+    // crate::test_utils::init_tracing();
+
+    let (rx, _handle) = spawn_server()?;
+    let addr = rx.await?;
+    let server = format!("ws://{}", addr);
+
+    let server_public_key = server_public_key().await?;
+    derived::run_dkg_derived_sign_2_3(&server, server_public_key)
+        .await?;
+
+    Ok(())
+}
+
+/// Taproot output key for key shares of an imported key
+/// matches the BIP-86 test vector.
+#[test]
+fn frost_secp256k1_tr_taproot_output_key() -> Result<()> {
+    taproot::run_import_taproot_output_key()
+}
+
+/// Signing drivers for the Taproot output key of imported
+/// key shares without a script tree.
+#[test]
+fn frost_secp256k1_tr_taproot_tweaked_key_path() -> Result<()> {
+    taproot::run_import_taproot_sign_tweaked(TaprootTweak::KeyPath)
+}
+
+/// Signing drivers for the Taproot output key of imported
+/// key shares committing to a script tree.
+#[test]
+fn frost_secp256k1_tr_taproot_tweaked_merkle_root() -> Result<()> {
+    taproot::run_import_taproot_sign_tweaked(
+        TaprootTweak::MerkleRoot([7u8; 32]),
+    )
+}
+
+/// FROST DKG followed by signing for a Taproot output key
+/// without a script tree (2-of-3).
+#[tokio::test]
+async fn frost_secp256k1_tr_taproot_key_path_2_3() -> Result<()> {
+    // crate::test_utils::init_tracing();
+
+    let (rx, _handle) = spawn_server()?;
+    let addr = rx.await?;
+    let server = format!("ws://{}", addr);
+
+    let server_public_key = server_public_key().await?;
+    taproot::run_dkg_taproot_sign_2_3(
+        &server,
+        server_public_key,
+        TaprootTweak::KeyPath,
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// FROST DKG followed by signing for a Taproot output key
+/// committing to a script tree (2-of-3).
+#[tokio::test]
+async fn frost_secp256k1_tr_taproot_merkle_root_2_3() -> Result<()> {
+    // crate::test_utils::init_tracing();
+
+    let (rx, _handle) = spawn_server()?;
+    let addr = rx.await?;
+    let server = format!("ws://{}", addr);
+
+    let server_public_key = server_public_key().await?;
+    taproot::run_dkg_taproot_sign_2_3(
+        &server,
+        server_public_key,
+        TaprootTweak::MerkleRoot([7u8; 32]),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// FROST DKG followed by signing a key path spend of a
+/// PSBT input for the Taproot output key (2-of-3).
+#[tokio::test]
+async fn frost_secp256k1_tr_taproot_psbt_2_3() -> Result<()> {
+    // crate::test_utils::init_tracing();
+
+    let (rx, _handle) = spawn_server()?;
+    let addr = rx.await?;
+    let server = format!("ws://{}", addr);
+
+    let server_public_key = server_public_key().await?;
+    taproot::run_dkg_taproot_sign_psbt_2_3(&server, server_public_key)
+        .await?;
+
+    Ok(())
+}
+
+/// FROST DKG followed by non-hardened derivation of child
+/// key shares and signing with the child key shares (2-of-3).
+#[tokio::test]
+async fn frost_secp256k1_tr_dkg_derive_sign_2_3() -> Result<()> {
+    // crate::test_utils::init_tracing();
+
+    let (rx, _handle) = spawn_server()?;
+    let addr = rx.await?;
+    let server = format!("ws://{}", addr);
+
+    let server_public_key = server_public_key().await?;
+    derive::run_dkg_derive_sign_2_3(&server, server_public_key)
+        .await?;
+
+    Ok(())
+}
+
+/// FROST DKG followed by nonce preprocessing and signing
+/// with the preprocessed nonces (2-of-3).
+#[tokio::test]
+async fn frost_secp256k1_tr_dkg_preprocess_sign_2_3() -> Result<()> {
+    // crate::test_utils::init_tracing();
+
+    let (rx, _handle) = spawn_server()?;
+    let addr = rx.await?;
+    let server = format!("ws://{}", addr);
+
+    let server_public_key = server_public_key().await?;
+    preprocess::run_dkg_preprocess_sign_2_3(
+        &server,
+        server_public_key,
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// FROST DKG followed by coordinated signing where a
+/// coordinator without a key share aggregates the
+/// signature (2-of-3).
+#[tokio::test]
+async fn frost_secp256k1_tr_dkg_coordinator_sign_2_3() -> Result<()> {
+    // crate::test_utils::init_tracing();
+
+    let (rx, _handle) = spawn_server()?;
+    let addr = rx.await?;
+    let server = format!("ws://{}", addr);
+
+    let server_public_key = server_public_key().await?;
+    coordinator::run_dkg_coordinator_sign_2_3(
+        &server,
+        server_public_key,
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// FROST DKG followed by reconstructing the signing key.
+#[cfg(feature = "reconstruct")]
+#[tokio::test]
+async fn frost_secp256k1_tr_dkg_reconstruct_2_3() -> Result<()> {
+    // crate::test_utils::init_tracing();
+
+    let (rx, _handle) = spawn_server()?;
+    let addr = rx.await?;
+    let server = format!("ws://{}", addr);
+
+    let server_public_key = server_public_key().await?;
+    reconstruct::run_dkg_reconstruct_2_3(&server, server_public_key)
+        .await?;
+
+    Ok(())
+}
+
 /// FROST DKG followed by signing (3-of-5).
 #[tokio::test]
 async fn frost_secp256k1_tr_dkg_sign_3_5() -> Result<()> {
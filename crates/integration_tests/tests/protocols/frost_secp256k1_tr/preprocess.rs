@@ -0,0 +1,19 @@
+use super::dkg::run_dkg;
+use anyhow::Result;
+use polysig_client::{
+    frost::secp256k1_tr::{preprocess, sign_preprocessed},
+    ServerOptions, SessionOptions,
+};
+use polysig_driver::{
+    frost::secp256k1_tr::{
+        Identifier, KeyShare, NonceStore, Participant, PartyOptions,
+    },
+    k256::schnorr::SigningKey,
+};
+use polysig_protocol::{Keypair, Parameters};
+
+use crate::protocols::frost_core::{
+    make_signing_message, preprocess::frost_preprocess_sign,
+};
+
+frost_preprocess_sign!();
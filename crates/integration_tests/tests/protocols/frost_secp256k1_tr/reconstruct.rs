@@ -0,0 +1,10 @@
+use super::dkg::run_dkg;
+use anyhow::Result;
+use polysig_driver::frost::{
+    secp256k1_tr::{reconstruct_key, Identifier},
+    Error,
+};
+
+use crate::protocols::frost_core::reconstruct::frost_dkg_reconstruct;
+
+frost_dkg_reconstruct!();
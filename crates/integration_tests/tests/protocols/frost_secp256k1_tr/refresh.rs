@@ -0,0 +1,19 @@
+use super::{
+    dkg::run_dkg,
+    sign::{check_sign, SelectedSigners},
+};
+use anyhow::Result;
+use polysig_client::{
+    frost::secp256k1_tr::refresh, ServerOptions, SessionOptions,
+};
+use polysig_driver::{
+    frost::secp256k1_tr::{
+        Identifier, KeyShare, Participant, PartyOptions,
+    },
+    k256::schnorr::SigningKey,
+};
+use polysig_protocol::{Keypair, Parameters};
+
+use crate::protocols::frost_core::refresh::frost_dkg_refresh_sign;
+
+frost_dkg_refresh_sign!();
@@ -0,0 +1,20 @@
+use super::{
+    dkg::run_dkg,
+    sign::{check_sign, SelectedSigners},
+};
+use anyhow::Result;
+use polysig_client::{
+    frost::secp256k1_tr::reshare, ServerOptions, SessionOptions,
+};
+use polysig_driver::{
+    frost::secp256k1_tr::{
+        Identifier, KeyShare, Participant, PartyOptions,
+    },
+    frost_secp256k1_tr::VerifyingKey as GroupVerifyingKey,
+    k256::schnorr::SigningKey,
+};
+use polysig_protocol::{Keypair, Parameters};
+
+use crate::protocols::frost_core::reshare::frost_dkg_reshare_sign;
+
+frost_dkg_reshare_sign!();
@@ -0,0 +1,325 @@
+use super::{dkg::run_dkg, make_signers, sign::SelectedSigners};
+use anyhow::Result;
+use polysig_client::{
+    frost::secp256k1_tr::sign_taproot, ServerOptions, SessionOptions,
+};
+use polysig_driver::{
+    bitcoin::{
+        tweak_public_key, Map, OutPoint, Psbt, PsbtInput,
+        Transaction, TxIn, TxOut,
+    },
+    frost::secp256k1_tr::{
+        import_key, tweaked_output_key, Identifier, KeyShare,
+        Participant, PartyOptions, SignatureDriver, TaprootTweak,
+    },
+    frost_secp256k1_tr::{keys::PublicKeyPackage, Signature},
+    k256::schnorr::{self, SigningKey},
+    signers::schnorr::SchnorrSigner,
+    ProtocolDriver, Round,
+};
+use polysig_protocol::{Parameters, SessionId};
+use std::num::NonZeroU16;
+
+use crate::protocols::frost_core::make_signing_message;
+
+const PSBT_IN_WITNESS_UTXO: u8 = 0x01;
+const PSBT_IN_TAP_INTERNAL_KEY: u8 = 0x17;
+
+/// Compute the output key for the internal key of a public
+/// key package using the single-party BIP-341 tweak.
+fn expected_output_key(
+    public_key_package: &PublicKeyPackage,
+    tweak: &TaprootTweak,
+) -> Result<[u8; 32]> {
+    let bytes = public_key_package.verifying_key().serialize()?;
+    let internal_key: [u8; 32] = bytes[1..].try_into()?;
+    let merkle_root = match tweak {
+        TaprootTweak::KeyPath => None,
+        TaprootTweak::MerkleRoot(root) => Some(root),
+    };
+    Ok(tweak_public_key(&internal_key, merkle_root)?)
+}
+
+/// Check the output key for key shares of the BIP-86 test
+/// vector key.
+pub fn run_import_taproot_output_key() -> Result<()> {
+    let identifiers: Vec<Identifier> =
+        (1..=3u16).map(|i| i.try_into().unwrap()).collect();
+
+    // Private key for m/86'/0'/0'/0/0 from BIP-86
+    let signing_key = SchnorrSigner::from_slice(&hex::decode(
+        "41f41d69260df4cf277826a9b65a3717e4eeddbeedf637f212ca096576479361",
+    )?)?;
+    let key_shares = import_key(&signing_key, &identifiers, 2)?;
+    let key_share: KeyShare =
+        key_shares.first().unwrap().try_into()?;
+
+    let output_key =
+        tweaked_output_key(&key_share.1, &TaprootTweak::KeyPath)?;
+    assert_eq!(
+        hex::decode(
+            "a60869f0dbcf1dc659c9cecbaf8050135ea9e8cdc487053f1dc6880949dc684c",
+        )?,
+        output_key,
+    );
+
+    let tweak = TaprootTweak::MerkleRoot([7u8; 32]);
+    assert_eq!(
+        expected_output_key(&key_share.1, &tweak)?,
+        tweaked_output_key(&key_share.1, &tweak)?,
+    );
+    Ok(())
+}
+
+/// Sign in process with drivers created for the output key
+/// of key shares of an imported key.
+pub fn run_import_taproot_sign_tweaked(
+    tweak: TaprootTweak,
+) -> Result<()> {
+    let t = 2;
+    let identifiers: Vec<Identifier> =
+        (1..=3u16).map(|i| i.try_into().unwrap()).collect();
+    let signing_key = SchnorrSigner::random();
+    let mut key_shares = Vec::new();
+    for key_share in import_key(&signing_key, &identifiers, t)? {
+        let key_share: KeyShare = (&key_share).try_into()?;
+        key_shares.push(key_share);
+    }
+
+    let output_key = tweaked_output_key(&key_shares[0].1, &tweak)?;
+    let output_key = schnorr::VerifyingKey::from_bytes(&output_key)?;
+
+    // First and third participants sign
+    let signing_ids = vec![identifiers[0], identifiers[2]];
+    let signing_shares =
+        vec![key_shares.remove(0), key_shares.remove(1)];
+    let (signers, verifiers) = make_signers(t as usize);
+    let session_id = SessionId::new_v4();
+    let message = make_signing_message();
+
+    let mut drivers = Vec::new();
+    for (index, (signer, key_share)) in
+        signers.into_iter().zip(signing_shares).enumerate()
+    {
+        drivers.push(SignatureDriver::new_tweaked(
+            session_id,
+            NonZeroU16::new(index as u16 + 1).unwrap(),
+            signing_ids.clone(),
+            signer,
+            verifiers.clone(),
+            t,
+            key_share,
+            message.clone(),
+            tweak,
+        )?);
+    }
+
+    // Commitments followed by signature shares
+    for _ in 0..2 {
+        let mut messages = Vec::new();
+        for driver in drivers.iter_mut() {
+            messages.extend(driver.proceed()?);
+        }
+        for message in messages {
+            let receiver = message.receiver().get() as usize - 1;
+            drivers[receiver].handle_incoming(message)?;
+        }
+    }
+
+    for driver in drivers.iter_mut() {
+        let signature = driver
+            .try_finalize_round()?
+            .expect("signature for the output key");
+        let signature = schnorr::Signature::try_from(
+            signature.serialize()?.as_slice(),
+        )?;
+        output_key.verify_raw(&message, &signature)?;
+    }
+
+    Ok(())
+}
+
+pub async fn run_dkg_taproot_sign_2_3(
+    server: &str,
+    server_public_key: Vec<u8>,
+    tweak: TaprootTweak,
+) -> Result<()> {
+    let n = 3;
+    let t = 2;
+    let identifiers: Vec<Identifier> =
+        (1..=n).map(|i| i.try_into().unwrap()).collect();
+
+    let (server, key_shares, signers) =
+        run_dkg(t, n, server, server_public_key, identifiers).await?;
+
+    // Output key for address generation
+    let public_key_package = &key_shares.first().unwrap().1;
+    let output_key = tweaked_output_key(public_key_package, &tweak)?;
+    assert_eq!(
+        expected_output_key(public_key_package, &tweak)?,
+        output_key,
+    );
+    let output_key = schnorr::VerifyingKey::from_bytes(&output_key)?;
+
+    let message = make_signing_message();
+    let signatures = sign_taproot_2_3(
+        server, signers, key_shares, &message, tweak,
+    )
+    .await?;
+    for signature in signatures {
+        // Check the signature is a valid BIP-340 signature
+        // for the tweaked output key.
+        let signature = schnorr::Signature::try_from(
+            signature.serialize()?.as_slice(),
+        )?;
+        output_key.verify_raw(&message, &signature)?;
+    }
+
+    Ok(())
+}
+
+/// Sign a key path spend of a PSBT input for the output key
+/// of key shares from DKG.
+pub async fn run_dkg_taproot_sign_psbt_2_3(
+    server: &str,
+    server_public_key: Vec<u8>,
+) -> Result<()> {
+    let n = 3;
+    let t = 2;
+    let identifiers: Vec<Identifier> =
+        (1..=n).map(|i| i.try_into().unwrap()).collect();
+
+    let (server, key_shares, signers) =
+        run_dkg(t, n, server, server_public_key, identifiers).await?;
+
+    let public_key_package = &key_shares.first().unwrap().1;
+    let internal_key =
+        public_key_package.verifying_key().serialize()?[1..].to_vec();
+    let output_key = tweaked_output_key(
+        public_key_package,
+        &TaprootTweak::KeyPath,
+    )?;
+    let mut script_pubkey = vec![0x51, 0x20];
+    script_pubkey.extend_from_slice(&output_key);
+
+    // Output spent by the input encoded as a witness UTXO
+    let mut witness_utxo = 50_000u64.to_le_bytes().to_vec();
+    witness_utxo.push(script_pubkey.len() as u8);
+    witness_utxo.extend_from_slice(&script_pubkey);
+
+    let mut fields = Map::new();
+    fields.insert(vec![PSBT_IN_WITNESS_UTXO], witness_utxo);
+    fields.insert(vec![PSBT_IN_TAP_INTERNAL_KEY], internal_key);
+
+    let psbt = Psbt {
+        unsigned_tx: Transaction {
+            version: 2,
+            inputs: vec![TxIn {
+                previous_output: OutPoint {
+                    txid: [0xaa; 32],
+                    vout: 0,
+                },
+                sequence: 0xfffffffd,
+                ..Default::default()
+            }],
+            outputs: vec![TxOut {
+                value: 40_000,
+                script_pubkey,
+            }],
+            lock_time: 0,
+        },
+        global: Map::new(),
+        inputs: vec![PsbtInput { fields }],
+        outputs: vec![Map::new()],
+    };
+
+    let tweak = psbt.taproot_tweak(0)?;
+    assert_eq!(TaprootTweak::KeyPath, tweak);
+    assert!(psbt.taproot_tweak(1).is_err());
+    let sighash = psbt.taproot_sighash(0)?;
+
+    let signatures = sign_taproot_2_3(
+        server, signers, key_shares, &sighash, tweak,
+    )
+    .await?;
+    for signature in signatures {
+        // Signature is verified for the output key of the
+        // witness UTXO before it is added
+        let mut signed = psbt.clone();
+        signed.add_frost_signature(0, &signature)?;
+        assert_eq!(
+            signature.serialize()?.as_slice(),
+            signed.inputs[0].tap_key_sig().unwrap(),
+        );
+        assert_eq!(signed, Psbt::from_base64(&signed.to_base64())?);
+    }
+
+    Ok(())
+}
+
+/// Sign a message with the first and third participants
+/// for the output key of the key shares.
+async fn sign_taproot_2_3(
+    server: ServerOptions,
+    signers: Vec<SigningKey>,
+    key_shares: Vec<KeyShare>,
+    message: &[u8],
+    tweak: TaprootTweak,
+) -> Result<Vec<Signature>> {
+    let n = 3;
+    let t = 2;
+    let selected =
+        SelectedSigners::new(t, &[0, 2], signers, key_shares)?;
+
+    let params = Parameters {
+        parties: n,
+        threshold: t,
+    };
+
+    let mut tasks = Vec::new();
+    for (index, ((keypair, key_share), (signer, verifiers))) in
+        selected
+            .keypairs
+            .into_iter()
+            .zip(selected.key_shares.into_iter())
+            .zip(selected.signers.into_iter())
+            .enumerate()
+    {
+        let opts = SessionOptions {
+            keypair,
+            parameters: params.clone(),
+            server: server.clone(),
+        };
+        let participants = selected.public_keys.clone();
+        let is_initiator = index == 0;
+        let public_key = participants.get(index).unwrap().to_vec();
+
+        let party = PartyOptions::new(
+            public_key,
+            participants,
+            is_initiator,
+            verifiers,
+        )?;
+
+        let verifier = signer.verifying_key().clone();
+        let participant = Participant::new(signer, verifier, party)?;
+        let msg = message.to_vec();
+        let ids = selected.identifiers.clone();
+
+        tasks.push(tokio::task::spawn(async move {
+            let signature = sign_taproot(
+                opts,
+                participant,
+                ids,
+                key_share,
+                msg,
+                tweak,
+            )
+            .await?;
+            Ok::<_, anyhow::Error>(signature)
+        }));
+    }
+
+    let results = futures::future::try_join_all(tasks).await?;
+    results.into_iter().collect()
+}
@@ -0,0 +1,21 @@
+use crate::test_utils::{
+    impersonation, server_public_key, spawn_server,
+};
+use anyhow::Result;
+
+/// Creates a client that claims a public key it does not
+/// hold the private key for and expects the server to
+/// reject the connection.
+#[tokio::test]
+async fn integration_impersonation() -> Result<()> {
+    //crate::test_utils::init_tracing();
+
+    let (rx, _handle) = spawn_server()?;
+    let addr = rx.await?;
+    let server = format!("ws://{}", addr);
+
+    let server_public_key = server_public_key().await?;
+    impersonation::run(&server, server_public_key).await?;
+
+    Ok(())
+}
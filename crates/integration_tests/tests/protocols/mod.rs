@@ -4,10 +4,20 @@ mod cggmp;
 mod frost_core;
 #[cfg(feature = "frost-ed25519")]
 mod frost_ed25519;
+#[cfg(feature = "frost-ed448")]
+mod frost_ed448;
+#[cfg(feature = "frost-p256")]
+mod frost_p256;
+#[cfg(feature = "frost-ristretto255")]
+mod frost_ristretto255;
+#[cfg(feature = "frost-secp256k1")]
+mod frost_secp256k1;
 #[cfg(feature = "frost-secp256k1-tr")]
 mod frost_secp256k1_tr;
+mod impersonation;
 mod meeting_point;
 mod peer_channel;
 mod session_handshake;
 mod session_timeout;
 mod socket_close;
+mod unexpected_peer;
@@ -0,0 +1,21 @@
+use crate::test_utils::{
+    server_public_key, spawn_server, unexpected_peer,
+};
+use anyhow::Result;
+
+/// Creates a client that only expects a single participant
+/// and a client outside the participants that attempts a peer
+/// handshake; the handshake must be rejected.
+#[tokio::test]
+async fn integration_unexpected_peer() -> Result<()> {
+    //crate::test_utils::init_tracing();
+
+    let (rx, _handle) = spawn_server()?;
+    let addr = rx.await?;
+    let server = format!("ws://{}", addr);
+
+    let server_public_key = server_public_key().await?;
+    unexpected_peer::run(&server, server_public_key).await?;
+
+    Ok(())
+}
@@ -0,0 +1,114 @@
+use anyhow::Result;
+use polysig_driver::{
+    addresses::{Network, PublicKey},
+    signers::{
+        ecdsa::EcdsaSigner, eddsa::EddsaSigner,
+        schnorr::SchnorrSigner,
+    },
+    Error,
+};
+use std::borrow::Cow;
+
+#[test]
+fn addresses_secp256k1() -> Result<()> {
+    let mut secret_key = [0u8; 32];
+    secret_key[31] = 1;
+    let signing_key = EcdsaSigner::from_slice(&secret_key)?;
+    let signer = EcdsaSigner::new(Cow::Borrowed(&signing_key));
+    let public_key = PublicKey::from(signer.verifying_key());
+
+    // Uncompressed keys are accepted
+    let uncompressed = signer.verifying_key().to_encoded_point(false);
+    assert_eq!(
+        public_key,
+        PublicKey::secp256k1(uncompressed.as_bytes())?
+    );
+
+    // Test vectors from BIP-173
+    assert_eq!(
+        "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4",
+        public_key.bitcoin_p2wpkh(Network::Bitcoin)?,
+    );
+    assert_eq!(
+        "tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx",
+        public_key.bitcoin_p2wpkh("testnet".parse()?)?,
+    );
+    assert_eq!(
+        "bcrt1qw508d6qejxtdg4y5r3zarvary0c5xw7kygt080",
+        public_key.bitcoin_p2wpkh(Network::Regtest)?,
+    );
+    assert_eq!(
+        "tb1pmfr3p9j00pfxjh0zmgp99y8zftmd3s5pmedqhyptwy6lm87hf5ssk79hv2",
+        public_key.bitcoin_p2tr(Network::Testnet)?,
+    );
+    assert_eq!(
+        "0x7E5F4552091A69125d5DfCb7b8C2659029395Bdf",
+        public_key.ethereum()?,
+    );
+    assert_eq!(
+        "cosmos1w508d6qejxtdg4y5r3zarvary0c5xw7k6ah60c",
+        public_key.cosmos("cosmos")?,
+    );
+
+    assert!(public_key.solana().is_err());
+    assert!(public_key.cosmos("Cosmos").is_err());
+    assert!(public_key.cosmos("").is_err());
+    assert!("litecoin".parse::<Network>().is_err());
+    Ok(())
+}
+
+#[test]
+fn addresses_x_only() -> Result<()> {
+    // Test vector from BIP-86
+    let public_key = PublicKey::x_only(&hex::decode(
+        "cc8a4bc64d897bddc5fbc2f670f7a8ba0b386779106cf1223c6fc5d7cd6fc115",
+    )?)?;
+    assert_eq!(
+        "bc1p5cyxnuxmeuwuvkwfem96lqzszd02n6xdcjrs20cac6yqjjwudpxqkedrcr",
+        public_key.bitcoin_p2tr(Network::Bitcoin)?,
+    );
+
+    let signing_key = SchnorrSigner::from_slice(&[0x11; 32])?;
+    let signer = SchnorrSigner::new(Cow::Borrowed(&signing_key));
+    let public_key = PublicKey::from(signer.verifying_key());
+    assert_eq!(
+        "tb1p9fjtrm3nwhemkjek0wxtswz2glmneu33w9lcylrvd7alttk0psmqds9pcj",
+        public_key.bitcoin_p2tr(Network::Testnet)?,
+    );
+
+    // Parity of the Y coordinate is unknown
+    assert!(public_key.bitcoin_p2wpkh(Network::Bitcoin).is_err());
+    assert!(public_key.ethereum().is_err());
+    assert!(public_key.cosmos("cosmos").is_err());
+    Ok(())
+}
+
+#[test]
+fn addresses_ed25519() -> Result<()> {
+    // Secret key from RFC 8032 test 1
+    let secret_key: [u8; 32] = hex::decode(
+        "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60",
+    )?
+    .as_slice()
+    .try_into()?;
+    let signing_key = EddsaSigner::from_bytes(&secret_key);
+    let signer = EddsaSigner::new(Cow::Borrowed(&signing_key));
+    let public_key = PublicKey::from(signer.verifying_key());
+    assert_eq!(
+        "FVen3X669xLzsi6N2V91DoiyzHzg1uAgqiT8jZ9nS96Z",
+        public_key.solana()?,
+    );
+
+    assert!(public_key.bitcoin_p2tr(Network::Bitcoin).is_err());
+    assert!(public_key.ethereum().is_err());
+    assert!(PublicKey::ed25519(&[0u8; 31]).is_err());
+
+    // No curve point has a Y coordinate of 2
+    let mut invalid = [0u8; 32];
+    invalid[0] = 2;
+    assert!(matches!(
+        PublicKey::ed25519(&invalid),
+        Err(Error::AddressPublicKey)
+    ));
+    Ok(())
+}
@@ -0,0 +1,356 @@
+use anyhow::Result;
+use polysig_driver::{
+    bitcoin::{
+        taproot_sighash, taproot_sighash_message, tweak_public_key,
+        tweak_signing_key, Psbt, Transaction, TxOut, SIGHASH_ALL,
+        SIGHASH_DEFAULT,
+    },
+    k256::{elliptic_curve::PrimeField, FieldBytes, Scalar},
+    signers::schnorr::{SchnorrSigner, Signature, VerifyingKey},
+};
+use serde::Deserialize;
+use std::{borrow::Cow, collections::BTreeMap};
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Bip341TestVectors {
+    key_path_spending: Vec<KeyPathSpending>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct KeyPathSpending {
+    given: KeyPathGiven,
+    /// Hashes shared by the signature messages.
+    intermediary: BTreeMap<String, String>,
+    input_spending: Vec<InputSpending>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct KeyPathGiven {
+    #[serde(with = "hex::serde")]
+    raw_unsigned_tx: Vec<u8>,
+    utxos_spent: Vec<UtxoSpent>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UtxoSpent {
+    #[serde(with = "hex::serde")]
+    script_pub_key: Vec<u8>,
+    amount_sats: u64,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct InputSpending {
+    given: InputGiven,
+    intermediary: InputIntermediary,
+    expected: InputExpected,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct InputGiven {
+    txin_index: usize,
+    #[serde(with = "hex::serde")]
+    internal_privkey: Vec<u8>,
+    merkle_root: Option<String>,
+    hash_type: u8,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct InputIntermediary {
+    internal_pubkey: String,
+    tweak: String,
+    tweaked_privkey: String,
+    #[serde(with = "hex::serde")]
+    sig_msg: Vec<u8>,
+    /// Names of the shared hashes in the signature message.
+    precomputed_used: Vec<String>,
+    sig_hash: String,
+}
+
+#[derive(Deserialize)]
+struct InputExpected {
+    witness: Vec<String>,
+}
+
+/// Test vectors are from https://github.com/bitcoin/bips/blob/master/bip-0341/wallet-test-vectors.json
+const BIP341_TEST_VECTORS: &[u8] =
+    include_bytes!("./test_vectors/bip341.json");
+
+/// PSBT with two Taproot inputs.
+///
+/// The first input is a BIP-86 key path spend for the key
+/// `[0x11; 32]` without a signature hash type, the second
+/// input commits to a script tree for the key `[0x22; 32]`
+/// and uses `SIGHASH_ALL`.
+const PSBT: &str = "cHNidP8BAKYCAAAAAqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqAAAAAAD9////u7u7u7u7u7u7u7u7u7u7u7u7u7u7u7u7u7u7u7u7u7sBAAAAAP3///8CYOoAAAAAAAAWABR3d3d3d3d3d3d3d3d3d3d3d3d3dwD6AAAAAAAAIlEgKmSx7jN187tLNnuMuDhKR/c88jFxf4J8bG+79a7PDDYAAAAAAAEBK1DDAAAAAAAAIlEgKmSx7jN187tLNnuMuDhKR/c88jFxf4J8bG+79a7PDDYBFyBPNVvct8wK9yjvPM65YV2QaEu1sspfhZqw8LcEB1hxqgABASv4JAEAAAAAACJRIMAniJC8RbnKMQylewixSB8orysUYmVXlBYxrSUp4uYoAQMEAQAAAAEXIEZtf8rlY+XLCaDRhwu1gDRIBGF4eaFJSc8iKF8brj8nARggVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVUAAAA=";
+
+const OUTPUT_KEY_1: &str =
+    "2a64b1ee3375f3bb4b367b8cb8384a47f73cf231717f827c6c6fbbf5aecf0c36";
+const OUTPUT_KEY_2: &str =
+    "c0278890bc45b9ca310ca57b08b1481f28af2b14626557941631ad2529e2e628";
+
+fn hex32(value: &str) -> Result<[u8; 32]> {
+    Ok(hex::decode(value)?.as_slice().try_into()?)
+}
+
+fn scalar(bytes: &[u8]) -> Result<Scalar> {
+    let bytes: [u8; 32] = bytes.try_into()?;
+    Option::from(Scalar::from_repr(FieldBytes::from(bytes)))
+        .ok_or_else(|| anyhow::anyhow!("invalid scalar"))
+}
+
+#[test]
+fn bitcoin_tweak_public_key() -> Result<()> {
+    // Test vector from BIP-86
+    let internal_key = hex32(
+        "cc8a4bc64d897bddc5fbc2f670f7a8ba0b386779106cf1223c6fc5d7cd6fc115",
+    )?;
+    let output_key = tweak_public_key(&internal_key, None)?;
+    assert_eq!(
+        hex32("a60869f0dbcf1dc659c9cecbaf8050135ea9e8cdc487053f1dc6880949dc684c")?,
+        output_key,
+    );
+
+    let signing_key = SchnorrSigner::from_slice(&[0x22; 32])?;
+    let signer = SchnorrSigner::new(Cow::Borrowed(&signing_key));
+    let internal_key: [u8; 32] =
+        signer.verifying_key().to_bytes().into();
+    let merkle_root = [0x55; 32];
+    let output_key =
+        tweak_public_key(&internal_key, Some(&merkle_root))?;
+    assert_eq!(hex32(OUTPUT_KEY_2)?, output_key);
+
+    let tweaked = signer.taproot_tweak(Some(&merkle_root))?;
+    let tweaked_key: [u8; 32] =
+        tweaked.verifying_key().to_bytes().into();
+    assert_eq!(output_key, tweaked_key);
+    Ok(())
+}
+
+#[test]
+fn bitcoin_bip341_key_path_spending() -> Result<()> {
+    let vectors: Bip341TestVectors =
+        serde_json::from_slice(BIP341_TEST_VECTORS)?;
+
+    for test in vectors.key_path_spending {
+        let tx =
+            Transaction::from_bytes(&test.given.raw_unsigned_tx)?;
+        let prevouts = test
+            .given
+            .utxos_spent
+            .into_iter()
+            .map(|utxo| TxOut {
+                value: utxo.amount_sats,
+                script_pubkey: utxo.script_pub_key,
+            })
+            .collect::<Vec<_>>();
+
+        for input in test.input_spending {
+            let InputSpending {
+                given,
+                intermediary,
+                expected,
+            } = input;
+            let index = given.txin_index;
+
+            let sig_msg = taproot_sighash_message(
+                &tx,
+                &prevouts,
+                index,
+                given.hash_type,
+            )?;
+            assert_eq!(intermediary.sig_msg, sig_msg);
+            for name in &intermediary.precomputed_used {
+                let hash = hex::decode(&test.intermediary[name])?;
+                assert!(sig_msg
+                    .windows(hash.len())
+                    .any(|window| window == hash.as_slice()));
+            }
+
+            let sighash = taproot_sighash(
+                &tx,
+                &prevouts,
+                index,
+                given.hash_type,
+            )?;
+            assert_eq!(hex32(&intermediary.sig_hash)?, sighash);
+
+            // Internal key with an even Y coordinate
+            let internal_signing_key =
+                SchnorrSigner::from_slice(&given.internal_privkey)?;
+            let internal_key: [u8; 32] = internal_signing_key
+                .verifying_key()
+                .to_bytes()
+                .into();
+            assert_eq!(
+                hex32(&intermediary.internal_pubkey)?,
+                internal_key
+            );
+
+            let merkle_root = given
+                .merkle_root
+                .as_deref()
+                .map(hex32)
+                .transpose()?;
+            let tweak = scalar(&hex32(&intermediary.tweak)?)?;
+            let tweaked_privkey =
+                scalar(&hex32(&intermediary.tweaked_privkey)?)?;
+            assert_eq!(
+                tweaked_privkey,
+                scalar(&internal_signing_key.to_bytes())? + tweak,
+            );
+
+            let output_key = tweak_public_key(
+                &internal_key,
+                merkle_root.as_ref(),
+            )?;
+            assert_eq!(
+                &prevouts[index].script_pubkey[2..],
+                &output_key
+            );
+
+            let signing_key = tweak_signing_key(
+                &internal_signing_key,
+                merkle_root.as_ref(),
+            )?;
+            let expected_key = SchnorrSigner::from_slice(
+                &tweaked_privkey.to_bytes(),
+            )?;
+            assert_eq!(
+                expected_key.verifying_key(),
+                signing_key.verifying_key()
+            );
+
+            // Signatures use all zero auxiliary randomness
+            let signer = SchnorrSigner::new(Cow::Owned(signing_key));
+            let signature = signer.sign_raw(&sighash, &[0; 32])?;
+            let mut witness = signature.to_bytes().to_vec();
+            if given.hash_type != SIGHASH_DEFAULT {
+                witness.push(given.hash_type);
+            }
+            assert_eq!(vec![hex::encode(witness)], expected.witness);
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn bitcoin_psbt_sighash() -> Result<()> {
+    let psbt = Psbt::from_base64(PSBT)?;
+    assert_eq!(2, psbt.inputs.len());
+    assert_eq!(2, psbt.outputs.len());
+    assert_eq!(PSBT, psbt.to_base64());
+
+    assert_eq!(
+        hex32("3f30ce6906bac9c5ebc4556d62ddb2d8aae8af360826d0c46e3d7f95279b6125")?,
+        psbt.taproot_sighash(0)?,
+    );
+    assert_eq!(
+        hex32("a453e65205a5dde6f9be8cb3cb454b21174107853ae86d5232e82d24b5cc4f22")?,
+        psbt.taproot_sighash(1)?,
+    );
+
+    let prevouts = psbt
+        .inputs
+        .iter()
+        .map(|input| Ok(input.witness_utxo()?.unwrap()))
+        .collect::<Result<Vec<_>>>()?;
+    let expected = [
+        (SIGHASH_DEFAULT, "3f30ce6906bac9c5ebc4556d62ddb2d8aae8af360826d0c46e3d7f95279b6125"),
+        (SIGHASH_ALL, "a453e65205a5dde6f9be8cb3cb454b21174107853ae86d5232e82d24b5cc4f22"),
+        (0x02, "c2a1bec5782d3f85822ee75bea319a5235d687614521c275a713f21db17567d4"),
+        (0x03, "4dcdb57904a326cec391a539a8d07f9c838487b9d9579d6e57286010d4282cde"),
+        (0x81, "deb43f26fa1afb05bd7cd5fcefad6b0bebc9c5d0988c082d9703f444303d2ef0"),
+        (0x82, "e481be9dcd48bba6873fe64917a435171283ddf24f80b58fecc6371a78703eed"),
+        (0x83, "cfe48bfc93775f590b3da6380a3c0b91b752a9e5b70632ec1d245e058f058c6e"),
+    ];
+    for (sighash_type, sighash) in expected {
+        let index = if sighash_type == SIGHASH_ALL { 1 } else { 0 };
+        assert_eq!(
+            hex32(sighash)?,
+            taproot_sighash(
+                &psbt.unsigned_tx,
+                &prevouts,
+                index,
+                sighash_type,
+            )?,
+        );
+    }
+
+    assert!(taproot_sighash(&psbt.unsigned_tx, &prevouts, 0, 0x04)
+        .is_err());
+    assert!(taproot_sighash(&psbt.unsigned_tx, &prevouts, 2, 0x00)
+        .is_err());
+    assert!(taproot_sighash(
+        &psbt.unsigned_tx,
+        &prevouts[..1],
+        0,
+        0x00
+    )
+    .is_err());
+    Ok(())
+}
+
+#[test]
+fn bitcoin_psbt_sign() -> Result<()> {
+    let mut psbt = Psbt::from_base64(PSBT)?;
+
+    let signing_key = SchnorrSigner::from_slice(&[0x11; 32])?;
+    let signer = SchnorrSigner::new(Cow::Borrowed(&signing_key));
+    assert_eq!(vec![0], psbt.sign_taproot(&signer)?);
+
+    let signing_key = SchnorrSigner::from_slice(&[0x22; 32])?;
+    let signer = SchnorrSigner::new(Cow::Borrowed(&signing_key));
+    assert_eq!(vec![1], psbt.sign_taproot(&signer)?);
+
+    let signing_key = SchnorrSigner::from_slice(&[0x33; 32])?;
+    let signer = SchnorrSigner::new(Cow::Borrowed(&signing_key));
+    assert!(psbt.sign_taproot(&signer)?.is_empty());
+
+    // Default signature hash type is omitted
+    let signature = psbt.inputs[0].tap_key_sig().unwrap();
+    assert_eq!(64, signature.len());
+    let verifying_key =
+        VerifyingKey::from_bytes(&hex32(OUTPUT_KEY_1)?)?;
+    verifying_key.verify_raw(
+        &psbt.taproot_sighash(0)?,
+        &Signature::try_from(signature)?,
+    )?;
+
+    let signature = psbt.inputs[1].tap_key_sig().unwrap();
+    assert_eq!(65, signature.len());
+    assert_eq!(SIGHASH_ALL, signature[64]);
+    let verifying_key =
+        VerifyingKey::from_bytes(&hex32(OUTPUT_KEY_2)?)?;
+    verifying_key.verify_raw(
+        &psbt.taproot_sighash(1)?,
+        &Signature::try_from(&signature[..64])?,
+    )?;
+
+    // Signatures survive encoding
+    let encoded = psbt.to_base64();
+    assert_eq!(psbt, Psbt::from_base64(&encoded)?);
+    Ok(())
+}
+
+#[test]
+fn bitcoin_psbt_reject_signature() -> Result<()> {
+    let mut psbt = Psbt::from_base64(PSBT)?;
+
+    // Signature for the untweaked key is not valid
+    let signing_key = SchnorrSigner::from_slice(&[0x11; 32])?;
+    let signer = SchnorrSigner::new(Cow::Borrowed(&signing_key));
+    let signature =
+        signer.sign_prehash(&psbt.taproot_sighash(0)?)?.to_bytes();
+    assert!(psbt.add_tap_key_sig(0, &signature).is_err());
+    assert!(psbt.inputs[0].tap_key_sig().is_none());
+
+    assert!(psbt.add_tap_key_sig(2, &signature).is_err());
+    Ok(())
+}
@@ -98,3 +98,316 @@ fn ecdsa_sign_verify_recover() -> Result<()> {
 
     Ok(())
 }
+
+/// Example from the EIP-712 specification.
+const TYPED_DATA: &str = r#"
+{
+  "types": {
+    "EIP712Domain": [
+      { "name": "name", "type": "string" },
+      { "name": "version", "type": "string" },
+      { "name": "chainId", "type": "uint256" },
+      { "name": "verifyingContract", "type": "address" }
+    ],
+    "Person": [
+      { "name": "name", "type": "string" },
+      { "name": "wallet", "type": "address" }
+    ],
+    "Mail": [
+      { "name": "from", "type": "Person" },
+      { "name": "to", "type": "Person" },
+      { "name": "contents", "type": "string" }
+    ]
+  },
+  "primaryType": "Mail",
+  "domain": {
+    "name": "Ether Mail",
+    "version": "1",
+    "chainId": 1,
+    "verifyingContract": "0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC"
+  },
+  "message": {
+    "from": {
+      "name": "Cow",
+      "wallet": "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826"
+    },
+    "to": {
+      "name": "Bob",
+      "wallet": "0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB"
+    },
+    "contents": "Hello, Bob!"
+  }
+}
+"#;
+
+#[test]
+fn ecdsa_sign_typed_data() -> Result<()> {
+    use polysig_driver::eip712::TypedData;
+    use sha3::{Digest, Keccak256};
+
+    let mut typed_data: TypedData = serde_json::from_str(TYPED_DATA)?;
+
+    assert_eq!(
+        "f2cee375fa42b42143804025fc449deafd50cc031ca257e0b194a650a912090f",
+        hex::encode(typed_data.domain_separator()?),
+    );
+    assert_eq!(
+        "c52c0ee5d84264471806290a3f2c4cecfc5490626bf912d01f240d7a274b371e",
+        hex::encode(typed_data.message_hash()?),
+    );
+    assert_eq!(
+        "be609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2",
+        hex::encode(typed_data.signing_hash()?),
+    );
+
+    let private_key = Keccak256::digest(b"cow");
+    let signing_key =
+        EcdsaSigner::from_slice(private_key.as_slice())?;
+    let signer = EcdsaSigner::new(Cow::Owned(signing_key));
+
+    let (signature, recid) = signer.sign_typed_data(&typed_data)?;
+    let (r, s) = signature.split_bytes();
+    assert_eq!(
+        "4355c47d63924e8a72e509b65029052eb6c299d53a04e167c5775fd466751c9d",
+        hex::encode(r),
+    );
+    assert_eq!(
+        "07299936d304c153f6443dfa05f40ff007d72911b6f72307f996231605b91562",
+        hex::encode(s),
+    );
+    assert_eq!(1, u8::from(recid));
+
+    // Domain type is inferred from the domain values
+    let domain_separator = typed_data.domain_separator()?;
+    typed_data.types.remove("EIP712Domain");
+    assert_eq!(domain_separator, typed_data.domain_separator()?);
+
+    // Values must be valid for the member type
+    typed_data.message["contents"] = serde_json::json!(42);
+    assert!(typed_data.signing_hash().is_err());
+
+    Ok(())
+}
+
+/// Recover the address of the signer of a transaction.
+fn recover_sender(
+    prehash: &[u8; 32],
+    signature: &RecoverableSignature,
+) -> Result<String> {
+    use polysig_driver::k256::ecdsa::{
+        RecoveryId, Signature, VerifyingKey,
+    };
+    let verifying_key = VerifyingKey::recover_from_prehash(
+        prehash,
+        &Signature::from_slice(&signature.bytes)?,
+        RecoveryId::try_from(signature.recovery_id)?,
+    )?;
+    Ok(polysig_driver::address(
+        verifying_key.to_encoded_point(false).as_bytes(),
+    ))
+}
+
+/// Legacy vector is the example from EIP-155, typed
+/// transactions are published go-ethereum, mainnet and
+/// Sepolia transactions.
+#[test]
+fn ecdsa_sign_transaction() -> Result<()> {
+    use polysig_driver::transaction::{
+        AccessListItem, Eip1559Transaction, Eip2930Transaction,
+        Eip4844Transaction, LegacyTransaction, Transaction,
+    };
+
+    // Example from EIP-155
+    let signing_key = EcdsaSigner::from_slice(&[0x46; 32])?;
+    let signer = EcdsaSigner::new(Cow::Owned(signing_key));
+    let transaction: Transaction = LegacyTransaction {
+        chain_id: Some(1),
+        nonce: 9,
+        gas_price: 20_000_000_000,
+        gas_limit: 21_000,
+        to: Some([0x35; 20]),
+        value: 1_000_000_000_000_000_000,
+        data: vec![],
+    }
+    .into();
+    let prehash = transaction.signing_hash();
+    assert_eq!(
+        "daf5a779ae972f972197303d7b574746c7ef83eadac0f2791ad23db92e4c8e53",
+        hex::encode(prehash),
+    );
+    let signature: RecoverableSignature =
+        signer.sign_prehash_recoverable(&prehash)?.into();
+    let signed = transaction.encode_signed(&signature)?;
+    assert_eq!(
+        "f86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83",
+        hex::encode(&signed.raw),
+    );
+    assert_eq!(
+        "33469b22e9f636356c4160a87eb19df52b7412e8eac32a4a55ffe88ea8350788",
+        hex::encode(signed.hash),
+    );
+
+    // Typed transactions from go-ethereum, Ethereum mainnet
+    // and Sepolia with the published signatures
+    let address = |value: &str| -> Result<[u8; 20]> {
+        Ok(hex::decode(value)?.as_slice().try_into()?)
+    };
+    let versioned_hash = |value: &str| -> Result<[u8; 32]> {
+        Ok(hex::decode(value)?.as_slice().try_into()?)
+    };
+    let vectors: Vec<(
+        Transaction,
+        (&str, u8),
+        Option<&str>,
+        Option<&str>,
+        &str,
+        Option<&str>,
+    )> = vec![
+        // go-ethereum core/types/transaction_test.go
+        (
+            Eip2930Transaction {
+                chain_id: 1,
+                nonce: 3,
+                gas_price: 1,
+                gas_limit: 25_000,
+                to: Some(address(
+                    "b94f5374fce5edbc8e2a8697c15331677e6ebf0b",
+                )?),
+                value: 10,
+                data: vec![0x55, 0x44],
+                access_list: vec![],
+            }
+            .into(),
+            (
+                "c9519f4f2b30335884581971573fadf60c6204f59a911df35ee8a540456b266032f1e8e2c5dd761f9e4f88f41c8310aeaba26a8bfcdacfedfa12ec3862d37521",
+                1,
+            ),
+            Some("49b486f0ec0a60dfbbca2d30cb07c9e8ffb2a2ff41f29a1ab6737475f6ff69f3"),
+            Some("01f8630103018261a894b94f5374fce5edbc8e2a8697c15331677e6ebf0b0a825544c001a0c9519f4f2b30335884581971573fadf60c6204f59a911df35ee8a540456b2660a032f1e8e2c5dd761f9e4f88f41c8310aeaba26a8bfcdacfedfa12ec3862d37521"),
+            "d900408d8fec1ffdb3e360685f94400b2ef6e1211ac0f98abbaa140e1a73683a",
+            None,
+        ),
+        // Mainnet transaction decoded in the ethers-rs tests
+        (
+            Eip2930Transaction {
+                chain_id: 1,
+                nonce: 9215,
+                gas_price: 43_000_000_000,
+                gas_limit: 1_000_000,
+                to: Some(address(
+                    "0000000000a8fb09af944ab3baf7a9b3e1ab29d8",
+                )?),
+                value: 0,
+                data: hex::decode("200200001525000000000b69ffb300000000557b933a7c2c45672b610f8954a3deb39a51a8cae53ec727dbdeb9e2d5456c3be40cff031ab40a55724d5c9c618a2152e99a45649a3b8cf198321f46720b722f4ec38f99ba3bb1303258d2e816e6a95b25647e01bd0967c1b9599fa3521939871d1d0888")?,
+                access_list: [
+                    "724d5c9c618a2152e99a45649a3b8cf198321f46",
+                    "720b722f4ec38f99ba3bb1303258d2e816e6a95b",
+                    "25647e01bd0967c1b9599fa3521939871d1d0888",
+                ]
+                .into_iter()
+                .map(|value| {
+                    Ok(AccessListItem {
+                        address: address(value)?,
+                        storage_keys: vec![],
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?,
+            }
+            .into(),
+            (
+                "8323efae7b9993bd31a58da7924359d24b5504aa2b33194fcc5ae206e65d2e6254ce201e3b4b5cd38eb17c56ee2f9111b2e164efcd57b3e70fa308a0a51f7014",
+                1,
+            ),
+            None,
+            Some("01f90126018223ff850a02ffee00830f4240940000000000a8fb09af944ab3baf7a9b3e1ab29d880b876200200001525000000000b69ffb300000000557b933a7c2c45672b610f8954a3deb39a51a8cae53ec727dbdeb9e2d5456c3be40cff031ab40a55724d5c9c618a2152e99a45649a3b8cf198321f46720b722f4ec38f99ba3bb1303258d2e816e6a95b25647e01bd0967c1b9599fa3521939871d1d0888f845d694724d5c9c618a2152e99a45649a3b8cf198321f46c0d694720b722f4ec38f99ba3bb1303258d2e816e6a95bc0d69425647e01bd0967c1b9599fa3521939871d1d0888c001a08323efae7b9993bd31a58da7924359d24b5504aa2b33194fcc5ae206e65d2e62a054ce201e3b4b5cd38eb17c56ee2f9111b2e164efcd57b3e70fa308a0a51f7014"),
+            "26cb56eafde9500db3fc4dda805f80c765afda70917aa9ab7b756a8c1832ff37",
+            Some("0xe9c790e8fde820ded558a4771b72eec916c04763"),
+        ),
+        // Mainnet 0x0ec0b6a2df4d87424e5f6ad2a654e27aaeb7dac20ae9e8385cc09087ad532ee0
+        (
+            Eip1559Transaction {
+                chain_id: 1,
+                nonce: 0x42,
+                max_priority_fee_per_gas: 0x3b9aca00,
+                max_fee_per_gas: 0x4a817c800,
+                gas_limit: 44_386,
+                to: Some(address(
+                    "6069a6c32cf691f5982febae4faf8a6f3ab2f0f6",
+                )?),
+                value: 0,
+                data: hex::decode("a22cb4650000000000000000000000005eee75727d804a2b13038928d36f8b188945a57a0000000000000000000000000000000000000000000000000000000000000000")?,
+                access_list: vec![],
+            }
+            .into(),
+            (
+                "840cfc572845f5786e702984c2a582528cad4b49b2a10b9db1be7fca9005856525e7109ceb98168d95b09b18bbf6b685130e0562f233877d492b94eee0c5b6d1",
+                0,
+            ),
+            Some("0d5688ac3897124635b6cf1bc0e29d6dfebceebdc10a54d74f2ef8b56535b682"),
+            None,
+            "0ec0b6a2df4d87424e5f6ad2a654e27aaeb7dac20ae9e8385cc09087ad532ee0",
+            Some("0xdd6b8b3dc6b7ad97db52f08a275ff4483e024cea"),
+        ),
+        // Sepolia 0x9a22ccb0029bc8b0ddd073be1a1d923b7ae2b2ea52100bae0db4424f9107e9c0
+        (
+            Eip4844Transaction {
+                chain_id: 11_155_111,
+                nonce: 4002,
+                max_priority_fee_per_gas: 2_000_000_000,
+                max_fee_per_gas: 200_000_000_000,
+                gas_limit: 21_000,
+                to: address("11e9ca82a3a762b4b5bd264d4173a242e7a77064")?,
+                value: 0,
+                data: vec![],
+                access_list: vec![],
+                max_fee_per_blob_gas: 20_000_000_000,
+                blob_versioned_hashes: vec![
+                    versioned_hash("012ec3d6f66766bedb002a190126b3549fce0047de0d4c25cffce0dc1c57921a")?,
+                    versioned_hash("0152d8e24762ff22b1cfd9f8c0683786a7ca63ba49973818b3d1e9512cd2cec4")?,
+                    versioned_hash("013b98c6c83e066d5b14af2b85199e3d4fc7d1e778dd53130d180f5077e2d1c7")?,
+                    versioned_hash("01148b495d6e859114e670ca54fb6e2657f0cbae5b08063605093a4b3dc9f8f1")?,
+                    versioned_hash("011ac212f13c5dff2b2c6b600a79635103d6f580a4221079951181b25c7e6549")?,
+                ],
+            }
+            .into(),
+            (
+                "c8de4cced43169f9aa3d36506363b2d2c44f6c49fc1fd91ea114c86f3757077e1e11fdd0d1934eda0492606ee0bb80a7bf8f35cc5f86ec60fe5031ba48bfd544",
+                1,
+            ),
+            None,
+            Some("03f9011d83aa36a7820fa28477359400852e90edd0008252089411e9ca82a3a762b4b5bd264d4173a242e7a770648080c08504a817c800f8a5a0012ec3d6f66766bedb002a190126b3549fce0047de0d4c25cffce0dc1c57921aa00152d8e24762ff22b1cfd9f8c0683786a7ca63ba49973818b3d1e9512cd2cec4a0013b98c6c83e066d5b14af2b85199e3d4fc7d1e778dd53130d180f5077e2d1c7a001148b495d6e859114e670ca54fb6e2657f0cbae5b08063605093a4b3dc9f8f1a0011ac212f13c5dff2b2c6b600a79635103d6f580a4221079951181b25c7e654901a0c8de4cced43169f9aa3d36506363b2d2c44f6c49fc1fd91ea114c86f3757077ea01e11fdd0d1934eda0492606ee0bb80a7bf8f35cc5f86ec60fe5031ba48bfd544"),
+            "9a22ccb0029bc8b0ddd073be1a1d923b7ae2b2ea52100bae0db4424f9107e9c0",
+            Some("0xa83c816d4f9b2783761a22ba6fadb0eb0606d7b2"),
+        ),
+    ];
+
+    for (
+        transaction,
+        (signature, recovery_id),
+        signing_hash,
+        raw,
+        hash,
+        sender,
+    ) in vectors
+    {
+        let signature = RecoverableSignature {
+            bytes: hex::decode(signature)?,
+            recovery_id,
+        };
+        let prehash = transaction.signing_hash();
+        if let Some(signing_hash) = signing_hash {
+            assert_eq!(signing_hash, hex::encode(prehash));
+        }
+        if let Some(sender) = sender {
+            assert_eq!(sender, recover_sender(&prehash, &signature)?);
+        }
+
+        let signed = transaction.encode_signed(&signature)?;
+        if let Some(raw) = raw {
+            assert_eq!(raw, hex::encode(&signed.raw));
+        }
+        assert_eq!(hash, hex::encode(signed.hash));
+    }
+
+    Ok(())
+}
@@ -6,3 +6,16 @@ mod eddsa;
 
 #[cfg(feature = "schnorr")]
 mod schnorr;
+
+#[cfg(all(feature = "solana", feature = "frost-ed25519"))]
+mod solana;
+
+#[cfg(feature = "bitcoin")]
+mod bitcoin;
+
+#[cfg(all(
+    feature = "addresses",
+    feature = "ecdsa",
+    feature = "eddsa"
+))]
+mod addresses;
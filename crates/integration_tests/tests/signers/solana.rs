@@ -0,0 +1,108 @@
+use anyhow::Result;
+use polysig_driver::{
+    signers::eddsa::EddsaSigner,
+    solana::{
+        offchain_message, AccountMeta, Hash, Instruction, Message,
+        Pubkey, Transaction,
+    },
+};
+use std::borrow::Cow;
+
+const SYSTEM_PROGRAM: &str = "11111111111111111111111111111111";
+
+const PAYER: &str = "AKnL4NNf3DGWZJS6cPknBuEGnVsV4A4m5tgebLHaRSZ9";
+
+const BLOCKHASH: &str = "CktRuQ2mttgRGkXJtyksdKHjUdc2C4TgDzyB98oEzy8";
+
+const LEGACY_MESSAGE: &str = "010001038a88e3dd7409f195fd52db2d3cba5d72ca6709bf1d94121bf3748801b40f6f5c02020202020202020202020202020202020202020202020202020202020202020000000000000000000000000000000000000000000000000000000000000000030303030303030303030303030303030303030303030303030303030303030301020200010c0200000040420f0000000000";
+
+const LEGACY_BASE64: &str = "AbAzBZ/GDYM/ECc1DTFAHDIcRbflRHeufC+gIRWSpXs1kr3qYsY+EXPXB6aQQZfLJbcIfQkNNgp8qm5Kso2hLw0BAAEDiojj3XQJ8ZX9UtstPLpdcspnCb8dlBIb83SIAbQPb1wCAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMBAgIAAQwCAAAAQEIPAAAAAAA=";
+
+const LEGACY_BASE58: &str = "5gBBbBu4j1mVkTYycJmSe3uWjktJjhSA3HHwPkzzeZC7sCYYTH1SEtHjxYwe5YbkBqJhanAKscBv9dWj3EUFDNbynZaW7YxH7FfbrtwB4AJ38YiCPZTvoZrnbx28GrTbXhvo959zCpwV3mmkpx43HnZg1bwx5zVSLgHu8s1jcHXWsgiAHuUbsbfoq3ANXZLcMcvYz7orfV7YfSqkYzGH73rUah8gApiKyDdMKSpjRrga2LuE8PNJXFz91TRkvdSK14sjgHgVaSK24oqyzpzJ1t6SEoytPmhJdX3Ew";
+
+const V0_BASE64: &str = "Ad+uH4WCtjZcMHrfy6vkR711i3w7BL6YZlQrY2DcZxEGo/TkYDZ+MX7td/BrcM70pnTYVdUaknPijxcxY4XPVQKAAQABA4qI4910CfGV/VLbLTy6XXLKZwm/HZQSG/N0iAG0D29cAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgIAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAQICAAEMAgAAAEBCDwAAAAAAAA==";
+
+/// System program instruction to transfer lamports.
+fn transfer(from: Pubkey, to: Pubkey, lamports: u64) -> Instruction {
+    let mut data = 2u32.to_le_bytes().to_vec();
+    data.extend_from_slice(&lamports.to_le_bytes());
+    Instruction {
+        program_id: SYSTEM_PROGRAM.parse().unwrap(),
+        accounts: vec![
+            AccountMeta::new(from, true),
+            AccountMeta::new(to, false),
+        ],
+        data,
+    }
+}
+
+#[test]
+fn solana_sign_transaction() -> Result<()> {
+    let signing_key = EddsaSigner::from_bytes(&[1; 32]);
+    let signer = EddsaSigner::new(Cow::Owned(signing_key));
+
+    let payer: Pubkey = signer.verifying_key().into();
+    assert_eq!(PAYER, payer.to_string());
+    assert_eq!([0; 32], SYSTEM_PROGRAM.parse::<Pubkey>()?.0);
+
+    let blockhash: Hash = BLOCKHASH.parse()?;
+    assert_eq!([3; 32], blockhash.0);
+
+    let instructions =
+        vec![transfer(payer, Pubkey([2; 32]), 1_000_000)];
+
+    // Legacy message
+    let message = Message::legacy(&payer, &instructions, blockhash)?;
+    assert_eq!(LEGACY_MESSAGE, hex::encode(message.serialize()));
+
+    let mut transaction = Transaction::new(message);
+    assert!(!transaction.is_signed());
+    transaction.sign(&signer)?;
+    assert!(transaction.is_signed());
+    assert_eq!(LEGACY_BASE64, transaction.to_base64());
+    assert_eq!(LEGACY_BASE58, transaction.to_base58());
+
+    // Only signers of the message can add a signature
+    let signature = signer.sign(transaction.message_bytes());
+    assert!(transaction
+        .add_signature(&Pubkey([2; 32]), signature)
+        .is_err());
+
+    // Signature from FROST
+    let signature =
+        polysig_driver::frost_ed25519::Signature::deserialize(
+            &signature.to_bytes(),
+        )?;
+    let mut transaction = Transaction::new(transaction.message);
+    transaction.add_signature(
+        &payer,
+        polysig_driver::solana::Signature::try_from(signature)?,
+    )?;
+    assert_eq!(LEGACY_BASE64, transaction.to_base64());
+
+    // Version 0 message
+    let message = Message::v0(&payer, &instructions, blockhash)?;
+    let mut transaction = Transaction::new(message);
+    transaction.sign(&signer)?;
+    assert_eq!(V0_BASE64, transaction.to_base64());
+
+    Ok(())
+}
+
+#[test]
+fn solana_offchain_message() -> Result<()> {
+    let message = offchain_message(b"Hello, world!")?;
+    assert_eq!(b"\xffsolana offchain", &message[..16]);
+    // Version, restricted ASCII format and length
+    assert_eq!(&[0, 0, 13, 0], &message[16..20]);
+    assert_eq!(b"Hello, world!", &message[20..]);
+
+    // Non-ASCII messages use the UTF-8 format
+    let message = offchain_message("Grüße".as_bytes())?;
+    assert_eq!(1, message[17]);
+
+    assert!(offchain_message(&[]).is_err());
+    assert!(offchain_message(&[0xff, 0xfe]).is_err());
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use anyhow::Result;
+use futures::StreamExt;
+use polysig_client::{
+    Client, ClientOptions, Error, NetworkTransport,
+};
+use polysig_protocol::{hex, Event, Keypair};
+
+pub async fn run(
+    server: &str,
+    server_public_key: Vec<u8>,
+) -> Result<()> {
+    let victim = Keypair::generate()?;
+    let attacker = Keypair::generate()?;
+
+    // Claim the victim's public key in the query string
+    // whilst holding a different private key
+    let url = format!(
+        "{}/?public_key={}",
+        server.trim_end_matches('/'),
+        hex::encode(victim.public_key())
+    );
+    let options = ClientOptions {
+        keypair: Some(attacker),
+        server_public_key: Some(server_public_key),
+        pattern: None,
+        participants: None,
+    };
+    let (mut client, event_loop) = Client::new(&url, options).await?;
+
+    client.connect().await?;
+
+    let mut rejected = false;
+    let mut s = event_loop.run();
+    while let Some(event) = s.next().await {
+        match event {
+            Ok(Event::ServerConnected { .. }) => {}
+            Ok(Event::Close) => break,
+            Err(Error::ServerError(_, message)) => {
+                assert_eq!("static key proof is invalid", message);
+                rejected = true;
+                client.close().await?;
+            }
+            other => panic!("unexpected event {:#?}", other),
+        }
+    }
+
+    assert!(rejected);
+    Ok(())
+}
@@ -1,9 +1,11 @@
+pub(crate) mod impersonation;
 pub(crate) mod meeting_point;
 pub(crate) mod peer_channel;
 #[cfg(any(feature = "cggmp", feature = "frost-ed25519"))]
 pub(crate) mod session_handshake;
 pub(crate) mod session_timeout;
 pub(crate) mod socket_close;
+pub(crate) mod unexpected_peer;
 
 pub(crate) mod meeting_server;
 pub use meeting_server::spawn_meeting_server;
@@ -55,6 +57,7 @@ pub async fn new_client_with_keypair<
         keypair: Some(keypair),
         server_public_key: Some(server_public_key),
         pattern: None,
+        participants: None,
     };
     let url = options.url(server);
     let (client, event_loop) = Client::new(&url, options).await?;
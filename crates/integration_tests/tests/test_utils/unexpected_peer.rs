@@ -0,0 +1,138 @@
+use anyhow::Result;
+use futures::StreamExt;
+use polysig_client::{
+    Client, ClientOptions, Error, EventLoop, NetworkTransport,
+};
+use polysig_protocol::{hex, Event, Keypair};
+use tokio::sync::{mpsc, oneshot};
+
+use super::new_client;
+
+pub async fn run(
+    server: &str,
+    server_public_key: Vec<u8>,
+) -> Result<()> {
+    let victim_key = Keypair::generate()?;
+    let expected_key = Keypair::generate()?;
+
+    // Victim only expects to talk to a single participant
+    let options = ClientOptions {
+        keypair: Some(victim_key.clone()),
+        server_public_key: Some(server_public_key.clone()),
+        pattern: None,
+        participants: Some(vec![expected_key.public_key().to_vec()]),
+    };
+    let url = options.url(server);
+    let (victim, event_loop_v) = Client::new(&url, options).await?;
+
+    let (intruder, event_loop_i, intruder_key) =
+        new_client::<anyhow::Error>(server, server_public_key)
+            .await?;
+
+    let (ready_tx, ready_rx) = oneshot::channel::<()>();
+    let (shutdown_tx, shutdown_rx) = mpsc::channel::<()>(1);
+
+    let ev_v = victim_client(
+        victim,
+        event_loop_v,
+        intruder_key.public_key(),
+        ready_tx,
+        shutdown_tx,
+    );
+    let ev_i = intruder_client(
+        intruder,
+        event_loop_i,
+        victim_key.public_key(),
+        ready_rx,
+        shutdown_rx,
+    );
+
+    let (res_v, res_i) = futures::join!(ev_v, ev_i);
+    res_v?;
+    res_i?;
+
+    Ok(())
+}
+
+async fn victim_client(
+    mut client: Client,
+    event_loop: EventLoop,
+    intruder_public_key: &[u8],
+    ready_tx: oneshot::Sender<()>,
+    shutdown_tx: mpsc::Sender<()>,
+) -> Result<()> {
+    client.connect().await?;
+
+    let mut ready_tx = Some(ready_tx);
+    let mut rejected = false;
+    let mut s = event_loop.run();
+    while let Some(event) = s.next().await {
+        match event {
+            Ok(Event::ServerConnected { .. }) => {
+                if let Some(tx) = ready_tx.take() {
+                    let _ = tx.send(());
+                }
+            }
+            Ok(Event::Close) => break,
+            Err(Error::UnexpectedPeer(key)) => {
+                assert_eq!(hex::encode(intruder_public_key), key);
+                rejected = true;
+
+                // Must not initiate a handshake with the intruder
+                // either
+                let result =
+                    client.connect_peer(intruder_public_key).await;
+                assert!(matches!(
+                    result,
+                    Err(Error::UnexpectedPeer(_))
+                ));
+
+                let _ = shutdown_tx.send(()).await;
+                client.close().await?;
+            }
+            other => panic!("unexpected event {:#?}", other),
+        }
+    }
+
+    assert!(rejected);
+    Ok(())
+}
+
+async fn intruder_client(
+    mut client: Client,
+    event_loop: EventLoop,
+    victim_public_key: &[u8],
+    ready_rx: oneshot::Receiver<()>,
+    mut shutdown_rx: mpsc::Receiver<()>,
+) -> Result<()> {
+    client.connect().await?;
+
+    let mut ready_rx = Some(ready_rx);
+    let mut s = event_loop.run();
+    loop {
+        tokio::select! {
+            biased;
+            shutdown = shutdown_rx.recv() => {
+                if shutdown.is_some() {
+                    client.close().await?;
+                }
+            }
+            event = s.next() => {
+                match event {
+                    Some(Ok(Event::ServerConnected { .. })) => {
+                        // Wait for the victim to be connected
+                        // to the relay before the handshake
+                        if let Some(rx) = ready_rx.take() {
+                            rx.await?;
+                        }
+                        client.connect_peer(victim_public_key).await?;
+                    }
+                    Some(Ok(Event::Close)) | None => break,
+                    Some(Ok(_)) => {}
+                    Some(Err(e)) => return Err(e.into()),
+                }
+            }
+        }
+    }
+    Ok(())
+}
@@ -5,6 +5,14 @@
 use crate::{
     Chunk, Encoding, Error, ProtocolState, Result, SealedEnvelope,
 };
+use snow::{
+    params::{DHChoice, HashChoice, NoiseParams},
+    resolvers::{CryptoResolver, DefaultResolver},
+    HandshakeState,
+};
+
+/// Domain separator for static key proofs.
+const STATIC_KEY_PROOF: &[u8] = b"polysig static key proof";
 
 /// Encrypt a message to send to the server.
 ///
@@ -45,3 +53,232 @@ pub async fn decrypt_server_channel(
         _ => Err(Error::NotTransportState),
     }
 }
+
+/// Read a handshake message and verify the remote static key.
+///
+/// For patterns that pre-share or transmit static keys
+/// the remote static key must match the public key we
+/// expect to be talking to otherwise the handshake is rejected.
+#[doc(hidden)]
+pub fn read_handshake_message(
+    handshake: &mut HandshakeState,
+    message: &[u8],
+    expected_key: &[u8],
+) -> Result<()> {
+    let mut read_buf = vec![0u8; 1024];
+    if let Err(e) = handshake.read_message(message, &mut read_buf) {
+        return Err(match e {
+            snow::Error::Decrypt
+                if handshake.get_remote_static().is_some() =>
+            {
+                Error::HandshakeAuthentication(hex::encode(
+                    expected_key,
+                ))
+            }
+            _ => e.into(),
+        });
+    }
+
+    if let Some(remote_key) = handshake.get_remote_static() {
+        if remote_key != expected_key {
+            return Err(Error::StaticKeyMismatch(
+                hex::encode(expected_key),
+                hex::encode(remote_key),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Determine if a noise pattern authenticates the static
+/// key of the initiator during the handshake.
+///
+/// When it does not the initiator must send a static key proof
+/// after the handshake to prove it holds the private key.
+#[doc(hidden)]
+pub fn authenticates_initiator(params: &NoiseParams) -> bool {
+    params.handshake.pattern.needs_local_static_key(true)
+}
+
+/// Compute a proof of possession for a static key.
+///
+/// The proof is a BLAKE2s HMAC over the handshake hash
+/// keyed by the Diffie-Hellman shared secret of both static
+/// keys; binding to the handshake hash prevents a proof
+/// being replayed for another connection.
+#[doc(hidden)]
+pub fn static_key_proof(
+    private_key: &[u8],
+    remote_public_key: &[u8],
+    handshake_hash: &[u8],
+) -> Result<Vec<u8>> {
+    let resolver = DefaultResolver;
+    let mut dh = resolver
+        .resolve_dh(&DHChoice::Curve25519)
+        .ok_or(Error::StaticKeyProof)?;
+    let mut hash = resolver
+        .resolve_hash(&HashChoice::Blake2s)
+        .ok_or(Error::StaticKeyProof)?;
+
+    if private_key.len() != dh.priv_len()
+        || remote_public_key.len() != dh.pub_len()
+    {
+        return Err(Error::StaticKeyProof);
+    }
+
+    dh.set(private_key);
+    let mut shared_secret = vec![0u8; dh.pub_len()];
+    dh.dh(remote_public_key, &mut shared_secret)?;
+
+    let mut data = STATIC_KEY_PROOF.to_vec();
+    data.extend_from_slice(handshake_hash);
+
+    let mut proof = vec![0u8; hash.hash_len()];
+    hash.hmac(&shared_secret, &data, &mut proof);
+    Ok(proof)
+}
+
+/// Verify a proof of possession for a static key.
+#[doc(hidden)]
+pub fn verify_static_key_proof(
+    private_key: &[u8],
+    remote_public_key: &[u8],
+    handshake_hash: &[u8],
+    proof: &[u8],
+) -> Result<()> {
+    let expected = static_key_proof(
+        private_key,
+        remote_public_key,
+        handshake_hash,
+    )?;
+    // Compare without short-circuit
+    let is_valid = expected.len() == proof.len()
+        && expected
+            .iter()
+            .zip(proof.iter())
+            .fold(0u8, |acc, (a, b)| acc | (a ^ b))
+            == 0;
+    if is_valid {
+        Ok(())
+    } else {
+        Err(Error::StaticKeyProof)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        read_handshake_message, static_key_proof,
+        verify_static_key_proof,
+    };
+    use crate::{Error, Keypair, AUTHENTICATED_PATTERN};
+    use anyhow::Result;
+
+    fn handshake(
+        initiator_key: &Keypair,
+        responder_key: &Keypair,
+        claimed_key: &[u8],
+    ) -> Result<()> {
+        let mut initiator =
+            snow::Builder::new(AUTHENTICATED_PATTERN.parse()?)
+                .local_private_key(initiator_key.private_key())
+                .remote_public_key(responder_key.public_key())
+                .build_initiator()?;
+
+        let mut responder =
+            snow::Builder::new(AUTHENTICATED_PATTERN.parse()?)
+                .local_private_key(responder_key.private_key())
+                .remote_public_key(claimed_key)
+                .build_responder()?;
+
+        let mut message = [0u8; 1024];
+        let len = initiator.write_message(&[], &mut message)?;
+        read_handshake_message(
+            &mut responder,
+            &message[..len],
+            claimed_key,
+        )?;
+
+        let len = responder.write_message(&[], &mut message)?;
+        read_handshake_message(
+            &mut initiator,
+            &message[..len],
+            responder_key.public_key(),
+        )?;
+
+        assert!(initiator.is_handshake_finished());
+        assert!(responder.is_handshake_finished());
+        Ok(())
+    }
+
+    #[test]
+    fn authenticated_handshake() -> Result<()> {
+        let initiator_key = Keypair::generate()?;
+        let responder_key = Keypair::generate()?;
+        handshake(
+            &initiator_key,
+            &responder_key,
+            initiator_key.public_key(),
+        )
+    }
+
+    #[test]
+    fn authenticated_handshake_wrong_key() -> Result<()> {
+        let initiator_key = Keypair::generate()?;
+        let responder_key = Keypair::generate()?;
+        let impersonated = Keypair::generate()?;
+        let result = handshake(
+            &initiator_key,
+            &responder_key,
+            impersonated.public_key(),
+        );
+        let err = result.unwrap_err().downcast::<Error>()?;
+        assert!(matches!(err, Error::HandshakeAuthentication(_)));
+        Ok(())
+    }
+
+    #[test]
+    fn static_key_proof_verify() -> Result<()> {
+        let client_key = Keypair::generate()?;
+        let server_key = Keypair::generate()?;
+        let impersonator = Keypair::generate()?;
+        let handshake_hash = [7u8; 32];
+
+        let proof = static_key_proof(
+            client_key.private_key(),
+            server_key.public_key(),
+            &handshake_hash,
+        )?;
+        verify_static_key_proof(
+            server_key.private_key(),
+            client_key.public_key(),
+            &handshake_hash,
+            &proof,
+        )?;
+
+        // Proof for another handshake is rejected
+        let result = verify_static_key_proof(
+            server_key.private_key(),
+            client_key.public_key(),
+            &[8u8; 32],
+            &proof,
+        );
+        assert!(matches!(result, Err(Error::StaticKeyProof)));
+
+        // Proof from a different static key is rejected
+        let forged = static_key_proof(
+            impersonator.private_key(),
+            server_key.public_key(),
+            &handshake_hash,
+        )?;
+        let result = verify_static_key_proof(
+            server_key.private_key(),
+            client_key.public_key(),
+            &handshake_hash,
+            &forged,
+        );
+        assert!(matches!(result, Err(Error::StaticKeyProof)));
+        Ok(())
+    }
+}
@@ -3,6 +3,14 @@
 /// Noise protocol pattern.
 pub const PATTERN: &str = "Noise_NN_25519_ChaChaPoly_BLAKE2s";
 
+/// Noise protocol pattern that authenticates static keys.
+///
+/// Both sides of the handshake must already know the public
+/// key of the remote party; the handshake fails if the remote
+/// party does not hold the corresponding private key.
+pub const AUTHENTICATED_PATTERN: &str =
+    "Noise_KK_25519_ChaChaPoly_BLAKE2s";
+
 /// Tag for PEM encoding of noise pattern.
 pub const PEM_PATTERN: &str = "NOISE PATTERN";
 
@@ -100,6 +100,7 @@ pub(crate) mod types {
     pub const SESSION_TIMEOUT: u8 = 10;
     pub const SESSION_CLOSE: u8 = 11;
     pub const SESSION_FINISHED: u8 = 12;
+    pub const AUTHENTICATE: u8 = 13;
 
     pub const ENCODING_BLOB: u8 = 1;
     pub const ENCODING_JSON: u8 = 2;
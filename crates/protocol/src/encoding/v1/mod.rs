@@ -245,6 +245,9 @@ impl Encodable for ServerMessage {
             Self::SessionFinished(session_id) => {
                 writer.write_bytes(session_id.as_bytes()).await?;
             }
+            Self::Authenticate(proof) => {
+                encode_buffer(writer, proof).await?;
+            }
             Self::Noop => unreachable!(),
         }
         Ok(())
@@ -340,6 +343,10 @@ impl Decodable for ServerMessage {
                 );
                 *self = ServerMessage::SessionFinished(session_id);
             }
+            types::AUTHENTICATE => {
+                let proof = decode_buffer(reader).await?;
+                *self = ServerMessage::Authenticate(proof);
+            }
             _ => {
                 return Err(encoding_error(
                     crate::Error::EncodingKind(id),
@@ -24,6 +24,21 @@ pub enum Error {
     #[error(r#"noise protocol pattern mismatch, expecting "{0}""#)]
     PatternMismatch(String),
 
+    /// Error generated when the remote static key learned during
+    /// a handshake does not match the expected public key.
+    #[error("static key mismatch, expected {0} but got {1}")]
+    StaticKeyMismatch(String, String),
+
+    /// Error generated when a handshake message could not be
+    /// authenticated using the expected static key.
+    #[error("handshake could not authenticate static key {0}")]
+    HandshakeAuthentication(String),
+
+    /// Error generated when a static key proof of possession
+    /// is invalid.
+    #[error("static key proof is invalid")]
+    StaticKeyProof,
+
     /// Error generated when the PEM encoding does not match
     /// the expected format.
     #[error("encoding in PEM is invalid")]
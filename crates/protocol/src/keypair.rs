@@ -1,7 +1,7 @@
 //! Helper functions for working with static keys.
 use crate::{
     constants::{PATTERN, PEM_PATTERN, PEM_PRIVATE, PEM_PUBLIC},
-    snow::params::NoiseParams,
+    snow::params::{DHChoice, NoiseParams},
     Error, Result,
 };
 use pem::Pem;
@@ -120,7 +120,17 @@ impl Keypair {
             if (PEM_PATTERN, PEM_PUBLIC, PEM_PRIVATE)
                 == (first.tag(), second.tag(), third.tag())
             {
-                if first.into_contents() != PATTERN.as_bytes() {
+                // Static keys are shared between patterns
+                // that use the same DH function
+                let is_compatible =
+                    String::from_utf8(first.into_contents())
+                        .ok()
+                        .and_then(|s| s.parse::<NoiseParams>().ok())
+                        .map(|params| {
+                            params.dh == DHChoice::Curve25519
+                        })
+                        .unwrap_or(false);
+                if !is_compatible {
                     return Err(Error::PatternMismatch(
                         PATTERN.to_string(),
                     ));
@@ -153,6 +153,12 @@ pub enum ServerMessage {
     CloseSession(SessionId),
     /// Message sent when a session was closed.
     SessionFinished(SessionId),
+    /// Proof the client holds the private key for the
+    /// public key it offered when connecting.
+    ///
+    /// Only sent when the noise pattern does not authenticate
+    /// the client static key during the server handshake.
+    Authenticate(Vec<u8>),
 }
 
 impl From<&ServerMessage> for u8 {
@@ -176,6 +182,7 @@ impl From<&ServerMessage> for u8 {
             ServerMessage::SessionFinished(_) => {
                 types::SESSION_FINISHED
             }
+            ServerMessage::Authenticate(_) => types::AUTHENTICATE,
         }
     }
 }
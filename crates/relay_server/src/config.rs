@@ -1,5 +1,7 @@
 //! Server configuration.
-use polysig_protocol::{hex, Keypair};
+use polysig_protocol::{
+    hex, snow::params::NoiseParams, Keypair, PATTERN,
+};
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
 use tokio::fs;
@@ -39,6 +41,15 @@ pub struct ServerConfig {
 }
 
 impl ServerConfig {
+    /// Parse noise parameters from the pattern.
+    ///
+    /// If no pattern is configured the default noise
+    /// parameters pattern is used.
+    pub fn params(&self) -> Result<NoiseParams> {
+        let pattern = self.pattern.as_deref().unwrap_or(PATTERN);
+        Ok(pattern.parse()?)
+    }
+
     /// Determine if a public key is allowed access.
     pub fn is_allowed_access(&self, key: impl AsRef<[u8]>) -> bool {
         //let restricted = self.allow.is_some() || self.deny.is_some();
@@ -35,6 +35,11 @@ pub enum Error {
     #[error("no parent directory")]
     NoParentDir,
 
+    /// Error generated when a connection has not proven it holds
+    /// the private key for the public key it offered.
+    #[error("connection is not authenticated")]
+    Unauthenticated,
+
     /// Error generated when a participant expects to be in the handshake
     /// protocol state.
     #[error("not handshake protocol state")]
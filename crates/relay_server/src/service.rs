@@ -8,10 +8,14 @@ use tokio::sync::mpsc;
 use tokio_stream::wrappers::IntervalStream;
 
 use polysig_protocol::{
-    channel::{decrypt_server_channel, encrypt_server_channel},
+    channel::{
+        authenticates_initiator, decrypt_server_channel,
+        encrypt_server_channel, read_handshake_message,
+        verify_static_key_proof,
+    },
     decode, encode, hex, Encoding, HandshakeMessage, OpaqueMessage,
-    ProtocolState, RequestMessage, ResponseMessage, ServerMessage,
-    SessionState, TransparentMessage,
+    ProtocolState, RequestMessage, ResponseMessage, SealedEnvelope,
+    ServerMessage, SessionState, TransparentMessage,
 };
 
 use crate::{server::State, websocket::Connection, Error, Result};
@@ -72,21 +76,34 @@ async fn handle_request(
                 HandshakeMessage::Initiator(len, buf),
             ),
         ) => {
-            let mut writer = conn.write().await;
-            let (len, payload) = match &mut writer.state {
-                Some(ProtocolState::Handshake(responder)) => {
-                    let mut reply = vec![0u8; 1024];
-                    let mut read_buf = vec![0u8; 1024];
-                    responder
-                        .read_message(&buf[..len], &mut read_buf)?;
-                    let len =
-                        responder.write_message(&[], &mut reply)?;
-
-                    (len, reply)
-                }
-                _ => return Err(Error::NotHandshakeState),
+            let requires_proof = {
+                let reader = state.read().await;
+                !authenticates_initiator(&reader.config.params()?)
             };
 
+            let mut writer = conn.write().await;
+            let public_key = writer.public_key.clone();
+            let (len, payload, handshake_hash) =
+                match &mut writer.state {
+                    Some(ProtocolState::Handshake(responder)) => {
+                        let mut reply = vec![0u8; 1024];
+                        read_handshake_message(
+                            responder,
+                            &buf[..len],
+                            &public_key,
+                        )?;
+                        let len = responder
+                            .write_message(&[], &mut reply)?;
+
+                        (
+                            len,
+                            reply,
+                            responder.get_handshake_hash().to_vec(),
+                        )
+                    }
+                    _ => return Err(Error::NotHandshakeState),
+                };
+
             let response = ResponseMessage::Transparent(
                 TransparentMessage::ServerHandshake(
                     HandshakeMessage::Responder(len, payload),
@@ -105,11 +122,21 @@ async fn handle_request(
                 unreachable!();
             }
 
-            drop(writer);
+            if requires_proof {
+                // Client must prove it holds the private key
+                // before the connection is promoted
+                writer.challenge = Some(handshake_hash);
+                drop(writer);
+            } else {
+                drop(writer);
 
-            // Now move from pending to transport active
-            promote_connection(Arc::clone(&state), Arc::clone(&conn))
+                // Now move from pending to transport active
+                promote_connection(
+                    Arc::clone(&state),
+                    Arc::clone(&conn),
+                )
                 .await;
+            }
         }
         RequestMessage::Transparent(
             TransparentMessage::PeerHandshake {
@@ -117,10 +144,7 @@ async fn handle_request(
                 message,
             },
         ) => {
-            let from_public_key = {
-                let reader = conn.read().await;
-                reader.public_key.clone()
-            };
+            let from_public_key = authenticated_key(&conn).await?;
 
             let peer = {
                 let reader = state.read().await;
@@ -179,10 +203,7 @@ async fn handle_request(
                 }
             }
 
-            let from_public_key = {
-                let reader = conn.read().await;
-                reader.public_key.clone()
-            };
+            let from_public_key = authenticated_key(&conn).await?;
 
             let peer = {
                 let reader = state.read().await;
@@ -217,11 +238,15 @@ async fn handle_request(
         RequestMessage::Opaque(OpaqueMessage::ServerMessage(
             envelope,
         )) => {
-            let from_public_key = {
+            let (from_public_key, authenticated) = {
                 let reader = conn.read().await;
-                reader.public_key.clone()
+                (reader.public_key.clone(), reader.authenticated)
             };
 
+            if !authenticated {
+                return authenticate(state, conn, envelope).await;
+            }
+
             let peer = {
                 let reader = state.read().await;
                 reader.active.get(&from_public_key).map(Arc::clone)
@@ -565,13 +590,69 @@ async fn send_message(
     Ok(())
 }
 
+/// Public key for a connection that has proven possession
+/// of the private key.
+async fn authenticated_key(conn: &Connection) -> Result<Vec<u8>> {
+    let reader = conn.read().await;
+    if reader.authenticated {
+        Ok(reader.public_key.clone())
+    } else {
+        Err(Error::Unauthenticated)
+    }
+}
+
+/// Verify the static key proof sent by a client after
+/// the server handshake and promote the connection.
+///
+/// The proof may only be attempted once per connection.
+async fn authenticate(
+    state: State,
+    conn: Connection,
+    envelope: SealedEnvelope,
+) -> Result<()> {
+    let private_key = {
+        let reader = state.read().await;
+        reader.keypair.private_key().to_vec()
+    };
+
+    let mut writer = conn.write().await;
+    let challenge =
+        writer.challenge.take().ok_or(Error::Unauthenticated)?;
+    let peer_state =
+        writer.state.as_mut().ok_or(Error::NotHandshakeState)?;
+    let (encoding, contents) =
+        decrypt_server_channel(peer_state, envelope).await?;
+
+    let message: ServerMessage = match encoding {
+        Encoding::Blob => decode(&contents).await?,
+        _ => return Err(Error::Unauthenticated),
+    };
+
+    if let ServerMessage::Authenticate(proof) = message {
+        verify_static_key_proof(
+            &private_key,
+            &writer.public_key,
+            &challenge,
+            &proof,
+        )?;
+        drop(writer);
+
+        promote_connection(state, conn).await;
+        Ok(())
+    } else {
+        Err(Error::Unauthenticated)
+    }
+}
+
 /// Promote a connection from pending to active state.
 ///
-/// Called once the server handshake has been initiated.
+/// Called once the client has proven it holds the private
+/// key for the public key it offered.
 async fn promote_connection(state: State, conn: Connection) {
     let (id, public_key) = {
-        let reader = conn.read().await;
-        (reader.id, reader.public_key.clone())
+        let mut writer = conn.write().await;
+        writer.authenticated = true;
+        (writer.id, writer.public_key.clone())
     };
     let mut writer = state.write().await;
     writer.pending.remove(&id);
@@ -23,10 +23,7 @@ use crate::{
     Result,
 };
 use polysig_protocol::{
-    hex,
-    snow::{params::NoiseParams, Builder},
-    uuid::Uuid,
-    zlib, ProtocolState, PATTERN,
+    hex, snow::Builder, uuid::Uuid, zlib, ProtocolState,
 };
 
 pub type Connection = Arc<RwLock<WebSocketConnection>>;
@@ -45,7 +42,16 @@ pub struct WebSocketConnection {
     /// Unique identifier for the socket connection.
     pub(crate) id: Uuid,
     /// User supplied public key.
+    ///
+    /// Only trusted once the connection is authenticated.
     pub(crate) public_key: Vec<u8>,
+    /// Whether the client has proven it holds the private
+    /// key for the public key it offered.
+    pub(crate) authenticated: bool,
+    /// Handshake hash the client must use to prove possession
+    /// of the private key when the noise pattern does not
+    /// authenticate the client static key.
+    pub(crate) challenge: Option<Vec<u8>>,
     /// Outoing channel for messages sent to clients.
     pub(crate) outgoing: mpsc::Sender<Message>,
     // Incoming channel for messages received from clients.
@@ -96,14 +102,9 @@ pub async fn upgrade(
         return Err(StatusCode::FORBIDDEN);
     }
 
-    let pattern = if let Some(pattern) = &writer.config.pattern {
-        pattern
-    } else {
-        PATTERN
-    };
-
-    let params: NoiseParams = pattern
-        .parse()
+    let params = writer
+        .config
+        .params()
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
     let responder = Builder::new(params)
@@ -121,6 +122,8 @@ pub async fn upgrade(
     let conn = Arc::new(RwLock::new(WebSocketConnection {
         id,
         public_key: query.public_key,
+        authenticated: false,
+        challenge: None,
         outgoing: outgoing_tx.clone(),
         incoming,
         state: Some(protocol_state),
@@ -144,14 +147,18 @@ pub async fn upgrade(
 }
 
 async fn disconnect(state: State, conn: Connection) {
-    let (id, public_key) = {
+    let (id, public_key, authenticated) = {
         let reader = conn.read().await;
-        (reader.id, reader.public_key.clone())
+        (reader.id, reader.public_key.clone(), reader.authenticated)
     };
     tracing::debug!(public_key = ?hex::encode(&public_key), "disconnect");
     let mut writer = state.write().await;
     writer.pending.remove(&id);
-    writer.active.remove(&public_key);
+    // Unauthenticated connections must not evict the
+    // active connection for the key they claimed
+    if authenticated {
+        writer.active.remove(&public_key);
+    }
 }
 
 async fn handle_socket(